@@ -2405,6 +2405,10 @@ pub mod cmds {
         /// Get a recommendation on a batch of transfers
         /// to relay.
         RecommendBatch(RecommendBatch),
+        /// Chart the fees paid by the transfers in the pool.
+        FeeHistogram(BridgePoolFeeHistogram),
+        /// Preview the relay of a recommended batch of transfers.
+        PreviewRelay(PreviewRelay),
     }
 
     /// Ethereum Bridge pool commands not requiring [`super::Context`].
@@ -2417,6 +2421,11 @@ pub mod cmds {
         /// Construct and relay a Bridge pool proof to
         /// Ethereum directly.
         RelayProof(RelayProof),
+        /// Export a Bridge pool proof, to be relayed without access
+        /// to Namada.
+        ExportBundle(ExportRelayBundle),
+        /// Relay an exported Bridge pool proof to Ethereum.
+        RelayBundle(RelayFromBundle),
         /// Query the contents of the pool.
         QueryPool(QueryEthBridgePool),
         /// Query to provable contents of the pool.
@@ -2424,16 +2433,40 @@ pub mod cmds {
         /// Check the confirmation status of `TransferToEthereum`
         /// events.
         QueryRelays(QueryRelayProgress),
+        /// Monitor the rate at which the pool grows.
+        MonitorGrowth(MonitorBridgePoolGrowth),
+        /// Query the status of a set of transfers in the pool.
+        QueryTransfers(QueryBridgePoolTransfers),
+        /// Query a summary of the status of the pool.
+        QueryStatus(QueryBridgePoolStatus),
+        /// Check if the Bridge contract is caught up with the
+        /// latest signed root of the pool.
+        CheckConsistency(CheckBridgeConsistency),
+        /// Verify the signatures over the latest signed root of
+        /// the pool.
+        VerifySignedRoot(VerifySignedBridgePoolRoot),
+        /// Keep track of the fees earned by relayers.
+        FeeLedger(RelayerFeeLedger),
     }
 
     impl Cmd for EthBridgePool {
         fn add_sub(app: App) -> App {
             app.subcommand(RecommendBatch::def().display_order(1))
+                .subcommand(BridgePoolFeeHistogram::def().display_order(1))
+                .subcommand(PreviewRelay::def().display_order(1))
                 .subcommand(ConstructProof::def().display_order(1))
                 .subcommand(RelayProof::def().display_order(1))
+                .subcommand(ExportRelayBundle::def().display_order(1))
+                .subcommand(RelayFromBundle::def().display_order(1))
                 .subcommand(QueryEthBridgePool::def().display_order(1))
                 .subcommand(QuerySignedBridgePool::def().display_order(1))
                 .subcommand(QueryRelayProgress::def().display_order(1))
+                .subcommand(MonitorBridgePoolGrowth::def().display_order(1))
+                .subcommand(QueryBridgePoolTransfers::def().display_order(1))
+                .subcommand(QueryBridgePoolStatus::def().display_order(1))
+                .subcommand(CheckBridgeConsistency::def().display_order(1))
+                .subcommand(VerifySignedBridgePoolRoot::def().display_order(1))
+                .subcommand(RelayerFeeLedger::def().display_order(1))
         }
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
@@ -2441,19 +2474,44 @@ pub mod cmds {
             use EthBridgePoolWithoutCtx::*;
 
             let recommend = Self::parse_with_ctx(matches, RecommendBatch);
+            let fee_histogram = Self::parse_with_ctx(matches, FeeHistogram);
+            let preview_relay = Self::parse_with_ctx(matches, PreviewRelay);
             let construct_proof =
                 Self::parse_without_ctx(matches, ConstructProof);
             let relay_proof = Self::parse_without_ctx(matches, RelayProof);
+            let export_bundle =
+                Self::parse_without_ctx(matches, ExportBundle);
+            let relay_bundle = Self::parse_without_ctx(matches, RelayBundle);
             let query_pool = Self::parse_without_ctx(matches, QueryPool);
             let query_signed = Self::parse_without_ctx(matches, QuerySigned);
             let query_relays = Self::parse_without_ctx(matches, QueryRelays);
+            let monitor_growth =
+                Self::parse_without_ctx(matches, MonitorGrowth);
+            let query_transfers =
+                Self::parse_without_ctx(matches, QueryTransfers);
+            let query_status = Self::parse_without_ctx(matches, QueryStatus);
+            let check_consistency =
+                Self::parse_without_ctx(matches, CheckConsistency);
+            let verify_signed_root =
+                Self::parse_without_ctx(matches, VerifySignedRoot);
+            let fee_ledger = Self::parse_without_ctx(matches, FeeLedger);
 
             construct_proof
                 .or(recommend)
+                .or(fee_histogram)
+                .or(preview_relay)
                 .or(relay_proof)
+                .or(export_bundle)
+                .or(relay_bundle)
                 .or(query_pool)
                 .or(query_signed)
                 .or(query_relays)
+                .or(monitor_growth)
+                .or(query_transfers)
+                .or(query_status)
+                .or(check_consistency)
+                .or(verify_signed_root)
+                .or(fee_ledger)
         }
     }
 
@@ -2492,10 +2550,20 @@ pub mod cmds {
                 .subcommand_required(true)
                 .subcommand(ConstructProof::def().display_order(1))
                 .subcommand(RecommendBatch::def().display_order(1))
+                .subcommand(BridgePoolFeeHistogram::def().display_order(1))
+                .subcommand(PreviewRelay::def().display_order(1))
                 .subcommand(RelayProof::def().display_order(1))
+                .subcommand(ExportRelayBundle::def().display_order(1))
+                .subcommand(RelayFromBundle::def().display_order(1))
                 .subcommand(QueryEthBridgePool::def().display_order(1))
                 .subcommand(QuerySignedBridgePool::def().display_order(1))
                 .subcommand(QueryRelayProgress::def().display_order(1))
+                .subcommand(MonitorBridgePoolGrowth::def().display_order(1))
+                .subcommand(QueryBridgePoolTransfers::def().display_order(1))
+                .subcommand(QueryBridgePoolStatus::def().display_order(1))
+                .subcommand(CheckBridgeConsistency::def().display_order(1))
+                .subcommand(VerifySignedBridgePoolRoot::def().display_order(1))
+                .subcommand(RelayerFeeLedger::def().display_order(1))
         }
     }
 
@@ -2565,6 +2633,54 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct ExportRelayBundle(pub args::ExportRelayBundle<args::CliTypes>);
+
+    impl SubCmd for ExportRelayBundle {
+        const CMD: &'static str = "export-relay-bundle";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::ExportRelayBundle::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Construct a merkle proof that the given transfers are in \
+                     the pool, and write it to a bundle with everything \
+                     needed to relay it to Ethereum without access to \
+                     Namada.",
+                )
+                .arg_required_else_help(true)
+                .add_args::<args::ExportRelayBundle<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct RelayFromBundle(pub args::RelayFromBundle<args::CliTypes>);
+
+    impl SubCmd for RelayFromBundle {
+        const CMD: &'static str = "relay-bundle";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::RelayFromBundle::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Relay a bundle written by the export-relay-bundle \
+                     command to Ethereum.",
+                )
+                .arg_required_else_help(true)
+                .add_args::<args::RelayFromBundle<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct RecommendBatch(pub args::RecommendBatch<args::CliTypes>);
 
@@ -2589,7 +2705,56 @@ pub mod cmds {
     }
 
     #[derive(Clone, Debug)]
-    pub struct QueryEthBridgePool(pub args::QueryWithoutCtx<args::CliTypes>);
+    pub struct BridgePoolFeeHistogram(
+        pub args::BridgePoolFeeHistogram<args::CliTypes>,
+    );
+
+    impl SubCmd for BridgePoolFeeHistogram {
+        const CMD: &'static str = "fee-histogram";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                Self(args::BridgePoolFeeHistogram::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Chart the fees paid by the transfers in the bridge pool, \
+                     to help choose the fee of a new transfer.",
+                )
+                .arg_required_else_help(true)
+                .add_args::<args::BridgePoolFeeHistogram<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct PreviewRelay(pub args::PreviewRelay<args::CliTypes>);
+
+    impl SubCmd for PreviewRelay {
+        const CMD: &'static str = "preview-relay";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::PreviewRelay::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Get a recommended batch of transfers from the bridge \
+                     pool, construct a proof of it and dry-run its relay to \
+                     Ethereum, without relaying it.",
+                )
+                .arg_required_else_help(true)
+                .add_args::<args::PreviewRelay<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryEthBridgePool(pub args::QueryBridgePool<args::CliTypes>);
 
     impl SubCmd for QueryEthBridgePool {
         const CMD: &'static str = "query";
@@ -2597,26 +2762,28 @@ pub mod cmds {
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::QueryWithoutCtx::parse(matches)))
+                .map(|matches| Self(args::QueryBridgePool::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about("Get the contents of the Ethereum Bridge pool.")
-                .add_args::<args::QueryWithoutCtx<args::CliTypes>>()
+                .add_args::<args::QueryBridgePool<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct QuerySignedBridgePool(pub args::QueryWithoutCtx<args::CliTypes>);
+    pub struct QuerySignedBridgePool(
+        pub args::QuerySignedBridgePool<args::CliTypes>,
+    );
 
     impl SubCmd for QuerySignedBridgePool {
         const CMD: &'static str = "query-signed";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
-            matches
-                .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::QueryWithoutCtx::parse(matches)))
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                Self(args::QuerySignedBridgePool::parse(matches))
+            })
         }
 
         fn def() -> App {
@@ -2625,12 +2792,12 @@ pub mod cmds {
                     "Get the contents of the Ethereum Bridge pool with a \
                      signed Merkle root.",
                 )
-                .add_args::<args::QueryWithoutCtx<args::CliTypes>>()
+                .add_args::<args::QuerySignedBridgePool<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct QueryRelayProgress(pub args::QueryWithoutCtx<args::CliTypes>);
+    pub struct QueryRelayProgress(pub args::RelayProgress<args::CliTypes>);
 
     impl SubCmd for QueryRelayProgress {
         const CMD: &'static str = "query-relayed";
@@ -2638,16 +2805,166 @@ pub mod cmds {
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::QueryWithoutCtx::parse(matches)))
+                .map(|matches| Self(args::RelayProgress::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about("Get the confirmation status of transfers to Ethereum.")
+                .add_args::<args::RelayProgress<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct MonitorBridgePoolGrowth(
+        pub args::MonitorBridgePoolGrowth<args::CliTypes>,
+    );
+
+    impl SubCmd for MonitorBridgePoolGrowth {
+        const CMD: &'static str = "monitor-growth";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                Self(args::MonitorBridgePoolGrowth::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Periodically estimate the rate at which transfers are \
+                     added to the Ethereum Bridge pool, and the rate at which \
+                     their fees accumulate.",
+                )
+                .add_args::<args::MonitorBridgePoolGrowth<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryBridgePoolStatus(
+        pub args::BridgePoolStatus<args::CliTypes>,
+    );
+
+    impl SubCmd for QueryBridgePoolStatus {
+        const CMD: &'static str = "status";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::BridgePoolStatus::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Get a summary of the status of the Ethereum Bridge pool, \
+                     including the number of pending, signed and in progress \
+                     transfers, and their fees.",
+                )
+                .add_args::<args::BridgePoolStatus<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct CheckBridgeConsistency(
+        pub args::BridgeConsistency<args::CliTypes>,
+    );
+
+    impl SubCmd for CheckBridgeConsistency {
+        const CMD: &'static str = "check-consistency";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::BridgeConsistency::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Check whether the latest signed root of the Ethereum \
+                     Bridge pool can be relayed, has been relayed and awaits \
+                     its observation in Namada, or whether the nonce of the \
+                     Bridge contract on Ethereum is out of sync with Namada.",
+                )
+                .add_args::<args::BridgeConsistency<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryBridgePoolTransfers(
+        pub args::BridgePoolTransfers<args::CliTypes>,
+    );
+
+    impl SubCmd for QueryBridgePoolTransfers {
+        const CMD: &'static str = "query-transfers";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::BridgePoolTransfers::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Get the status of a set of transfers in the Ethereum \
+                     Bridge pool.",
+                )
+                .arg_required_else_help(true)
+                .add_args::<args::BridgePoolTransfers<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct VerifySignedBridgePoolRoot(
+        pub args::QueryWithoutCtx<args::CliTypes>,
+    );
+
+    impl SubCmd for VerifySignedBridgePoolRoot {
+        const CMD: &'static str = "verify-signed-root";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::QueryWithoutCtx::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Verify that the latest signed Merkle root of the \
+                     Ethereum Bridge pool is backed by a quorum of valid \
+                     validator signatures.",
+                )
                 .add_args::<args::QueryWithoutCtx<args::CliTypes>>()
         }
     }
 
+    /// Used as sub-commands (`SubCmd` instance) in `namadar` binary.
+    #[derive(Clone, Debug)]
+    pub struct RelayerFeeLedger(pub args::RelayerFeeLedger<args::CliTypes>);
+
+    impl SubCmd for RelayerFeeLedger {
+        const CMD: &'static str = "fee-ledger";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::RelayerFeeLedger::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(
+                    "Record relayed batches of transfers in a ledger file, \
+                     and report the fees earned by each relayer.",
+                )
+                .arg_required_else_help(true)
+                .add_args::<args::RelayerFeeLedger<args::CliTypes>>()
+        }
+    }
+
     /// Used as sub-commands (`SubCmd` instance) in `namadar` binary.
     #[derive(Clone, Debug)]
     pub enum ValidatorSet {
@@ -2854,6 +3171,7 @@ pub mod args {
     use namada::types::address::{Address, EstablishedAddress};
     use namada::types::chain::{ChainId, ChainIdPrefix};
     use namada::types::dec::Dec;
+    use namada::types::eth_bridge_pool::TransferToEthereumKind;
     use namada::types::ethereum_events::EthAddress;
     use namada::types::keccak::KeccakHash;
     use namada::types::key::*;
@@ -2862,7 +3180,9 @@ pub mod args {
     use namada::types::time::DateTimeUtc;
     use namada::types::token;
     use namada::types::token::NATIVE_MAX_DECIMAL_PLACES;
+    use namada::types::voting_power::FractionalVotingPower;
     pub use namada_sdk::args::*;
+    use namada_sdk::eth_bridge::bridge_pool::DEFAULT_MAX_QUERIED_TRANSFERS;
     pub use namada_sdk::tx::{
         TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM, TX_BRIDGE_POOL_WASM,
         TX_CHANGE_COMMISSION_WASM, TX_CHANGE_CONSENSUS_KEY_WASM,
@@ -2884,6 +3204,7 @@ pub mod args {
     use crate::facade::tendermint::Timeout;
     use crate::facade::tendermint_config::net::Address as TendermintAddress;
 
+    pub const ACCESS_LIST: ArgFlag = flag("access-list");
     pub const ADDRESS: Arg<WalletAddress> = arg("address");
     pub const ALIAS_OPT: ArgOpt<String> = ALIAS.opt();
     pub const ALIAS: Arg<String> = arg("alias");
@@ -2931,11 +3252,13 @@ pub mod args {
     pub const CODE_PATH_OPT: ArgOpt<PathBuf> = CODE_PATH.opt();
     pub const COMMISSION_RATE: Arg<Dec> = arg("commission-rate");
     pub const COMMISSION_RATE_OPT: ArgOpt<Dec> = COMMISSION_RATE.opt();
+    pub const COMPRESS_PROOF: ArgFlag = flag("compress-proof");
     pub const CONSENSUS_TIMEOUT_COMMIT: ArgDefault<Timeout> = arg_default(
         "consensus-timeout-commit",
         DefaultFn(|| Timeout::from_str("1s").unwrap()),
     );
     pub const CONVERSION_TABLE: Arg<PathBuf> = arg("conversion-table");
+    pub const CONVERSION_TABLE_OPT: ArgOpt<PathBuf> = CONVERSION_TABLE.opt();
     pub const DAEMON_MODE: ArgFlag = flag("daemon");
     pub const DAEMON_MODE_RETRY_DUR: ArgOpt<Duration> = arg_opt("retry-sleep");
     pub const DAEMON_MODE_SUCCESS_DUR: ArgOpt<Duration> =
@@ -2949,6 +3272,7 @@ pub mod args {
         arg("destination-validator");
     pub const DISCORD_OPT: ArgOpt<String> = arg_opt("discord-handle");
     pub const DO_IT: ArgFlag = flag("do-it");
+    pub const DESCRIBE_TX: ArgFlag = flag("describe");
     pub const DONT_ARCHIVE: ArgFlag = flag("dont-archive");
     pub const DONT_PREFETCH_WASM: ArgFlag = flag("dont-prefetch-wasm");
     pub const DRY_RUN_TX: ArgFlag = flag("dry-run");
@@ -2956,15 +3280,34 @@ pub mod args {
     pub const DUMP_TX: ArgFlag = flag("dump-tx");
     pub const EPOCH: ArgOpt<Epoch> = arg_opt("epoch");
     pub const ERC20: Arg<EthAddress> = arg("erc20");
-    pub const ETH_CONFIRMATIONS: Arg<u64> = arg("confirmations");
+    pub const ERC20_DECIMALS: ArgOpt<u8> = arg_opt("erc20-decimals");
+    pub const ETH_CHAIN_ID: Arg<u64> = arg("eth-chain-id");
+    pub const ETH_CONFIRMATIONS: ArgDefault<u64> =
+        arg_default("confirmations", DefaultFn(|| 3));
+    pub const ETH_ESTIMATED_GAS: ArgOpt<u64> = arg_opt("estimated-eth-gas");
     pub const ETH_GAS: ArgOpt<u64> = arg_opt("eth-gas");
     pub const ETH_GAS_PRICE: ArgOpt<u64> = arg_opt("eth-gas-price");
+    pub const ETH_GAS_PRICE_BUMP: ArgOpt<u64> = arg_opt("eth-gas-price-bump");
+    pub const ETH_POLL_INTERVAL: ArgOpt<Duration> =
+        arg_opt("eth-poll-interval");
+    pub const ETH_BRIDGE_RPC_PATH: ArgOpt<String> =
+        arg_opt("eth-bridge-rpc-path");
+    pub const ETH_REBROADCAST_AFTER: ArgOpt<Duration> =
+        arg_opt("eth-rebroadcast-after");
+    pub const ETH_MAX_REBROADCASTS: ArgOpt<u64> =
+        arg_opt("eth-max-rebroadcasts");
+    pub const ETH_CONFIRMATION_TIMEOUT: ArgOpt<Duration> =
+        arg_opt("eth-confirmation-timeout");
+    pub const ETH_REQUEST_TIMEOUT: ArgOpt<Duration> =
+        arg_opt("eth-request-timeout");
     pub const ETH_ADDRESS: Arg<EthAddress> = arg("ethereum-address");
     pub const ETH_ADDRESS_OPT: ArgOpt<EthAddress> = ETH_ADDRESS.opt();
     pub const ETH_RPC_ENDPOINT: ArgDefault<String> = arg_default(
         "eth-rpc-endpoint",
         DefaultFn(|| "http://localhost:8545".into()),
     );
+    pub const ETH_RPC_ENDPOINT_OPT: ArgOpt<String> =
+        arg_opt("eth-rpc-endpoint");
     pub const ETH_SYNC: ArgFlag = flag("sync");
     pub const EXPIRATION_OPT: ArgOpt<DateTimeUtc> = arg_opt("expiration");
     pub const EMAIL: Arg<String> = arg("email");
@@ -2974,6 +3317,11 @@ pub mod args {
     pub const FEE_AMOUNT_OPT: ArgOpt<token::DenominatedAmount> =
         arg_opt("gas-price");
     pub const FEE_PAYER_OPT: ArgOpt<WalletPublicKey> = arg_opt("gas-payer");
+    pub const FAIL_ON_WARNING: ArgFlag = flag("fail-on-warning");
+    pub const FAIL_ON_WARNING_ABOUT: &str =
+        "Halt without prompting if any of the transfers have surpassed the \
+         security threshold in Namada, listing the offending hashes. Also \
+         accepted as --no-prompt, to run non-interactively.";
     pub const FILE_PATH: Arg<String> = arg("file");
     pub const FORCE: ArgFlag = flag("force");
     pub const GAS_LIMIT: ArgDefault<GasLimit> =
@@ -2993,6 +3341,10 @@ pub mod args {
     pub const GENESIS_BOND_SOURCE: ArgOpt<GenesisAddress> = arg_opt("source");
     pub const GENESIS_PATH: Arg<PathBuf> = arg("genesis-path");
     pub const GENESIS_TIME: Arg<DateTimeUtc> = arg("genesis-time");
+    pub const GROWTH_WINDOW: ArgDefault<Duration> = arg_default(
+        "window",
+        DefaultFn(|| Duration::from_str("10m").unwrap()),
+    );
     pub const GENESIS_VALIDATOR: ArgOpt<String> =
         arg("genesis-validator").opt();
     pub const GENESIS_VALIDATOR_ADDRESS: Arg<EstablishedAddress> =
@@ -3004,14 +3356,38 @@ pub mod args {
     pub const HD_ALLOW_NON_COMPLIANT_DERIVATION_PATH: ArgFlag =
         flag("allow-non-compliant");
     pub const HD_PROMPT_BIP39_PASSPHRASE: ArgFlag = flag("bip39-passphrase");
+    pub const HISTOGRAM_BUCKETS: ArgDefault<u64> =
+        arg_default("buckets", DefaultFn(|| 10));
     pub const HISTORIC: ArgFlag = flag("historic");
     pub const IBC_TRANSFER_MEMO_PATH: ArgOpt<PathBuf> = arg_opt("memo-path");
     pub const INPUT_OPT: ArgOpt<PathBuf> = arg_opt("input");
+    pub const IN_PROGRESS_THRESHOLD: ArgDefault<FractionalVotingPower> =
+        arg_default(
+            "in-progress-threshold",
+            DefaultFn(|| FractionalVotingPower::NULL),
+        );
+    pub const JSON: ArgFlag = flag("json");
+    pub const TARGET_ROOT_ABOUT: &str =
+        "The block height at which to read the signed Bridge pool root to \
+         construct the proof against. Roots are signed anew under the same \
+         nonce until relayed, thus the targeted root must carry the latest \
+         Bridge pool nonce. Defaults to the latest signed root.";
     pub const LEDGER_ADDRESS_ABOUT: &str =
         "Address of a ledger node as \"{scheme}://{host}:{port}\". If the \
          scheme is not supplied, it is assumed to be TCP.";
+    pub const ETH_BRIDGE_RPC_PATH_ABOUT: &str =
+        "The path of the RPC of the ledger node at which the Ethereum bridge \
+         queries are exposed. Defaults to \"shell/eth_bridge\". Storage and \
+         block queries always go through the default \"shell\" path.";
+    pub const QUERY_OUTPUT_FILE_ABOUT: &str =
+        "Write the json payload of the query to the given file, instead of \
+         printing it out. Parent directories are created as needed.";
+    pub const MAX_QUERIED_TRANSFERS_ABOUT: &str =
+        "The maximum number of transfers the query may process. Queries of \
+         larger Bridge pools fail. Defaults to 100000.";
     pub const CONFIG_RPC_LEDGER_ADDRESS: ArgDefaultFromCtx<ConfigRpcAddress> =
         arg_default_from_ctx("node", DefaultFn(|| "".to_string()));
+    pub const FEE_LEDGER_FILE: Arg<PathBuf> = arg("ledger-file");
     pub const LEDGER_ADDRESS: ArgDefault<TendermintAddress> = arg("node")
         .default(DefaultFn(|| {
             let raw = "127.0.0.1:26657";
@@ -3019,29 +3395,49 @@ pub mod args {
         }));
     pub const LIST_FIND_ADDRESSES_ONLY: ArgFlag = flag("addr");
     pub const LIST_FIND_KEYS_ONLY: ArgFlag = flag("keys");
+    pub const LIFECYCLE: ArgFlag = flag("lifecycle");
     pub const LOCALHOST: ArgFlag = flag("localhost");
     pub const MAX_COMMISSION_RATE_CHANGE: Arg<Dec> =
         arg("max-commission-rate-change");
     pub const MAX_ETH_GAS: ArgOpt<u64> = arg_opt("max_eth-gas");
+    pub const MAX_ETH_GAS_PRICE: ArgOpt<u64> = arg_opt("max-eth-gas-price");
+    pub const MAX_ETH_GAS_PRICE_GWEI: ArgOpt<u64> =
+        arg_opt("max-eth-gas-price-gwei");
+    pub const MAX_CALLDATA_SIZE: ArgOpt<u64> = arg_opt("max-calldata-size");
+    pub const MAX_CONVERSION_RATE_AGE: ArgOpt<Duration> =
+        arg_opt("max-rate-age");
+    pub const MAX_QUERIED_TRANSFERS: ArgDefault<usize> = arg_default(
+        "max-transfers",
+        DefaultFn(|| DEFAULT_MAX_QUERIED_TRANSFERS),
+    );
     pub const MEMO_OPT: ArgOpt<String> = arg_opt("memo");
+    pub const MIN_BATCH_SIZE: ArgOpt<usize> = arg_opt("min-batch-size");
     pub const MODE: ArgOpt<String> = arg_opt("mode");
     pub const NET_ADDRESS: Arg<SocketAddr> = arg("net-address");
     pub const NAMADA_START_TIME: ArgOpt<DateTimeUtc> = arg_opt("time");
     pub const NO_CONVERSIONS: ArgFlag = flag("no-conversions");
     pub const NUT: ArgFlag = flag("nut");
+    pub const ONLY_PROFITABLE: ArgFlag = flag("only-profitable");
     pub const OUT_FILE_PATH_OPT: ArgOpt<PathBuf> = arg_opt("out-file-path");
     pub const OUTPUT: ArgOpt<PathBuf> = arg_opt("output");
+    pub const OUTPUT_FILE: ArgOpt<PathBuf> = arg_opt("output-file");
     pub const OUTPUT_FOLDER_PATH: ArgOpt<PathBuf> =
         arg_opt("output-folder-path");
     pub const OWNER: Arg<WalletAddress> = arg("owner");
     pub const OWNER_OPT: ArgOpt<WalletAddress> = OWNER.opt();
     pub const PATH: Arg<PathBuf> = arg("path");
     pub const PIN: ArgFlag = flag("pin");
+    pub const POLL_INTERVAL: ArgDefault<Duration> = arg_default(
+        "interval",
+        DefaultFn(|| Duration::from_str("30s").unwrap()),
+    );
     pub const PORT_ID: ArgDefault<PortId> = arg_default(
         "port-id",
         DefaultFn(|| PortId::from_str("transfer").unwrap()),
     );
     pub const PRE_GENESIS: ArgFlag = flag("pre-genesis");
+    pub const PRIVATE_RELAY_URL: ArgOpt<String> = arg_opt("private-relay-url");
+    pub const PRINT_CALLDATA: ArgFlag = flag("print-calldata");
     pub const PROPOSAL_ETH: ArgFlag = flag("eth");
     pub const PROPOSAL_PGF_STEWARD: ArgFlag = flag("pgf-stewards");
     pub const PROPOSAL_PGF_FUNDING: ArgFlag = flag("pgf-funding");
@@ -3056,6 +3452,7 @@ pub mod args {
     pub const PROPOSAL_VOTE_PGF_OPT: ArgOpt<String> = arg_opt("pgf");
     pub const PROPOSAL_VOTE_ETH_OPT: ArgOpt<String> = arg_opt("eth");
     pub const PROPOSAL_VOTE: Arg<String> = arg("vote");
+    pub const PROOF_FILE: ArgOpt<PathBuf> = arg_opt("proof-file");
     pub const RAW_ADDRESS: Arg<Address> = arg("address");
     pub const RAW_ADDRESS_ESTABLISHED: Arg<EstablishedAddress> = arg("address");
     pub const RAW_ADDRESS_OPT: ArgOpt<Address> = RAW_ADDRESS.opt();
@@ -3071,6 +3468,16 @@ pub mod args {
         RAW_PUBLIC_KEY_HASH.opt();
     pub const RECEIVER: Arg<String> = arg("receiver");
     pub const RELAYER: Arg<Address> = arg("relayer");
+    pub const RECOMMEND_OBJECTIVE: ArgDefault<Objective> =
+        arg_default("objective", DefaultFn(Objective::default));
+    pub const RECORD_PROOF: ArgOpt<PathBuf> = arg_opt("record");
+    pub const RELAY_AUDIT_LOG: ArgOpt<PathBuf> = arg_opt("audit-log");
+    pub const RELAY_BUNDLE: Arg<PathBuf> = arg("bundle");
+    pub const RELAY_STATE_FILE: ArgOpt<PathBuf> = arg_opt("relay-state-file");
+    pub const RPC_TIMEOUT: ArgDefault<Duration> = arg_default(
+        "rpc-timeout",
+        DefaultFn(|| Duration::from_str("120s").unwrap()),
+    );
     pub const SAFE_MODE: ArgFlag = flag("safe-mode");
     pub const SCHEME: ArgDefault<SchemeType> =
         arg_default("scheme", DefaultFn(|| SchemeType::Ed25519));
@@ -3079,15 +3486,22 @@ pub mod args {
     pub const SENDER: Arg<String> = arg("sender");
     pub const SHIELDED: ArgFlag = flag("shielded");
     pub const SIGNER: ArgOpt<WalletAddress> = arg_opt("signer");
+    pub const SINCE: ArgOpt<DateTimeUtc> = arg_opt("since");
     pub const SIGNING_KEYS: ArgMulti<WalletPublicKey, GlobStar> =
         arg_multi("signing-keys");
     pub const SIGNATURES: ArgMulti<PathBuf, GlobStar> = arg_multi("signatures");
     pub const SOURCE: Arg<WalletAddress> = arg("source");
     pub const SOURCE_OPT: ArgOpt<WalletAddress> = SOURCE.opt();
+    pub const STALL_TIMEOUT: ArgDefault<Duration> = arg_default(
+        "stall-timeout",
+        DefaultFn(|| Duration::from_str("60s").unwrap()),
+    );
     pub const STEWARD: Arg<WalletAddress> = arg("steward");
     pub const SOURCE_VALIDATOR: Arg<WalletAddress> = arg("source-validator");
+    pub const SORT_BY_HASH: ArgFlag = flag("sort-by-hash");
     pub const STORAGE_KEY: Arg<storage::Key> = arg("storage-key");
     pub const SUSPEND_ACTION: ArgFlag = flag("suspend");
+    pub const TARGET_ROOT: ArgOpt<BlockHeight> = arg_opt("target-root");
     pub const TEMPLATES_PATH: Arg<PathBuf> = arg("templates-path");
     pub const TIMEOUT_HEIGHT: ArgOpt<u64> = arg_opt("timeout-height");
     pub const TIMEOUT_SEC_OFFSET: ArgOpt<u64> = arg_opt("timeout-sec-offset");
@@ -3095,6 +3509,9 @@ pub mod args {
     pub const TOKEN_OPT: ArgOpt<WalletAddress> = TOKEN.opt();
     pub const TOKEN: Arg<WalletAddress> = arg("token");
     pub const TOKEN_STR: Arg<String> = arg("token");
+    pub const TRACE_RECOMMENDER: ArgFlag = flag("trace");
+    pub const TRANSFER_BREAKDOWN: ArgFlag = flag("transfer-breakdown");
+    pub const TRANSFER_KIND: ArgOpt<TransferToEthereumKind> = arg_opt("kind");
     pub const TRANSFER_SOURCE: Arg<WalletTransferSource> = arg("source");
     pub const TRANSFER_TARGET: Arg<WalletTransferTarget> = arg("target");
     pub const TRANSPARENT: ArgFlag = flag("transparent");
@@ -3124,6 +3541,7 @@ pub mod args {
     pub const WALLET_ALIAS_FORCE: ArgFlag = flag("wallet-alias-force");
     pub const WASM_CHECKSUMS_PATH: Arg<PathBuf> = arg("wasm-checksums-path");
     pub const WASM_DIR: ArgOpt<PathBuf> = arg_opt("wasm-dir");
+    pub const WATCH_INTERVAL: ArgOpt<Duration> = arg_opt("watch");
     pub const WEBSITE_OPT: ArgOpt<String> = arg_opt("website");
     pub const TX_PATH: Arg<PathBuf> = arg("tx-path");
     pub const TX_PATH_OPT: ArgOpt<PathBuf> = TX_PATH.opt();
@@ -3317,216 +3735,906 @@ pub mod args {
         fn to_sdk_ctxless(self) -> SDK;
     }
 
-    impl<CLI, SDK> CliToSdk<SDK> for CLI
-    where
-        CLI: Args + CliToSdkCtxless<SDK>,
-    {
-        #[inline]
-        fn to_sdk(self, _: &mut Context) -> SDK {
-            self.to_sdk_ctxless()
+    impl<CLI, SDK> CliToSdk<SDK> for CLI
+    where
+        CLI: Args + CliToSdkCtxless<SDK>,
+    {
+        #[inline]
+        fn to_sdk(self, _: &mut Context) -> SDK {
+            self.to_sdk_ctxless()
+        }
+    }
+
+    impl CliToSdk<QueryResult<SdkTypes>> for QueryResult<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> QueryResult<SdkTypes> {
+            QueryResult::<SdkTypes> {
+                query: self.query.to_sdk(ctx),
+                tx_hash: self.tx_hash,
+            }
+        }
+    }
+
+    impl Args for QueryResult<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let tx_hash = TX_HASH.parse(matches);
+            Self { query, tx_hash }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>().arg(
+                TX_HASH
+                    .def()
+                    .help("The hash of the transaction being looked up."),
+            )
+        }
+    }
+
+    impl CliToSdk<EthereumBridgePool<SdkTypes>> for EthereumBridgePool<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> EthereumBridgePool<SdkTypes> {
+            let tx = self.tx.to_sdk(ctx);
+            let chain_ctx = ctx.borrow_chain_or_exit();
+            EthereumBridgePool::<SdkTypes> {
+                kind: self.kind,
+                tx,
+                asset: self.asset,
+                recipient: self.recipient,
+                sender: chain_ctx.get(&self.sender),
+                amount: self.amount,
+                erc20_decimals: self.erc20_decimals,
+                fee_amount: self.fee_amount,
+                fee_payer: self
+                    .fee_payer
+                    .map(|fee_payer| chain_ctx.get(&fee_payer)),
+                fee_token: chain_ctx.get(&self.fee_token).into(),
+                code_path: self.code_path,
+                describe: self.describe,
+            }
+        }
+    }
+
+    impl Args for EthereumBridgePool<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let asset = ERC20.parse(matches);
+            let recipient = BRIDGE_POOL_TARGET.parse(matches);
+            let sender = SOURCE.parse(matches);
+            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let erc20_decimals = ERC20_DECIMALS.parse(matches);
+            let fee_amount =
+                InputAmount::Unvalidated(BRIDGE_POOL_GAS_AMOUNT.parse(matches));
+            let fee_payer = BRIDGE_POOL_GAS_PAYER.parse(matches);
+            let fee_token = BRIDGE_POOL_GAS_TOKEN.parse(matches);
+            let code_path = PathBuf::from(TX_BRIDGE_POOL_WASM);
+            let kind = TRANSFER_KIND.parse(matches).unwrap_or(
+                // NB: the `--nut` flag is deprecated in favor of `--kind`
+                if NUT.parse(matches) {
+                    TransferToEthereumKind::Nut
+                } else {
+                    TransferToEthereumKind::Erc20
+                },
+            );
+            let describe = DESCRIBE_TX.parse(matches);
+            Self {
+                tx,
+                asset,
+                recipient,
+                sender,
+                amount,
+                erc20_decimals,
+                fee_amount,
+                fee_payer,
+                fee_token,
+                code_path,
+                kind,
+                describe,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(
+                    ERC20
+                        .def()
+                        .help("The Ethereum address of the ERC20 token."),
+                )
+                .arg(
+                    BRIDGE_POOL_TARGET
+                        .def()
+                        .help("The Ethereum address receiving the tokens."),
+                )
+                .arg(
+                    SOURCE.def().help("The Namada address sending the tokens."),
+                )
+                .arg(
+                    AMOUNT.def().help(
+                        "The amount of tokens being sent across the bridge.",
+                    ),
+                )
+                .arg(ERC20_DECIMALS.def().help(
+                    "The decimal places of the ERC20 token. If provided, the \
+                     amount is validated against them, and the transfer is \
+                     rejected if they do not match the denomination of the \
+                     wrapped token in Namada.",
+                ))
+                .arg(BRIDGE_POOL_GAS_AMOUNT.def().help(
+                    "The amount of gas you wish to pay to have this transfer \
+                     relayed to Ethereum. This is escrowed in the Bridge \
+                     pool, and is distinct from the fee of the wrapper tx \
+                     set with --gas-price, --gas-limit and --gas-token.",
+                ))
+                .arg(BRIDGE_POOL_GAS_PAYER.def().help(
+                    "The Namada address, or wallet alias, of the account \
+                     paying the gas. By default, it is the same as the source.",
+                ))
+                .arg(BRIDGE_POOL_GAS_TOKEN.def().help(
+                    "The token for paying the Bridge pool gas fees. Defaults \
+                     to NAM. The token paying the fee of the wrapper tx is \
+                     set with --gas-token.",
+                ))
+                .arg(TRANSFER_KIND.def().help(
+                    "The kind of the transfer, one of `erc20` or `nut`. NUT \
+                     transfers add Non Usable Tokens (NUTs) to the Bridge \
+                     pool. These are usually obtained from invalid transfers \
+                     to Namada. Defaults to `erc20`.",
+                ))
+                .arg(
+                    NUT.def()
+                        .help(
+                            "Deprecated, use `--kind nut` instead. Add Non \
+                             Usable Tokens (NUTs) to the Bridge pool.",
+                        )
+                        .conflicts_with(TRANSFER_KIND.name),
+                )
+                .arg(DESCRIBE_TX.def().help(
+                    "Print a description of the built transaction, without \
+                     signing or submitting it.",
+                ))
+        }
+    }
+
+    impl CliToSdk<RecommendBatch<SdkTypes>> for RecommendBatch<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> RecommendBatch<SdkTypes> {
+            let query = self.query.to_sdk(ctx);
+            let chain_ctx = ctx.borrow_chain_or_exit();
+            RecommendBatch::<SdkTypes> {
+                query,
+                max_gas: self.max_gas,
+                gas: self.gas,
+                only_profitable: self.only_profitable,
+                in_progress_threshold: self.in_progress_threshold,
+                rpc_timeout: self.rpc_timeout,
+                objective: self.objective,
+                max_rate_age: self.max_rate_age,
+                sort_by_hash: self.sort_by_hash,
+                min_batch_size: self.min_batch_size,
+                trace: self.trace,
+                max_transfers: self.max_transfers,
+                conversion_table: read_bp_conversion_table(
+                    &self.conversion_table,
+                    |token| {
+                        let token_from_ctx = FromContext::<Address>::new(token);
+                        let address = chain_ctx.get(&token_from_ctx);
+                        (address, token_from_ctx.raw)
+                    },
+                ),
+            }
+        }
+    }
+
+    impl CliToSdk<BridgePoolFeeHistogram<SdkTypes>>
+        for BridgePoolFeeHistogram<CliTypes>
+    {
+        fn to_sdk(self, ctx: &mut Context) -> BridgePoolFeeHistogram<SdkTypes> {
+            let query = self.query.to_sdk(ctx);
+            let chain_ctx = ctx.borrow_chain_or_exit();
+            BridgePoolFeeHistogram::<SdkTypes> {
+                query,
+                buckets: self.buckets,
+                json: self.json,
+                conversion_table: read_bp_conversion_table(
+                    &self.conversion_table,
+                    |token| {
+                        let token_from_ctx = FromContext::<Address>::new(token);
+                        let address = chain_ctx.get(&token_from_ctx);
+                        (address, token_from_ctx.raw)
+                    },
+                ),
+            }
+        }
+    }
+
+    impl CliToSdk<PreviewRelay<SdkTypes>> for PreviewRelay<CliTypes> {
+        fn to_sdk(self, ctx: &mut Context) -> PreviewRelay<SdkTypes> {
+            PreviewRelay::<SdkTypes> {
+                recommend: self.recommend.to_sdk(ctx),
+                relayer: self.relayer,
+                eth_rpc_endpoint: (),
+                eth_addr: self.eth_addr,
+                request_timeout: self.request_timeout,
+            }
+        }
+    }
+
+    impl Args for PreviewRelay<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let recommend = RecommendBatch::parse(matches);
+            let relayer = RELAYER.parse(matches);
+            let eth_rpc_endpoint = ETH_RPC_ENDPOINT.parse(matches);
+            let eth_addr = ETH_ADDRESS_OPT.parse(matches);
+            let request_timeout =
+                ETH_REQUEST_TIMEOUT.parse(matches).map(|dur| dur.0);
+            Self {
+                recommend,
+                relayer,
+                eth_rpc_endpoint,
+                eth_addr,
+                request_timeout,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<RecommendBatch<CliTypes>>()
+                .arg(
+                    RELAYER
+                        .def()
+                        .help("The rewards address for relaying the proof."),
+                )
+                .arg(ETH_RPC_ENDPOINT.def().help("The Ethereum RPC endpoint."))
+                .arg(ETH_ADDRESS_OPT.def().help(
+                    "The address of the Ethereum wallet to dry-run the relay \
+                     from. If unset, the default wallet is used.",
+                ))
+                .arg(ETH_REQUEST_TIMEOUT.def().help(
+                    "The maximum amount of time to wait on each request to \
+                     the Ethereum node. Can also be set through the \
+                     NAMADA_ETH_REQUEST_TIMEOUT environment variable.",
+                ))
+        }
+    }
+
+    impl Args for BridgePoolFeeHistogram<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let conversion_table = CONVERSION_TABLE.parse(matches);
+            let buckets = HISTOGRAM_BUCKETS.parse(matches);
+            let json = JSON.parse(matches);
+            Self {
+                query,
+                conversion_table,
+                buckets,
+                json,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(CONVERSION_TABLE.def().help(
+                    "Path to a JSON object containing a mapping between token \
+                     aliases (or addresses) and their conversion rates in gwei",
+                ))
+                .arg(HISTOGRAM_BUCKETS.def().help(
+                    "The number of buckets of equal width to split the fees \
+                     paid by the transfers into, between 1 and 100. Defaults \
+                     to 10.",
+                ))
+                .arg(JSON.def().help(
+                    "Print the histogram and the minimum fees required to \
+                     rank among the top transfers as JSON.",
+                ))
+        }
+    }
+
+    /// A conversion rate in a Bridge pool conversion rates table,
+    /// optionally timestamped with the time at which it was set,
+    /// and weighted relative to the rates of other tokens.
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum ConversionTableRate {
+        Rate(f64),
+        Detailed {
+            rate: f64,
+            #[serde(default)]
+            as_of: Option<DateTimeUtc>,
+            #[serde(default)]
+            weight: Option<f64>,
+        },
+    }
+
+    /// Read a Bridge pool conversion rates table from a JSON file,
+    /// resolving the address and alias of its tokens with
+    /// `resolve_token`.
+    fn read_bp_conversion_table(
+        path: &PathBuf,
+        mut resolve_token: impl FnMut(String) -> (Address, String),
+    ) -> HashMap<Address, BpConversionTableEntry> {
+        let file = std::io::BufReader::new(std::fs::File::open(path).expect(
+            "Failed to open the provided file to the conversion table",
+        ));
+        let table: HashMap<String, ConversionTableRate> =
+            serde_json::from_reader(file)
+                .expect("Failed to parse conversion table");
+        table
+            .into_iter()
+            .map(|(token, rate)| {
+                let (address, alias) = resolve_token(token);
+                let (conversion_rate, as_of, fee_weight) = match rate {
+                    ConversionTableRate::Rate(rate) => (rate, None, None),
+                    ConversionTableRate::Detailed {
+                        rate,
+                        as_of,
+                        weight,
+                    } => (rate, as_of, weight),
+                };
+                (
+                    address,
+                    BpConversionTableEntry {
+                        alias,
+                        conversion_rate,
+                        as_of,
+                        fee_weight,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    impl Args for RecommendBatch<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let max_gas = MAX_ETH_GAS.parse(matches);
+            let gas = ETH_GAS.parse(matches);
+            let conversion_table = CONVERSION_TABLE.parse(matches);
+            let only_profitable = ONLY_PROFITABLE.parse(matches);
+            let in_progress_threshold = IN_PROGRESS_THRESHOLD.parse(matches);
+            let rpc_timeout = RPC_TIMEOUT.parse(matches).0;
+            let objective = RECOMMEND_OBJECTIVE.parse(matches);
+            let max_rate_age =
+                MAX_CONVERSION_RATE_AGE.parse(matches).map(|dur| dur.0);
+            let sort_by_hash = SORT_BY_HASH.parse(matches);
+            let min_batch_size = MIN_BATCH_SIZE.parse(matches);
+            let trace = TRACE_RECOMMENDER.parse(matches);
+            let max_transfers = MAX_QUERIED_TRANSFERS.parse(matches);
+            Self {
+                query,
+                max_gas,
+                gas,
+                conversion_table,
+                only_profitable,
+                in_progress_threshold,
+                rpc_timeout,
+                objective,
+                max_rate_age,
+                sort_by_hash,
+                min_batch_size,
+                trace,
+                max_transfers,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(MAX_ETH_GAS.def().help(
+                    "The maximum amount Ethereum gas that can be spent during \
+                     the relay call.",
+                ))
+                .arg(ETH_GAS.def().help(
+                    "Under ideal conditions, relaying transfers will yield a \
+                     net profit. If that is not possible, setting this \
+                     optional value will result in a batch transfer that \
+                     costs as close to the given value as possible without \
+                     exceeding it.",
+                ))
+                .arg(CONVERSION_TABLE.def().help(
+                    "Path to a JSON object containing a mapping between token \
+                     aliases (or addresses) and their conversion rates in \
+                     gwei. Rates may be given as objects such as {\"rate\": \
+                     1.5, \"as_of\": \"2024-01-01T00:00:00Z\", \"weight\": \
+                     2.0}, recording when they were set, and how much the \
+                     fees paid in the token are valued relative to other \
+                     tokens.",
+                ))
+                .arg(ONLY_PROFITABLE.def().help(
+                    "Exit with an error if no batch of transfers yielding a \
+                     net profit can be recommended, rather than merely \
+                     reporting it.",
+                ))
+                .arg(IN_PROGRESS_THRESHOLD.def().help(
+                    "Transfers backed by more than this fraction of the \
+                     voting power (e.g. 1/3) are deemed likely to have \
+                     already been relayed, and are not recommended. By \
+                     default, any transfer with some backing is excluded.",
+                ))
+                .arg(RPC_TIMEOUT.def().help(
+                    "The maximum amount of time to wait for each query to the \
+                     ledger node to complete, before giving up on the \
+                     recommendation. Defaults to 2 minutes.",
+                ))
+                .arg(RECOMMEND_OBJECTIVE.def().help(
+                    "The objective optimized by the recommended batch: \
+                     max-profit maximizes the net profit of the relayer, \
+                     while max-throughput relays as many transfers as \
+                     possible, as long as the net cost of the relay does not \
+                     exceed the --gas budget. Defaults to max-profit.",
+                ))
+                .arg(MAX_CONVERSION_RATE_AGE.def().help(
+                    "Do not recommend transfers paying fees in tokens whose \
+                     conversion rate was set longer than this amount of time \
+                     ago. Only rates timestamped in the conversion table are \
+                     checked. If unset, the age of rates is not checked.",
+                ))
+                .arg(SORT_BY_HASH.def().help(
+                    "Additionally list the hashes of the recommended \
+                     transfers sorted lexicographically, which is stable \
+                     across runs recommending the same batch. The selected \
+                     transfers are not affected.",
+                ))
+                .arg(MIN_BATCH_SIZE.def().help(
+                    "Do not recommend batches with fewer transfers than this, \
+                     to amortize the fixed cost of checking the signatures \
+                     of the validator set over several transfers.",
+                ))
+                .arg(TRACE_RECOMMENDER.def().help(
+                    "Print the decision of the recommender for each eligible \
+                     transfer, in the order they are considered, along with \
+                     the running totals of the batch and the constraint \
+                     that governed the decision.",
+                ))
+                .arg(
+                    MAX_QUERIED_TRANSFERS
+                        .def()
+                        .help(MAX_QUERIED_TRANSFERS_ABOUT),
+                )
+        }
+    }
+
+    impl CliToSdkCtxless<BridgePoolProof<SdkTypes>> for BridgePoolProof<CliTypes> {
+        fn to_sdk_ctxless(self) -> BridgePoolProof<SdkTypes> {
+            BridgePoolProof::<SdkTypes> {
+                ledger_address: self.ledger_address,
+                transfers: self.transfers,
+                relayer: self.relayer,
+                fail_on_warning: self.fail_on_warning,
+                compress_proof: self.compress_proof,
+                target_root: self.target_root,
+                transfer_breakdown: self.transfer_breakdown,
+                conversion_table: self.conversion_table.map(|path| {
+                    read_bp_conversion_table(&path, |token| {
+                        let address =
+                            Address::decode(&token).unwrap_or_else(|_| {
+                                tracing::info!(
+                                    "Could not parse '{token}' as an address \
+                                     in the conversion table."
+                                );
+                                safe_exit(1)
+                            });
+                        (address, token)
+                    })
+                }),
+            }
+        }
+    }
+
+    impl Args for BridgePoolProof<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let ledger_address = LEDGER_ADDRESS.parse(matches);
+            let hashes = HASH_LIST.parse(matches);
+            let relayer = RELAYER.parse(matches);
+            let fail_on_warning = FAIL_ON_WARNING.parse(matches);
+            let compress_proof = COMPRESS_PROOF.parse(matches);
+            let conversion_table = CONVERSION_TABLE_OPT.parse(matches);
+            let target_root = TARGET_ROOT.parse(matches);
+            let transfer_breakdown = TRANSFER_BREAKDOWN.parse(matches);
+            Self {
+                ledger_address,
+                transfers: hashes
+                    .split_whitespace()
+                    .map(|hash| {
+                        KeccakHash::try_from(hash).unwrap_or_else(|_| {
+                            tracing::info!(
+                                "Could not parse '{}' as a Keccak hash.",
+                                hash
+                            );
+                            safe_exit(1)
+                        })
+                    })
+                    .collect(),
+                relayer,
+                fail_on_warning,
+                compress_proof,
+                conversion_table,
+                target_root,
+                transfer_breakdown,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(LEDGER_ADDRESS.def().help(LEDGER_ADDRESS_ABOUT))
+                .arg(HASH_LIST.def().help(
+                    "Whitespace separated Keccak hash list of transfers in \
+                     the Bridge pool.",
+                ))
+                .arg(
+                    RELAYER
+                        .def()
+                        .help("The rewards address for relaying this proof."),
+                )
+                .arg(
+                    FAIL_ON_WARNING
+                        .def()
+                        .help(FAIL_ON_WARNING_ABOUT)
+                        // Lets scripts disable the only prompt of these
+                        // commands under a familiar name
+                        .alias("no-prompt"),
+                )
+                .arg(COMPRESS_PROOF.def().help(
+                    "Compress the ABI encoded proof with gzip. The encoding \
+                     of the proof is indicated in the output.",
+                ))
+                .arg(CONVERSION_TABLE_OPT.def().help(
+                    "Path to a JSON object containing a mapping between token \
+                     addresses and their conversion rates in gwei. If set, \
+                     the total fees of the proof are also expressed in gwei.",
+                ))
+                .arg(TARGET_ROOT.def().help(TARGET_ROOT_ABOUT))
+                .arg(TRANSFER_BREAKDOWN.def().help(
+                    "Report the marginal Ethereum gas of relaying each \
+                     transfer in the proof, along with the fees it pays. \
+                     Fees are also expressed in gwei, if a conversion table \
+                     is provided.",
+                ))
+        }
+    }
+
+    impl CliToSdkCtxless<QueryBridgePool<SdkTypes>> for QueryBridgePool<CliTypes> {
+        fn to_sdk_ctxless(self) -> QueryBridgePool<SdkTypes> {
+            QueryBridgePool::<SdkTypes> {
+                ledger_address: self.ledger_address,
+                lifecycle: self.lifecycle,
+                output: self.output,
+                rpc_path: self.rpc_path,
+                max_transfers: self.max_transfers,
+            }
+        }
+    }
+
+    impl Args for QueryBridgePool<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let ledger_address = LEDGER_ADDRESS.parse(matches);
+            let lifecycle = LIFECYCLE.parse(matches);
+            let output = OUTPUT_FILE.parse(matches);
+            let rpc_path = ETH_BRIDGE_RPC_PATH.parse(matches);
+            let max_transfers = MAX_QUERIED_TRANSFERS.parse(matches);
+            Self {
+                ledger_address,
+                lifecycle,
+                output,
+                rpc_path,
+                max_transfers,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(LEDGER_ADDRESS.def().help(LEDGER_ADDRESS_ABOUT))
+                .arg(LIFECYCLE.def().help(
+                    "Annotate each transfer with the block heights and times \
+                     at which it was added to the Bridge pool and covered by \
+                     a signed root, and with the voting power that has seen \
+                     it being relayed. This requires additional queries.",
+                ))
+                .arg(OUTPUT_FILE.def().help(QUERY_OUTPUT_FILE_ABOUT))
+                .arg(ETH_BRIDGE_RPC_PATH.def().help(ETH_BRIDGE_RPC_PATH_ABOUT))
+                .arg(
+                    MAX_QUERIED_TRANSFERS
+                        .def()
+                        .help(MAX_QUERIED_TRANSFERS_ABOUT),
+                )
+        }
+    }
+
+    impl CliToSdkCtxless<QuerySignedBridgePool<SdkTypes>>
+        for QuerySignedBridgePool<CliTypes>
+    {
+        fn to_sdk_ctxless(self) -> QuerySignedBridgePool<SdkTypes> {
+            QuerySignedBridgePool::<SdkTypes> {
+                ledger_address: self.ledger_address,
+                output: self.output,
+                rpc_path: self.rpc_path,
+                max_transfers: self.max_transfers,
+            }
+        }
+    }
+
+    impl Args for QuerySignedBridgePool<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let ledger_address = LEDGER_ADDRESS.parse(matches);
+            let output = OUTPUT_FILE.parse(matches);
+            let rpc_path = ETH_BRIDGE_RPC_PATH.parse(matches);
+            let max_transfers = MAX_QUERIED_TRANSFERS.parse(matches);
+            Self {
+                ledger_address,
+                output,
+                rpc_path,
+                max_transfers,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(LEDGER_ADDRESS.def().help(LEDGER_ADDRESS_ABOUT))
+                .arg(OUTPUT_FILE.def().help(QUERY_OUTPUT_FILE_ABOUT))
+                .arg(ETH_BRIDGE_RPC_PATH.def().help(ETH_BRIDGE_RPC_PATH_ABOUT))
+                .arg(
+                    MAX_QUERIED_TRANSFERS
+                        .def()
+                        .help(MAX_QUERIED_TRANSFERS_ABOUT),
+                )
+        }
+    }
+
+    impl CliToSdkCtxless<BridgePoolStatus<SdkTypes>>
+        for BridgePoolStatus<CliTypes>
+    {
+        fn to_sdk_ctxless(self) -> BridgePoolStatus<SdkTypes> {
+            BridgePoolStatus::<SdkTypes> {
+                ledger_address: self.ledger_address,
+                eth_rpc_endpoint: self.eth_rpc_endpoint.map(|_| ()),
+            }
+        }
+    }
+
+    impl Args for BridgePoolStatus<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let ledger_address = LEDGER_ADDRESS.parse(matches);
+            let eth_rpc_endpoint = ETH_RPC_ENDPOINT_OPT.parse(matches);
+            Self {
+                ledger_address,
+                eth_rpc_endpoint,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(LEDGER_ADDRESS.def().help(LEDGER_ADDRESS_ABOUT))
+                .arg(ETH_RPC_ENDPOINT_OPT.def().help(
+                    "The Ethereum RPC endpoint, used to query the nonce of \
+                     the Bridge contract. If unset, the nonce is not queried.",
+                ))
+        }
+    }
+
+    impl CliToSdkCtxless<BridgeConsistency<SdkTypes>>
+        for BridgeConsistency<CliTypes>
+    {
+        fn to_sdk_ctxless(self) -> BridgeConsistency<SdkTypes> {
+            BridgeConsistency::<SdkTypes> {
+                ledger_address: self.ledger_address,
+                eth_rpc_endpoint: (),
+            }
+        }
+    }
+
+    impl Args for BridgeConsistency<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let ledger_address = LEDGER_ADDRESS.parse(matches);
+            let eth_rpc_endpoint = ETH_RPC_ENDPOINT.parse(matches);
+            Self {
+                ledger_address,
+                eth_rpc_endpoint,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(LEDGER_ADDRESS.def().help(LEDGER_ADDRESS_ABOUT))
+                .arg(ETH_RPC_ENDPOINT.def().help("The Ethereum RPC endpoint."))
+        }
+    }
+
+    impl CliToSdkCtxless<BridgePoolTransfers<SdkTypes>>
+        for BridgePoolTransfers<CliTypes>
+    {
+        fn to_sdk_ctxless(self) -> BridgePoolTransfers<SdkTypes> {
+            BridgePoolTransfers::<SdkTypes> {
+                ledger_address: self.ledger_address,
+                transfers: self.transfers,
+            }
+        }
+    }
+
+    impl CliToSdkCtxless<RelayProgress<SdkTypes>> for RelayProgress<CliTypes> {
+        fn to_sdk_ctxless(self) -> RelayProgress<SdkTypes> {
+            RelayProgress::<SdkTypes> {
+                ledger_address: self.ledger_address,
+                watch: self.watch,
+                stall_timeout: self.stall_timeout,
+                output: self.output,
+            }
+        }
+    }
+
+    impl Args for RelayProgress<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let ledger_address = LEDGER_ADDRESS.parse(matches);
+            let watch = WATCH_INTERVAL.parse(matches).map(|dur| dur.0);
+            let stall_timeout = STALL_TIMEOUT.parse(matches).0;
+            let output = OUTPUT_FILE.parse(matches);
+            Self {
+                ledger_address,
+                watch,
+                stall_timeout,
+                output,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(LEDGER_ADDRESS.def().help(LEDGER_ADDRESS_ABOUT))
+                .arg(WATCH_INTERVAL.def().help(
+                    "Keep polling the relay progress at the given interval, \
+                     reporting the rate at which each transfer gains backing \
+                     voting power.",
+                ))
+                .arg(STALL_TIMEOUT.def().help(
+                    "The amount of time after which a watched transfer that \
+                     has not gained any voting power is flagged as stalled. \
+                     Defaults to 1 minute.",
+                ))
+                .arg(OUTPUT_FILE.def().help(
+                    "Write the json payload of the query to the given file, \
+                     instead of printing it out. Parent directories are \
+                     created as needed. Ignored when watching the relay \
+                     progress.",
+                ))
         }
     }
 
-    impl CliToSdk<QueryResult<SdkTypes>> for QueryResult<CliTypes> {
-        fn to_sdk(self, ctx: &mut Context) -> QueryResult<SdkTypes> {
-            QueryResult::<SdkTypes> {
-                query: self.query.to_sdk(ctx),
-                tx_hash: self.tx_hash,
+    impl CliToSdkCtxless<MonitorBridgePoolGrowth<SdkTypes>>
+        for MonitorBridgePoolGrowth<CliTypes>
+    {
+        fn to_sdk_ctxless(self) -> MonitorBridgePoolGrowth<SdkTypes> {
+            MonitorBridgePoolGrowth::<SdkTypes> {
+                ledger_address: self.ledger_address,
+                interval: self.interval,
+                window: self.window,
             }
         }
     }
 
-    impl Args for QueryResult<CliTypes> {
+    impl Args for MonitorBridgePoolGrowth<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
-            let query = Query::parse(matches);
-            let tx_hash = TX_HASH.parse(matches);
-            Self { query, tx_hash }
+            let ledger_address = LEDGER_ADDRESS.parse(matches);
+            let interval = POLL_INTERVAL.parse(matches).0;
+            let window = GROWTH_WINDOW.parse(matches).0;
+            Self {
+                ledger_address,
+                interval,
+                window,
+            }
         }
 
         fn def(app: App) -> App {
-            app.add_args::<Query<CliTypes>>().arg(
-                TX_HASH
-                    .def()
-                    .help("The hash of the transaction being looked up."),
-            )
+            app.arg(LEDGER_ADDRESS.def().help(LEDGER_ADDRESS_ABOUT))
+                .arg(POLL_INTERVAL.def().help(
+                    "The interval at which the Bridge pool is polled. \
+                     Defaults to 30 seconds.",
+                ))
+                .arg(GROWTH_WINDOW.def().help(
+                    "The window of time over which growth rates are \
+                     estimated. Defaults to 10 minutes.",
+                ))
         }
     }
 
-    impl CliToSdk<EthereumBridgePool<SdkTypes>> for EthereumBridgePool<CliTypes> {
-        fn to_sdk(self, ctx: &mut Context) -> EthereumBridgePool<SdkTypes> {
-            let tx = self.tx.to_sdk(ctx);
-            let chain_ctx = ctx.borrow_chain_or_exit();
-            EthereumBridgePool::<SdkTypes> {
-                nut: self.nut,
-                tx,
-                asset: self.asset,
-                recipient: self.recipient,
-                sender: chain_ctx.get(&self.sender),
-                amount: self.amount,
-                fee_amount: self.fee_amount,
-                fee_payer: self
-                    .fee_payer
-                    .map(|fee_payer| chain_ctx.get(&fee_payer)),
-                fee_token: chain_ctx.get(&self.fee_token).into(),
-                code_path: self.code_path,
+    impl CliToSdkCtxless<RelayerFeeLedger<SdkTypes>>
+        for RelayerFeeLedger<CliTypes>
+    {
+        fn to_sdk_ctxless(self) -> RelayerFeeLedger<SdkTypes> {
+            RelayerFeeLedger::<SdkTypes> {
+                ledger_address: self.ledger_address,
+                ledger_file: self.ledger_file,
+                record: self.record,
+                eth_rpc_endpoint: self.eth_rpc_endpoint.map(|_| ()),
+                since: self.since,
             }
         }
     }
 
-    impl Args for EthereumBridgePool<CliTypes> {
+    impl Args for RelayerFeeLedger<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
-            let tx = Tx::parse(matches);
-            let asset = ERC20.parse(matches);
-            let recipient = BRIDGE_POOL_TARGET.parse(matches);
-            let sender = SOURCE.parse(matches);
-            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
-            let fee_amount =
-                InputAmount::Unvalidated(BRIDGE_POOL_GAS_AMOUNT.parse(matches));
-            let fee_payer = BRIDGE_POOL_GAS_PAYER.parse(matches);
-            let fee_token = BRIDGE_POOL_GAS_TOKEN.parse(matches);
-            let code_path = PathBuf::from(TX_BRIDGE_POOL_WASM);
-            let nut = NUT.parse(matches);
+            let ledger_address = LEDGER_ADDRESS.parse(matches);
+            let ledger_file = FEE_LEDGER_FILE.parse(matches);
+            let record = RECORD_PROOF.parse(matches);
+            let eth_rpc_endpoint = ETH_RPC_ENDPOINT_OPT.parse(matches);
+            let since = SINCE.parse(matches);
             Self {
-                tx,
-                asset,
-                recipient,
-                sender,
-                amount,
-                fee_amount,
-                fee_payer,
-                fee_token,
-                code_path,
-                nut,
+                ledger_address,
+                ledger_file,
+                record,
+                eth_rpc_endpoint,
+                since,
             }
         }
 
         fn def(app: App) -> App {
-            app.add_args::<Tx<CliTypes>>()
-                .arg(
-                    ERC20
-                        .def()
-                        .help("The Ethereum address of the ERC20 token."),
-                )
+            app.arg(LEDGER_ADDRESS.def().help(LEDGER_ADDRESS_ABOUT))
+                .arg(FEE_LEDGER_FILE.def().help(
+                    "Path to the file where the relayed batches are \
+                     recorded. It is created if it does not exist yet.",
+                ))
                 .arg(
-                    BRIDGE_POOL_TARGET
+                    RECORD_PROOF
                         .def()
-                        .help("The Ethereum address receiving the tokens."),
-                )
-                .arg(
-                    SOURCE.def().help("The Namada address sending the tokens."),
-                )
-                .arg(
-                    AMOUNT.def().help(
-                        "The amount of tokens being sent across the bridge.",
-                    ),
+                        .help(
+                            "Path to a proof output by the construct-proof \
+                             command, whose batch of transfers is recorded as \
+                             relayed. The batch is only recorded once the \
+                             Bridge contract has moved past its nonce.",
+                        )
+                        .requires(ETH_RPC_ENDPOINT_OPT.name),
                 )
-                .arg(BRIDGE_POOL_GAS_AMOUNT.def().help(
-                    "The amount of gas you wish to pay to have this transfer \
-                     relayed to Ethereum.",
-                ))
-                .arg(BRIDGE_POOL_GAS_PAYER.def().help(
-                    "The Namada address of the account paying the gas. By \
-                     default, it is the same as the source.",
+                .arg(ETH_RPC_ENDPOINT_OPT.def().help(
+                    "The Ethereum RPC endpoint, used to check that the \
+                     recorded batch has been relayed.",
                 ))
-                .arg(BRIDGE_POOL_GAS_TOKEN.def().help(
-                    "The token for paying the Bridge pool gas fees. Defaults \
-                     to NAM.",
+                .arg(SINCE.def().help(
+                    "Only report the fees of the batches recorded since the \
+                     given RFC 3339 time.",
                 ))
-                .arg(NUT.def().help(
-                    "Add Non Usable Tokens (NUTs) to the Bridge pool. These \
-                     are usually obtained from invalid transfers to Namada.",
-                ))
-        }
-    }
-
-    impl CliToSdk<RecommendBatch<SdkTypes>> for RecommendBatch<CliTypes> {
-        fn to_sdk(self, ctx: &mut Context) -> RecommendBatch<SdkTypes> {
-            let query = self.query.to_sdk(ctx);
-            let chain_ctx = ctx.borrow_chain_or_exit();
-            RecommendBatch::<SdkTypes> {
-                query,
-                max_gas: self.max_gas,
-                gas: self.gas,
-                conversion_table: {
-                    let file = std::io::BufReader::new(
-                        std::fs::File::open(self.conversion_table).expect(
-                            "Failed to open the provided file to the \
-                             conversion table",
-                        ),
-                    );
-                    let table: HashMap<String, f64> =
-                        serde_json::from_reader(file)
-                            .expect("Failed to parse conversion table");
-                    table
-                        .into_iter()
-                        .map(|(token, conversion_rate)| {
-                            let token_from_ctx =
-                                FromContext::<Address>::new(token);
-                            let address = chain_ctx.get(&token_from_ctx);
-                            let alias = token_from_ctx.raw;
-                            (
-                                address,
-                                BpConversionTableEntry {
-                                    alias,
-                                    conversion_rate,
-                                },
-                            )
-                        })
-                        .collect()
-                },
-            }
         }
     }
 
-    impl Args for RecommendBatch<CliTypes> {
+    impl Args for BridgePoolTransfers<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
-            let query = Query::parse(matches);
-            let max_gas = MAX_ETH_GAS.parse(matches);
-            let gas = ETH_GAS.parse(matches);
-            let conversion_table = CONVERSION_TABLE.parse(matches);
+            let ledger_address = LEDGER_ADDRESS.parse(matches);
+            let hashes = HASH_LIST.parse(matches);
             Self {
-                query,
-                max_gas,
-                gas,
-                conversion_table,
+                ledger_address,
+                transfers: hashes
+                    .split_whitespace()
+                    .map(|hash| {
+                        KeccakHash::try_from(hash).unwrap_or_else(|_| {
+                            tracing::info!(
+                                "Could not parse '{}' as a Keccak hash.",
+                                hash
+                            );
+                            safe_exit(1)
+                        })
+                    })
+                    .collect(),
             }
         }
 
         fn def(app: App) -> App {
-            app.add_args::<Query<CliTypes>>()
-                .arg(MAX_ETH_GAS.def().help(
-                    "The maximum amount Ethereum gas that can be spent during \
-                     the relay call.",
-                ))
-                .arg(ETH_GAS.def().help(
-                    "Under ideal conditions, relaying transfers will yield a \
-                     net profit. If that is not possible, setting this \
-                     optional value will result in a batch transfer that \
-                     costs as close to the given value as possible without \
-                     exceeding it.",
-                ))
-                .arg(CONVERSION_TABLE.def().help(
-                    "Path to a JSON object containing a mapping between token \
-                     aliases (or addresses) and their conversion rates in gwei",
+            app.arg(LEDGER_ADDRESS.def().help(LEDGER_ADDRESS_ABOUT))
+                .arg(HASH_LIST.def().help(
+                    "Whitespace separated Keccak hash list of transfers in \
+                     the Bridge pool.",
                 ))
         }
     }
 
-    impl CliToSdkCtxless<BridgePoolProof<SdkTypes>> for BridgePoolProof<CliTypes> {
-        fn to_sdk_ctxless(self) -> BridgePoolProof<SdkTypes> {
-            BridgePoolProof::<SdkTypes> {
+    impl CliToSdkCtxless<ExportRelayBundle<SdkTypes>>
+        for ExportRelayBundle<CliTypes>
+    {
+        fn to_sdk_ctxless(self) -> ExportRelayBundle<SdkTypes> {
+            ExportRelayBundle::<SdkTypes> {
                 ledger_address: self.ledger_address,
                 transfers: self.transfers,
                 relayer: self.relayer,
+                eth_chain_id: self.eth_chain_id,
+                bundle_file: self.bundle_file,
+                target_root: self.target_root,
+                fail_on_warning: self.fail_on_warning,
             }
         }
     }
 
-    impl Args for BridgePoolProof<CliTypes> {
+    impl Args for ExportRelayBundle<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let ledger_address = LEDGER_ADDRESS.parse(matches);
             let hashes = HASH_LIST.parse(matches);
             let relayer = RELAYER.parse(matches);
+            let eth_chain_id = ETH_CHAIN_ID.parse(matches);
+            let bundle_file = RELAY_BUNDLE.parse(matches);
+            let target_root = TARGET_ROOT.parse(matches);
+            let fail_on_warning = FAIL_ON_WARNING.parse(matches);
             Self {
                 ledger_address,
                 transfers: hashes
@@ -3542,6 +4650,10 @@ pub mod args {
                     })
                     .collect(),
                 relayer,
+                eth_chain_id,
+                bundle_file,
+                target_root,
+                fail_on_warning,
             }
         }
 
@@ -3556,6 +4668,153 @@ pub mod args {
                         .def()
                         .help("The rewards address for relaying this proof."),
                 )
+                .arg(ETH_CHAIN_ID.def().help(
+                    "The id of the Ethereum chain the proof is to be relayed \
+                     to. Relaying the bundle to any other chain fails.",
+                ))
+                .arg(RELAY_BUNDLE.def().help(
+                    "Path to the file where the relay bundle is written.",
+                ))
+                .arg(TARGET_ROOT.def().help(TARGET_ROOT_ABOUT))
+                .arg(
+                    FAIL_ON_WARNING
+                        .def()
+                        .help(FAIL_ON_WARNING_ABOUT)
+                        // Lets scripts disable the only prompt of these
+                        // commands under a familiar name
+                        .alias("no-prompt"),
+                )
+        }
+    }
+
+    impl CliToSdkCtxless<RelayFromBundle<SdkTypes>>
+        for RelayFromBundle<CliTypes>
+    {
+        fn to_sdk_ctxless(self) -> RelayFromBundle<SdkTypes> {
+            RelayFromBundle::<SdkTypes> {
+                bundle_file: self.bundle_file,
+                eth_rpc_endpoint: (),
+                confirmations: self.confirmations,
+                gas: self.gas,
+                gas_price: self.gas_price,
+                max_gas_price_gwei: self.max_gas_price_gwei,
+                eth_addr: self.eth_addr,
+                sync: self.sync,
+                safe_mode: self.safe_mode,
+                poll_interval: self.poll_interval,
+                rebroadcast_after: self.rebroadcast_after,
+                max_rebroadcasts: self.max_rebroadcasts,
+                confirmation_timeout: self.confirmation_timeout,
+                request_timeout: self.request_timeout,
+                audit_log: self.audit_log,
+            }
+        }
+    }
+
+    impl Args for RelayFromBundle<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let bundle_file = RELAY_BUNDLE.parse(matches);
+            let eth_rpc_endpoint = ETH_RPC_ENDPOINT.parse(matches);
+            let confirmations = ETH_CONFIRMATIONS.parse(matches);
+            let gas = ETH_GAS.parse(matches);
+            let gas_price = ETH_GAS_PRICE.parse(matches);
+            let max_gas_price_gwei = MAX_ETH_GAS_PRICE_GWEI.parse(matches);
+            let eth_addr = ETH_ADDRESS_OPT.parse(matches);
+            let sync = ETH_SYNC.parse(matches);
+            let safe_mode = SAFE_MODE.parse(matches);
+            let poll_interval =
+                ETH_POLL_INTERVAL.parse(matches).map(|dur| dur.0);
+            let rebroadcast_after =
+                ETH_REBROADCAST_AFTER.parse(matches).map(|dur| dur.0);
+            let max_rebroadcasts = ETH_MAX_REBROADCASTS.parse(matches);
+            let confirmation_timeout =
+                ETH_CONFIRMATION_TIMEOUT.parse(matches).map(|dur| dur.0);
+            let request_timeout =
+                ETH_REQUEST_TIMEOUT.parse(matches).map(|dur| dur.0);
+            let audit_log = RELAY_AUDIT_LOG.parse(matches);
+            Self {
+                bundle_file,
+                eth_rpc_endpoint,
+                confirmations,
+                gas,
+                gas_price,
+                max_gas_price_gwei,
+                eth_addr,
+                sync,
+                safe_mode,
+                poll_interval,
+                rebroadcast_after,
+                max_rebroadcasts,
+                confirmation_timeout,
+                request_timeout,
+                audit_log,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(RELAY_BUNDLE.def().help(
+                "Path to a relay bundle written by the export-relay-bundle \
+                 command.",
+            ))
+            .arg(ETH_RPC_ENDPOINT.def().help("The Ethereum RPC endpoint."))
+            .arg(ETH_CONFIRMATIONS.def().help(
+                "The number of blocks, counting the block including the relay \
+                 transaction, before it is deemed confirmed. A single \
+                 confirmation means the relay was included in a block. Zero \
+                 confirmations are raised to one. Defaults to 3.",
+            ))
+            .arg(ETH_GAS.def().help(
+                "The Ethereum gas that can be spent during the relay call.",
+            ))
+            .arg(
+                ETH_GAS_PRICE
+                    .def()
+                    .help("The price of Ethereum gas, during the relay call."),
+            )
+            .arg(ETH_ADDRESS_OPT.def().help(
+                "The address of the Ethereum wallet to pay the gas fees. If \
+                 unset, the default wallet is used.",
+            ))
+            .arg(ETH_SYNC.def().help(
+                "Synchronize with the network, or exit immediately, if the \
+                 Ethereum node has fallen behind.",
+            ))
+            .arg(SAFE_MODE.def().help(
+                "Safe mode overrides keyboard interrupt signals, to ensure \
+                 Ethereum transfers aren't canceled midway through.",
+            ))
+            .arg(MAX_ETH_GAS_PRICE_GWEI.def().help(
+                "Only relay if the current Ethereum gas price, in gwei, does \
+                 not exceed this ceiling.",
+            ))
+            .arg(ETH_POLL_INTERVAL.def().help(
+                "The interval between polls of the Ethereum node, while \
+                 waiting for the relay to be confirmed.",
+            ))
+            .arg(ETH_REBROADCAST_AFTER.def().help(
+                "The amount of time the relay transaction may be neither \
+                 pending in the mempool nor mined, before it is re-broadcast.",
+            ))
+            .arg(ETH_MAX_REBROADCASTS.def().help(
+                "The maximum number of times the relay transaction is \
+                 re-broadcast, before giving up on it. Defaults to 5.",
+            ))
+            .arg(ETH_CONFIRMATION_TIMEOUT.def().help(
+                "The maximum amount of time to wait for the relay transaction \
+                 to be confirmed. By default, the relay is awaited until it \
+                 is confirmed.",
+            ))
+            .arg(ETH_REQUEST_TIMEOUT.def().help(
+                "The maximum amount of time to wait on each request to the \
+                 Ethereum node. Can also be set through the \
+                 NAMADA_ETH_REQUEST_TIMEOUT environment variable.",
+            ))
+            .arg(RELAY_AUDIT_LOG.def().help(
+                "Path to a file to which a line of json is appended for each \
+                 relay attempt, recording the relayed transfers, the gas \
+                 settings, the transaction hash and the outcome of the \
+                 attempt.",
+            ))
         }
     }
 
@@ -3571,9 +4830,27 @@ pub mod args {
                 eth_rpc_endpoint: (),
                 gas: self.gas,
                 gas_price: self.gas_price,
+                gas_price_bump: self.gas_price_bump,
+                max_gas_price: self.max_gas_price,
+                max_gas_price_gwei: self.max_gas_price_gwei,
                 eth_addr: self.eth_addr,
                 sync: self.sync,
                 safe_mode: self.safe_mode,
+                poll_interval: self.poll_interval,
+                rebroadcast_after: self.rebroadcast_after,
+                max_rebroadcasts: self.max_rebroadcasts,
+                confirmation_timeout: self.confirmation_timeout,
+                request_timeout: self.request_timeout,
+                state_file: self.state_file,
+                proof_file: self.proof_file,
+                target_root: self.target_root,
+                fail_on_warning: self.fail_on_warning,
+                estimated_gas: self.estimated_gas,
+                private_relay_url: self.private_relay_url,
+                max_calldata_size: self.max_calldata_size,
+                access_list: self.access_list,
+                audit_log: self.audit_log,
+                print_calldata: self.print_calldata,
             }
         }
     }
@@ -3586,10 +4863,32 @@ pub mod args {
             let relayer = RELAYER.parse(matches);
             let gas = ETH_GAS.parse(matches);
             let gas_price = ETH_GAS_PRICE.parse(matches);
+            let gas_price_bump = ETH_GAS_PRICE_BUMP.parse(matches);
+            let max_gas_price = MAX_ETH_GAS_PRICE.parse(matches);
+            let max_gas_price_gwei = MAX_ETH_GAS_PRICE_GWEI.parse(matches);
             let eth_rpc_endpoint = ETH_RPC_ENDPOINT.parse(matches);
             let eth_addr = ETH_ADDRESS_OPT.parse(matches);
             let confirmations = ETH_CONFIRMATIONS.parse(matches);
             let sync = ETH_SYNC.parse(matches);
+            let poll_interval =
+                ETH_POLL_INTERVAL.parse(matches).map(|dur| dur.0);
+            let rebroadcast_after =
+                ETH_REBROADCAST_AFTER.parse(matches).map(|dur| dur.0);
+            let max_rebroadcasts = ETH_MAX_REBROADCASTS.parse(matches);
+            let confirmation_timeout =
+                ETH_CONFIRMATION_TIMEOUT.parse(matches).map(|dur| dur.0);
+            let request_timeout =
+                ETH_REQUEST_TIMEOUT.parse(matches).map(|dur| dur.0);
+            let state_file = RELAY_STATE_FILE.parse(matches);
+            let proof_file = PROOF_FILE.parse(matches);
+            let target_root = TARGET_ROOT.parse(matches);
+            let fail_on_warning = FAIL_ON_WARNING.parse(matches);
+            let estimated_gas = ETH_ESTIMATED_GAS.parse(matches);
+            let private_relay_url = PRIVATE_RELAY_URL.parse(matches);
+            let max_calldata_size = MAX_CALLDATA_SIZE.parse(matches);
+            let access_list = ACCESS_LIST.parse(matches);
+            let audit_log = RELAY_AUDIT_LOG.parse(matches);
+            let print_calldata = PRINT_CALLDATA.parse(matches);
             Self {
                 ledger_address,
                 sync,
@@ -3608,10 +4907,28 @@ pub mod args {
                 relayer,
                 gas,
                 gas_price,
+                gas_price_bump,
+                max_gas_price,
+                max_gas_price_gwei,
                 eth_rpc_endpoint,
                 eth_addr,
                 confirmations,
                 safe_mode,
+                poll_interval,
+                rebroadcast_after,
+                max_rebroadcasts,
+                confirmation_timeout,
+                request_timeout,
+                state_file,
+                proof_file,
+                target_root,
+                fail_on_warning,
+                estimated_gas,
+                private_relay_url,
+                max_calldata_size,
+                access_list,
+                audit_log,
+                print_calldata,
             }
         }
 
@@ -3643,15 +4960,119 @@ pub mod args {
                     ),
                 )
                 .arg(ETH_RPC_ENDPOINT.def().help("The Ethereum RPC endpoint."))
-                .arg(
-                    ETH_CONFIRMATIONS
-                        .def()
-                        .help("The number of block confirmations on Ethereum."),
-                )
+                .arg(ETH_CONFIRMATIONS.def().help(
+                    "The number of blocks, counting the block including the \
+                     relay transaction, before it is deemed confirmed. A \
+                     single confirmation means the relay was included in a \
+                     block. Zero confirmations are raised to one. Defaults \
+                     to 3.",
+                ))
                 .arg(ETH_SYNC.def().help(
                     "Synchronize with the network, or exit immediately, if \
                      the Ethereum node has fallen behind.",
                 ))
+                .arg(ETH_GAS_PRICE_BUMP.def().help(
+                    "The percentage by which to bump the gas price of the \
+                     relay call, if it is rejected as underpriced. If unset, \
+                     underpriced relay calls are not resubmitted.",
+                ))
+                .arg(MAX_ETH_GAS_PRICE.def().help(
+                    "The maximum gas price the relay call can be escalated \
+                     to.",
+                ))
+                .arg(MAX_ETH_GAS_PRICE_GWEI.def().help(
+                    "Only relay if the current Ethereum gas price, in gwei, \
+                     does not exceed this ceiling. The break-even gas price \
+                     reported by the recommend-batch command is a natural \
+                     ceiling.",
+                ))
+                .arg(ETH_POLL_INTERVAL.def().help(
+                    "The interval between polls of the Ethereum node, while \
+                     waiting for the relay to be confirmed. Lower values \
+                     reduce the confirmation latency, at the cost of more \
+                     RPC requests.",
+                ))
+                .arg(ETH_REBROADCAST_AFTER.def().help(
+                    "The amount of time the relay transaction may be neither \
+                     pending in the mempool nor mined, before it is \
+                     re-broadcast. If set, the Ethereum node is polled with \
+                     an exponential backoff while waiting for the relay to be \
+                     confirmed.",
+                ))
+                .arg(ETH_MAX_REBROADCASTS.def().help(
+                    "The maximum number of times the relay transaction is \
+                     re-broadcast, before giving up on it. Defaults to 5.",
+                ))
+                .arg(ETH_CONFIRMATION_TIMEOUT.def().help(
+                    "The maximum amount of time to wait for the relay \
+                     transaction to be confirmed. By default, the relay is \
+                     awaited until it is confirmed.",
+                ))
+                .arg(ETH_REQUEST_TIMEOUT.def().help(
+                    "The maximum amount of time to wait on each request to \
+                     the Ethereum node. Can also be set through the \
+                     NAMADA_ETH_REQUEST_TIMEOUT environment variable.",
+                ))
+                .arg(RELAY_STATE_FILE.def().help(
+                    "Path to a file where the state of the relay is \
+                     persisted. If a previous relay was interrupted before \
+                     being confirmed, its Ethereum transaction is resumed \
+                     instead of relaying the transfers again.",
+                ))
+                .arg(PROOF_FILE.def().help(
+                    "Path to a proof output by the construct-proof command, \
+                     to relay instead of generating a new proof. Compressed \
+                     proofs are decompressed before being relayed. The proof \
+                     must cover exactly the given transfers, if any.",
+                ))
+                .arg(
+                    TARGET_ROOT
+                        .def()
+                        .help(TARGET_ROOT_ABOUT)
+                        .conflicts_with(PROOF_FILE.name),
+                )
+                .arg(
+                    FAIL_ON_WARNING
+                        .def()
+                        .help(FAIL_ON_WARNING_ABOUT)
+                        // Lets scripts disable the only prompt of these
+                        // commands under a familiar name
+                        .alias("no-prompt"),
+                )
+                .arg(ETH_ESTIMATED_GAS.def().help(
+                    "The Ethereum gas estimated by the batch recommender for \
+                     the relayed transfers. If set, it is compared against \
+                     the gas actually used by the relay, once confirmed.",
+                ))
+                .arg(PRIVATE_RELAY_URL.def().help(
+                    "URL of a private transaction relay, to submit the relay \
+                     transaction to instead of the public mempool, \
+                     protecting it from front-running. If unset, the relay \
+                     is broadcast through the Ethereum RPC endpoint.",
+                ))
+                .arg(MAX_CALLDATA_SIZE.def().help(
+                    "The maximum size of the calldata of the relay \
+                     transaction, in bytes. Larger proofs are not relayed, \
+                     and a split of the batch into smaller ones is suggested. \
+                     Defaults to 131072 bytes.",
+                ))
+                .arg(ACCESS_LIST.def().help(
+                    "Attach an EIP-2930 access list to the relay transaction, \
+                     generated by the Ethereum node, which may reduce its gas \
+                     cost. The estimated gas savings are reported.",
+                ))
+                .arg(RELAY_AUDIT_LOG.def().help(
+                    "Path to a file to which a line of json is appended for \
+                     each relay attempt, recording the relayed transfers, the \
+                     gas settings, the transaction hash and the outcome of \
+                     the attempt.",
+                ))
+                .arg(PRINT_CALLDATA.def().help(
+                    "Print the Bridge contract address, the function selector \
+                     and the hex encoded calldata of the relay transaction, \
+                     instead of sending it. The relay may then be submitted \
+                     through a hardware wallet or a multisig.",
+                ))
         }
     }
 
@@ -7247,3 +8668,57 @@ fn namada_relayer_app() -> App {
         .subcommand_required(true);
     cmds::NamadaRelayer::add_sub(args::Global::def(app))
 }
+
+#[cfg(test)]
+mod test_cli {
+    use namada::types::keccak::KeccakHash;
+
+    use super::*;
+
+    /// Test that `--no-prompt` is accepted by the Bridge pool proof
+    /// commands, in place of `--fail-on-warning`, and that it is not
+    /// a global flag.
+    #[test]
+    fn test_no_prompt() {
+        let hash = KeccakHash([1; 32]).to_hex();
+        let relayer = namada::types::address::nam().to_string();
+        let matches = namada_relayer_app()
+            .try_get_matches_from([
+                "namadar",
+                "ethereum-bridge-pool",
+                "construct-proof",
+                "--hash-list",
+                &hash,
+                "--relayer",
+                &relayer,
+                "--no-prompt",
+            ])
+            .expect("Test failed");
+        let Some(cmds::NamadaRelayer::EthBridgePool(
+            cmds::EthBridgePool::WithoutContext(
+                cmds::EthBridgePoolWithoutCtx::ConstructProof(
+                    cmds::ConstructProof(args),
+                ),
+            ),
+        )) = <cmds::NamadaRelayer as Cmd>::parse(&matches)
+        else {
+            panic!("Test failed");
+        };
+        assert!(args.fail_on_warning);
+
+        assert!(
+            namada_relayer_app()
+                .try_get_matches_from([
+                    "namadar",
+                    "--no-prompt",
+                    "ethereum-bridge-pool",
+                    "construct-proof",
+                    "--hash-list",
+                    &hash,
+                    "--relayer",
+                    &relayer,
+                ])
+                .is_err()
+        );
+    }
+}
@@ -33,7 +33,37 @@ impl CliApi {
                         client.wait_until_node_is_synced(&io).await?;
                         let args = args.to_sdk(&mut ctx);
                         let namada = ctx.to_sdk(client, io);
-                        bridge_pool::recommend_batch(&namada, args).await?;
+                        bridge_pool::recommend_batch(&namada, args, None)
+                            .await?;
+                    }
+                    EthBridgePoolWithCtx::FeeHistogram(
+                        BridgePoolFeeHistogram(args),
+                    ) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.query.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx);
+                        bridge_pool::query_fee_histogram(&client, &io, args)
+                            .await?;
+                    }
+                    EthBridgePoolWithCtx::PreviewRelay(PreviewRelay(args)) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.recommend.query.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let eth_client =
+                            get_eth_rpc_client(&args.eth_rpc_endpoint).await;
+                        let args = args.to_sdk(&mut ctx);
+                        let namada = ctx.to_sdk(client, io);
+                        bridge_pool::preview_relay(&namada, eth_client, args)
+                            .await?;
                     }
                 }
             }
@@ -46,7 +76,8 @@ impl CliApi {
                     });
                     client.wait_until_node_is_synced(&io).await?;
                     let args = args.to_sdk_ctxless();
-                    bridge_pool::construct_proof(&client, &io, args).await?;
+                    bridge_pool::construct_proof(&client, &io, args, None)
+                        .await?;
                 }
                 EthBridgePoolWithoutCtx::RelayProof(RelayProof(args)) => {
                     let client = client.unwrap_or_else(|| {
@@ -57,7 +88,29 @@ impl CliApi {
                         get_eth_rpc_client(&args.eth_rpc_endpoint).await;
                     let args = args.to_sdk_ctxless();
                     bridge_pool::relay_bridge_pool_proof(
-                        eth_client, &client, &io, args,
+                        eth_client, &client, &io, args, None, None,
+                    )
+                    .await?;
+                }
+                EthBridgePoolWithoutCtx::ExportBundle(ExportRelayBundle(
+                    args,
+                )) => {
+                    let client = client.unwrap_or_else(|| {
+                        C::from_tendermint_address(&args.ledger_address)
+                    });
+                    client.wait_until_node_is_synced(&io).await?;
+                    let args = args.to_sdk_ctxless();
+                    bridge_pool::export_relay_bundle(&client, &io, args)
+                        .await?;
+                }
+                EthBridgePoolWithoutCtx::RelayBundle(RelayFromBundle(args)) => {
+                    let eth_client =
+                        get_eth_rpc_client(&args.eth_rpc_endpoint).await;
+                    let args = args.to_sdk_ctxless();
+                    let bundle =
+                        bridge_pool::RelayBundle::load(&args.bundle_file)?;
+                    bridge_pool::relay_from_bundle(
+                        eth_client, &io, bundle, args,
                     )
                     .await?;
                 }
@@ -68,7 +121,11 @@ impl CliApi {
                         C::from_tendermint_address(&query.ledger_address)
                     });
                     client.wait_until_node_is_synced(&io).await?;
-                    bridge_pool::query_bridge_pool(&client, &io).await?;
+                    let query = query.to_sdk_ctxless();
+                    bridge_pool::query_bridge_pool_with_args(
+                        &client, &io, &query,
+                    )
+                    .await?;
                 }
                 EthBridgePoolWithoutCtx::QuerySigned(
                     QuerySignedBridgePool(query),
@@ -77,16 +134,128 @@ impl CliApi {
                         C::from_tendermint_address(&query.ledger_address)
                     });
                     client.wait_until_node_is_synced(&io).await?;
-                    bridge_pool::query_signed_bridge_pool(&client, &io).await?;
+                    let query = query.to_sdk_ctxless();
+                    bridge_pool::query_signed_bridge_pool_with_args(
+                        &client, &io, &query,
+                    )
+                    .await?;
                 }
                 EthBridgePoolWithoutCtx::QueryRelays(QueryRelayProgress(
-                    query,
+                    args,
                 )) => {
+                    let client = client.unwrap_or_else(|| {
+                        C::from_tendermint_address(&args.ledger_address)
+                    });
+                    client.wait_until_node_is_synced(&io).await?;
+                    let args = args.to_sdk_ctxless();
+                    match args.watch {
+                        Some(interval) => {
+                            bridge_pool::watch_relay_progress(
+                                &client,
+                                &io,
+                                interval,
+                                args.stall_timeout,
+                            )
+                            .await?;
+                        }
+                        None => {
+                            bridge_pool::query_relay_progress(
+                                &client,
+                                &io,
+                                args.output.as_deref(),
+                            )
+                            .await?;
+                        }
+                    }
+                }
+                EthBridgePoolWithoutCtx::MonitorGrowth(
+                    MonitorBridgePoolGrowth(args),
+                ) => {
+                    let client = client.unwrap_or_else(|| {
+                        C::from_tendermint_address(&args.ledger_address)
+                    });
+                    client.wait_until_node_is_synced(&io).await?;
+                    let args = args.to_sdk_ctxless();
+                    bridge_pool::monitor_bridge_pool_growth(&client, &io, args)
+                        .await?;
+                }
+                EthBridgePoolWithoutCtx::QueryTransfers(
+                    QueryBridgePoolTransfers(args),
+                ) => {
+                    let client = client.unwrap_or_else(|| {
+                        C::from_tendermint_address(&args.ledger_address)
+                    });
+                    client.wait_until_node_is_synced(&io).await?;
+                    let args = args.to_sdk_ctxless();
+                    bridge_pool::query_bridge_pool_transfers_status(
+                        &client, &io, args,
+                    )
+                    .await?;
+                }
+                EthBridgePoolWithoutCtx::QueryStatus(
+                    QueryBridgePoolStatus(args),
+                ) => {
+                    let client = client.unwrap_or_else(|| {
+                        C::from_tendermint_address(&args.ledger_address)
+                    });
+                    client.wait_until_node_is_synced(&io).await?;
+                    let eth_client = match &args.eth_rpc_endpoint {
+                        Some(endpoint) => {
+                            Some(get_eth_rpc_client(endpoint).await)
+                        }
+                        None => None,
+                    };
+                    bridge_pool::query_bridge_pool_status(
+                        &client, &io, eth_client,
+                    )
+                    .await?;
+                }
+                EthBridgePoolWithoutCtx::CheckConsistency(
+                    CheckBridgeConsistency(args),
+                ) => {
+                    let client = client.unwrap_or_else(|| {
+                        C::from_tendermint_address(&args.ledger_address)
+                    });
+                    client.wait_until_node_is_synced(&io).await?;
+                    let eth_client =
+                        get_eth_rpc_client(&args.eth_rpc_endpoint).await;
+                    let report = bridge_pool::check_bridge_consistency(
+                        &client, &io, eth_client,
+                    )
+                    .await?;
+                    if report.consistency
+                        == bridge_pool::BridgeConsistency::Anomaly
+                    {
+                        cli::safe_exit(1);
+                    }
+                }
+                EthBridgePoolWithoutCtx::VerifySignedRoot(
+                    VerifySignedBridgePoolRoot(query),
+                ) => {
                     let client = client.unwrap_or_else(|| {
                         C::from_tendermint_address(&query.ledger_address)
                     });
                     client.wait_until_node_is_synced(&io).await?;
-                    bridge_pool::query_relay_progress(&client, &io).await?;
+                    if !bridge_pool::verify_signed_root(&client, &io).await? {
+                        cli::safe_exit(1);
+                    }
+                }
+                EthBridgePoolWithoutCtx::FeeLedger(RelayerFeeLedger(args)) => {
+                    let client = client.unwrap_or_else(|| {
+                        C::from_tendermint_address(&args.ledger_address)
+                    });
+                    client.wait_until_node_is_synced(&io).await?;
+                    let eth_client = match &args.eth_rpc_endpoint {
+                        Some(endpoint) => {
+                            Some(get_eth_rpc_client(endpoint).await)
+                        }
+                        None => None,
+                    };
+                    let args = args.to_sdk_ctxless();
+                    bridge_pool::relayer_fee_ledger(
+                        &client, &io, eth_client, args,
+                    )
+                    .await?;
                 }
             },
             cli::NamadaRelayer::ValidatorSet(sub) => match sub {
@@ -22,6 +22,7 @@ use namada::types::address::{Address, ImplicitAddress};
 use namada::types::dec::Dec;
 use namada::types::io::Io;
 use namada::types::key::{self, *};
+use namada_sdk::eth_bridge::bridge_pool;
 use namada_sdk::rpc::{InnerTxResult, TxBroadcastData, TxResponse};
 use namada_sdk::wallet::alias::validator_consensus_key;
 use namada_sdk::wallet::{Wallet, WalletIo};
@@ -239,7 +240,13 @@ pub async fn submit_bridge_pool_tx<N: Namada>(
     let tx_args = args.tx.clone();
     let (mut tx, signing_data) = args.clone().build(namada).await?;
 
-    if args.tx.dump_tx {
+    if args.describe {
+        display_line!(
+            namada.io(),
+            "{}",
+            bridge_pool::describe_bridge_pool_tx(&tx)?
+        );
+    } else if args.tx.dump_tx {
         tx::dump_tx(namada.io(), &args.tx, tx);
     } else {
         submit_reveal_aux(namada, tx_args.clone(), &args.sender).await?;
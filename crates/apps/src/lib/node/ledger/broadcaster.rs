@@ -1,18 +1,356 @@
+use std::collections::VecDeque;
 use std::net::SocketAddr;
 use std::ops::ControlFlow;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::Arc;
 
+use namada::types::chain::ChainId;
 use namada::types::control_flow::time;
-use namada::types::time::{DateTimeUtc, Utc};
+use namada::types::hash::Hash;
+use namada::types::time::DateTimeUtc;
 use tokio::sync::mpsc::UnboundedReceiver;
 
+use crate::facade::tendermint_rpc::endpoint::status::Response as Status;
 use crate::facade::tendermint_rpc::{Client, HttpClient};
 
+/// The number of consecutive CheckTx rejections after which the
+/// broadcaster pauses broadcasting.
+const MAX_CONSECUTIVE_REJECTIONS: usize = 10;
+
+/// The amount of time the broadcaster pauses broadcasting for,
+/// once [`MAX_CONSECUTIVE_REJECTIONS`] has been reached.
+const REJECTIONS_COOLDOWN: time::Duration = time::Duration::from_secs(30);
+
+/// The default amount of time to wait for the node to acknowledge
+/// a broadcast tx.
+const DEFAULT_BROADCAST_TIMEOUT: time::Duration = time::Duration::from_secs(30);
+
 /// A service for broadcasting txs via an HTTP client.
 /// The receiver is for receiving message payloads for other services
 /// to be broadcast.
 pub struct Broadcaster {
     client: HttpClient,
     receiver: UnboundedReceiver<Vec<u8>>,
+    circuit_breaker: CircuitBreaker,
+    metrics: Arc<BroadcasterMetrics>,
+    config: BroadcasterConfig,
+}
+
+/// The source of time of the [`Broadcaster`], used to wait
+/// for the genesis time of the chain.
+#[async_trait::async_trait]
+pub trait Clock: Send + Sync {
+    /// The current time.
+    fn now(&self) -> DateTimeUtc;
+
+    /// Wait for the given amount of time to elapse.
+    async fn sleep(&self, duration: time::Duration);
+}
+
+/// A [`Clock`] following the wall-clock time of the system.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+#[async_trait::async_trait]
+impl Clock for SystemClock {
+    fn now(&self) -> DateTimeUtc {
+        DateTimeUtc::now()
+    }
+
+    async fn sleep(&self, duration: time::Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
+
+/// Wait until `genesis_time` has been reached, according to `clock`.
+async fn wait_for_genesis(clock: &dyn Clock, genesis_time: DateTimeUtc) {
+    if let Ok(sleep_time) =
+        genesis_time.0.signed_duration_since(clock.now().0).to_std()
+    {
+        if !sleep_time.is_zero() {
+            clock.sleep(sleep_time).await;
+        }
+    }
+}
+
+/// Configuration of the checks performed on the CometBFT node,
+/// before the [`Broadcaster`] starts broadcasting txs.
+#[derive(Debug, Clone)]
+pub struct BroadcasterConfig {
+    /// The chain the node is expected to be running, if any.
+    pub chain_id: Option<ChainId>,
+    /// The number of consecutive status checks in which the node
+    /// must report to be synced, before broadcasting starts.
+    pub synced_checks: u32,
+    /// The policy used to retry checking the status of the node,
+    /// until it is ready for broadcasting to start.
+    pub retry_policy: time::RetryPolicy,
+    /// The maximum amount of time to wait for the node to acknowledge
+    /// each broadcast tx, after which the broadcaster moves on to the
+    /// next tx.
+    pub broadcast_timeout: time::Duration,
+}
+
+impl Default for BroadcasterConfig {
+    fn default() -> Self {
+        Self {
+            chain_id: None,
+            synced_checks: 1,
+            retry_policy: time::RetryPolicy::default(),
+            broadcast_timeout: DEFAULT_BROADCAST_TIMEOUT,
+        }
+    }
+}
+
+/// Decide whether the [`Broadcaster`] should start broadcasting txs,
+/// given the latest `status` of the CometBFT node.
+///
+/// The node must be running the expected chain, and it must have
+/// reported to be synced in `config.synced_checks` consecutive status
+/// checks, which are counted in `consecutive_synced`. Breaking with an
+/// error means the broadcaster should not be started at all.
+fn should_proceed(
+    status: &Status,
+    config: &BroadcasterConfig,
+    consecutive_synced: &mut u32,
+) -> ControlFlow<Result<(), ()>> {
+    if let Some(chain_id) = &config.chain_id {
+        let network = status.node_info.network.as_str();
+        if network != chain_id.as_str() {
+            tracing::error!(
+                expected = chain_id.as_str(),
+                got = network,
+                "The CometBFT node is running an unexpected chain"
+            );
+            return ControlFlow::Break(Err(()));
+        }
+    }
+    if status.sync_info.catching_up {
+        *consecutive_synced = 0;
+        return ControlFlow::Continue(());
+    }
+    *consecutive_synced += 1;
+    if *consecutive_synced >= config.synced_checks {
+        ControlFlow::Break(Ok(()))
+    } else {
+        ControlFlow::Continue(())
+    }
+}
+
+/// The kind of a metric exported by the [`Broadcaster`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricKind {
+    /// A monotonically increasing value.
+    Counter,
+    /// A value that can go up and down.
+    Gauge,
+}
+
+/// Number of txs successfully broadcast to the node.
+pub const BROADCASTS_SUCCEEDED: &str = "broadcasts_succeeded";
+/// Number of txs that were rejected by, or could not be
+/// delivered to, the node.
+pub const BROADCASTS_FAILED: &str = "broadcasts_failed";
+/// Number of txs whose broadcast was not acknowledged by the node
+/// in time, which may still have been accepted into its mempool.
+pub const BROADCASTS_TIMED_OUT: &str = "broadcasts_timed_out";
+/// Number of txs waiting to be broadcast.
+pub const QUEUE_DEPTH: &str = "queue_depth";
+/// Number of times the node had to be reached again, after
+/// failing to be contacted.
+pub const RECONNECTS: &str = "reconnects";
+
+/// A sink for the metrics of the [`Broadcaster`], such as
+/// a Prometheus exporter.
+pub trait MetricsRecorder: Send + Sync {
+    /// Register a metric with the given name and description.
+    fn describe(
+        &self,
+        name: &'static str,
+        kind: MetricKind,
+        description: &'static str,
+    );
+
+    /// Increment the counter with the given name by `value`.
+    fn increment_counter(&self, name: &'static str, value: u64);
+
+    /// Set the gauge with the given name to `value`.
+    fn set_gauge(&self, name: &'static str, value: u64);
+}
+
+/// Register the metrics of the [`Broadcaster`] with the
+/// given recorder.
+pub fn describe_metrics(recorder: &dyn MetricsRecorder) {
+    recorder.describe(
+        BROADCASTS_SUCCEEDED,
+        MetricKind::Counter,
+        "Number of txs successfully broadcast to the node",
+    );
+    recorder.describe(
+        BROADCASTS_FAILED,
+        MetricKind::Counter,
+        "Number of txs rejected by, or undelivered to, the node",
+    );
+    recorder.describe(
+        BROADCASTS_TIMED_OUT,
+        MetricKind::Counter,
+        "Number of txs not acknowledged by the node in time",
+    );
+    recorder.describe(
+        QUEUE_DEPTH,
+        MetricKind::Gauge,
+        "Number of txs waiting to be broadcast",
+    );
+    recorder.describe(
+        RECONNECTS,
+        MetricKind::Counter,
+        "Number of times the node had to be reached again",
+    );
+}
+
+/// A [`MetricsRecorder`] reporting the metrics of the [`Broadcaster`]
+/// as tracing events.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TracingRecorder;
+
+impl MetricsRecorder for TracingRecorder {
+    fn describe(
+        &self,
+        name: &'static str,
+        kind: MetricKind,
+        description: &'static str,
+    ) {
+        tracing::debug!(
+            metric = name,
+            ?kind,
+            description,
+            "Registered broadcaster metric"
+        );
+    }
+
+    fn increment_counter(&self, name: &'static str, value: u64) {
+        tracing::trace!(metric = name, value, "Incremented broadcaster metric");
+    }
+
+    fn set_gauge(&self, name: &'static str, value: u64) {
+        tracing::trace!(metric = name, value, "Set broadcaster metric");
+    }
+}
+
+/// Metrics tracking the activity of the [`Broadcaster`], which
+/// are forwarded to a [`MetricsRecorder`], if one is set.
+#[derive(Default)]
+pub struct BroadcasterMetrics {
+    broadcasts_succeeded: AtomicU64,
+    broadcasts_failed: AtomicU64,
+    broadcasts_timed_out: AtomicU64,
+    queue_depth: AtomicU64,
+    reconnects: AtomicU64,
+    recorder: Option<Arc<dyn MetricsRecorder>>,
+}
+
+impl BroadcasterMetrics {
+    /// Number of txs successfully broadcast to the node.
+    pub fn broadcasts_succeeded(&self) -> u64 {
+        self.broadcasts_succeeded.load(Ordering::Relaxed)
+    }
+
+    /// Number of txs rejected by, or undelivered to, the node.
+    pub fn broadcasts_failed(&self) -> u64 {
+        self.broadcasts_failed.load(Ordering::Relaxed)
+    }
+
+    /// Number of txs not acknowledged by the node in time.
+    pub fn broadcasts_timed_out(&self) -> u64 {
+        self.broadcasts_timed_out.load(Ordering::Relaxed)
+    }
+
+    /// Number of txs waiting to be broadcast.
+    pub fn queue_depth(&self) -> u64 {
+        self.queue_depth.load(Ordering::Relaxed)
+    }
+
+    /// Number of times the node had to be reached again.
+    pub fn reconnects(&self) -> u64 {
+        self.reconnects.load(Ordering::Relaxed)
+    }
+
+    fn increment(&self, name: &'static str, counter: &AtomicU64) {
+        counter.fetch_add(1, Ordering::Relaxed);
+        if let Some(recorder) = &self.recorder {
+            recorder.increment_counter(name, 1);
+        }
+    }
+
+    fn record_broadcast(&self, accepted: bool) {
+        if accepted {
+            self.increment(BROADCASTS_SUCCEEDED, &self.broadcasts_succeeded);
+        } else {
+            self.increment(BROADCASTS_FAILED, &self.broadcasts_failed);
+        }
+    }
+
+    fn record_broadcast_timeout(&self) {
+        self.increment(BROADCASTS_TIMED_OUT, &self.broadcasts_timed_out);
+    }
+
+    fn record_reconnect(&self) {
+        self.increment(RECONNECTS, &self.reconnects);
+    }
+
+    fn set_queue_depth(&self, depth: usize) {
+        let depth = depth as u64;
+        self.queue_depth.store(depth, Ordering::Relaxed);
+        if let Some(recorder) = &self.recorder {
+            recorder.set_gauge(QUEUE_DEPTH, depth);
+        }
+    }
+}
+
+/// The reason why [`Broadcaster::run`] returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BroadcasterExit {
+    /// The broadcaster received an abort signal.
+    Aborted,
+    /// The sender of the abort signal was dropped, before
+    /// an abort signal was sent.
+    AbortSenderDropped,
+    /// The broadcaster loop returned unexpectedly.
+    LoopFailed,
+}
+
+/// Circuit breaker that trips after a number of consecutive
+/// CheckTx rejections, to avoid spamming a node that is
+/// rejecting every tx it is sent.
+struct CircuitBreaker {
+    /// The number of consecutive rejections observed so far.
+    consecutive_rejections: usize,
+    /// The number of consecutive rejections that trips
+    /// the circuit breaker.
+    max_consecutive_rejections: usize,
+    /// The amount of time to pause broadcasting for, once
+    /// the circuit breaker trips.
+    cooldown: time::Duration,
+}
+
+impl CircuitBreaker {
+    /// Record the outcome of broadcasting a tx.
+    ///
+    /// Returns the cooldown period to wait for before
+    /// broadcasting again, if the circuit breaker tripped.
+    fn record(&mut self, accepted: bool) -> Option<time::Duration> {
+        if accepted {
+            self.consecutive_rejections = 0;
+            return None;
+        }
+        self.consecutive_rejections += 1;
+        if self.consecutive_rejections >= self.max_consecutive_rejections {
+            self.consecutive_rejections = 0;
+            Some(self.cooldown)
+        } else {
+            None
+        }
+    }
 }
 
 impl Broadcaster {
@@ -23,88 +361,432 @@ impl Broadcaster {
             client: HttpClient::new(format!("http://{}", url).as_str())
                 .unwrap(),
             receiver,
+            circuit_breaker: CircuitBreaker {
+                consecutive_rejections: 0,
+                max_consecutive_rejections: MAX_CONSECUTIVE_REJECTIONS,
+                cooldown: REJECTIONS_COOLDOWN,
+            },
+            metrics: Arc::default(),
+            config: BroadcasterConfig::default(),
         }
     }
 
+    /// Configure the checks performed on the CometBFT node, before
+    /// broadcasting starts.
+    pub fn with_config(mut self, config: BroadcasterConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Forward the metrics of this broadcaster to the given recorder,
+    /// registering them with it.
+    pub fn with_metrics_recorder(
+        mut self,
+        recorder: Arc<dyn MetricsRecorder>,
+    ) -> Self {
+        describe_metrics(&*recorder);
+        self.metrics = Arc::new(BroadcasterMetrics {
+            recorder: Some(recorder),
+            ..Default::default()
+        });
+        self
+    }
+
     /// Loop forever, broadcasting messages that have been received
     /// by the receiver
     async fn run_loop(&mut self, genesis_time: DateTimeUtc) {
         // wait for start time if necessary
-        if let Ok(sleep_time) =
-            genesis_time.0.signed_duration_since(Utc::now()).to_std()
-        {
-            if !sleep_time.is_zero() {
-                tokio::time::sleep(sleep_time).await;
-            }
-        }
-        let result = time::Sleep {
-            strategy: time::ExponentialBackoff {
-                base: 2,
-                as_duration: time::Duration::from_secs,
-            },
-        }
-        .run(|| async {
-            let status_result = time::Sleep {
-                strategy: time::Constant(time::Duration::from_secs(1)),
-            }
-            .timeout(
-                time::Instant::now() + time::Duration::from_secs(30),
-                || async {
-                    match self.client.status().await {
-                        Ok(status) => ControlFlow::Break(status),
-                        Err(_) => ControlFlow::Continue(()),
-                    }
-                },
-            )
+        wait_for_genesis(&SystemClock, genesis_time).await;
+        // NB: the count is kept in an atomic, since it must be
+        // shared by the futures polling the status of the node
+        let consecutive_synced = AtomicU32::new(0);
+        let result = self
+            .config
+            .retry_policy
+            .run(|| async {
+                let status_result = time::Sleep {
+                    strategy: time::Constant(time::Duration::from_secs(1)),
+                }
+                .timeout(
+                    time::Instant::now() + time::Duration::from_secs(30),
+                    || async {
+                        match self.client.status().await {
+                            Ok(status) => ControlFlow::Break(status),
+                            Err(_) => {
+                                self.metrics.record_reconnect();
+                                ControlFlow::Continue(())
+                            }
+                        }
+                    },
+                )
+                .await;
+                let status = match status_result {
+                    Ok(status) => status,
+                    Err(_) => return ControlFlow::Break(Err(())),
+                };
+                let mut synced = consecutive_synced.load(Ordering::Relaxed);
+                let flow = should_proceed(&status, &self.config, &mut synced);
+                consecutive_synced.store(synced, Ordering::Relaxed);
+                flow
+            })
             .await;
-            let status = match status_result {
-                Ok(status) => status,
-                Err(_) => return ControlFlow::Break(Err(())),
-            };
-            if status.sync_info.catching_up {
-                ControlFlow::Continue(())
-            } else {
-                ControlFlow::Break(Ok(()))
-            }
-        })
-        .await;
-        if let Err(()) = result {
+        if let Ok(Ok(())) = result {
+            tracing::info!("Broadcaster successfully started.");
+        } else {
             tracing::error!("Broadcaster failed to connect to CometBFT node");
             return;
-        } else {
-            tracing::info!("Broadcaster successfully started.");
         }
+        let mut queue = VecDeque::new();
         loop {
-            if let Some(msg) = self.receiver.recv().await {
-                let _ = self.client.broadcast_tx_sync(msg).await;
+            if queue.is_empty() {
+                match self.receiver.recv().await {
+                    Some(msg) => queue.push_back(msg),
+                    None => continue,
+                }
+            }
+            while let Ok(msg) = self.receiver.try_recv() {
+                queue.push_back(msg);
+            }
+            let Some(msg) = queue.pop_front() else {
+                continue;
+            };
+            self.metrics.set_queue_depth(queue.len());
+            let tx_hash = Hash::sha256(&msg);
+            let broadcast = time::timeout(
+                self.config.broadcast_timeout,
+                self.client.broadcast_tx_sync(msg),
+            )
+            .await;
+            // NB: neither failing to reach the node altogether, nor
+            // the node failing to acknowledge the tx in time count
+            // as CheckTx rejections
+            let response = match broadcast {
+                Ok(Ok(response)) => response,
+                Ok(Err(_)) => {
+                    self.metrics.record_broadcast(false);
+                    continue;
+                }
+                Err(_) => {
+                    tracing::warn!(
+                        %tx_hash,
+                        timeout = ?self.config.broadcast_timeout,
+                        "The node did not acknowledge a broadcast tx in time. \
+                         It may still have been accepted into its mempool."
+                    );
+                    self.metrics.record_broadcast_timeout();
+                    continue;
+                }
+            };
+            let accepted = response.code.is_ok();
+            self.metrics.record_broadcast(accepted);
+            if let Some(cooldown) = self.circuit_breaker.record(accepted) {
+                tracing::warn!(
+                    rejections = MAX_CONSECUTIVE_REJECTIONS,
+                    ?cooldown,
+                    log = %response.log,
+                    "The node has rejected every tx recently broadcast, \
+                     pausing the broadcaster"
+                );
+                time::sleep(cooldown).await;
             }
         }
     }
 
     /// Loop until an abort signal is received, forwarding messages over
     /// the HTTP client as they are received from the receiver.
+    ///
+    /// Returns the reason why the broadcaster stopped running.
     pub async fn run(
         &mut self,
         abort_recv: tokio::sync::oneshot::Receiver<()>,
         genesis_time: DateTimeUtc,
-    ) {
+    ) -> BroadcasterExit {
         tracing::info!("Starting broadcaster.");
-        tokio::select! {
+        let exit = tokio::select! {
             _ = self.run_loop(genesis_time) => {
                 tracing::error!("Broadcaster unexpectedly shut down.");
-                tracing::info!("Shutting down broadcaster...");
+                BroadcasterExit::LoopFailed
             },
             resp_sender = abort_recv => {
                 match resp_sender {
-                    Ok(_) => {
-                        tracing::info!("Shutting down broadcaster...");
-                    },
+                    Ok(_) => BroadcasterExit::Aborted,
                     Err(err) => {
                         tracing::error!("The broadcaster abort sender has unexpectedly dropped: {}", err);
-                        tracing::info!("Shutting down broadcaster...");
+                        BroadcasterExit::AbortSenderDropped
                     }
                 }
             }
+        };
+        tracing::info!(
+            succeeded = self.metrics.broadcasts_succeeded(),
+            failed = self.metrics.broadcasts_failed(),
+            timed_out = self.metrics.broadcasts_timed_out(),
+            queued = self.metrics.queue_depth(),
+            reconnects = self.metrics.reconnects(),
+            "Shutting down broadcaster..."
+        );
+        exit
+    }
+}
+
+#[cfg(test)]
+mod test_broadcaster {
+    use super::*;
+
+    /// Test that the circuit breaker trips after the maximum
+    /// number of consecutive rejections, and that successful
+    /// broadcasts reset the count.
+    #[test]
+    fn test_circuit_breaker() {
+        let mut breaker = CircuitBreaker {
+            consecutive_rejections: 0,
+            max_consecutive_rejections: 3,
+            cooldown: REJECTIONS_COOLDOWN,
+        };
+        assert_eq!(breaker.record(false), None);
+        assert_eq!(breaker.record(false), None);
+        assert_eq!(breaker.record(true), None);
+        assert_eq!(breaker.record(false), None);
+        assert_eq!(breaker.record(false), None);
+        assert_eq!(breaker.record(false), Some(REJECTIONS_COOLDOWN));
+        // the count is reset once the breaker trips
+        assert_eq!(breaker.record(false), None);
+    }
+
+    /// Build a synthetic status of a CometBFT node.
+    fn status(network: &str, catching_up: bool) -> Status {
+        serde_json::from_value(serde_json::json!({
+            "node_info": {
+                "protocol_version": {
+                    "p2p": "8",
+                    "block": "11",
+                    "app": "0"
+                },
+                "id": "6b9a1e2f3c4d5e6f708192a3b4c5d6e7f8091a2b",
+                "listen_addr": "tcp://0.0.0.0:26656",
+                "network": network,
+                "version": "0.37.2",
+                "channels": "40202122233038606100",
+                "moniker": "test",
+                "other": {
+                    "tx_index": "on",
+                    "rpc_address": "tcp://0.0.0.0:26657"
+                }
+            },
+            "sync_info": {
+                "latest_block_hash": "",
+                "latest_app_hash": "",
+                "latest_block_height": "1",
+                "latest_block_time": "2024-01-01T00:00:00Z",
+                "earliest_block_hash": "",
+                "earliest_app_hash": "",
+                "earliest_block_height": "1",
+                "earliest_block_time": "2024-01-01T00:00:00Z",
+                "catching_up": catching_up
+            },
+            "validator_info": {
+                "address": "0000000000000000000000000000000000000000",
+                "pub_key": {
+                    "type": "tendermint/PubKeyEd25519",
+                    "value": "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA="
+                },
+                "voting_power": "0"
+            }
+        }))
+        .expect("The status of the node should deserialize")
+    }
+
+    /// Test that the broadcaster starts once the node is synced.
+    #[test]
+    fn test_should_proceed_synced() {
+        let config = BroadcasterConfig::default();
+        let mut synced = 0;
+        assert_eq!(
+            should_proceed(&status("chain", false), &config, &mut synced),
+            ControlFlow::Break(Ok(()))
+        );
+    }
+
+    /// Test that the broadcaster waits while the node is catching up.
+    #[test]
+    fn test_should_proceed_catching_up() {
+        let config = BroadcasterConfig::default();
+        let mut synced = 0;
+        assert_eq!(
+            should_proceed(&status("chain", true), &config, &mut synced),
+            ControlFlow::Continue(())
+        );
+        assert_eq!(synced, 0);
+    }
+
+    /// Test that the broadcaster is not started if the node runs
+    /// an unexpected chain.
+    #[test]
+    fn test_should_proceed_wrong_chain() {
+        let config = BroadcasterConfig {
+            chain_id: Some(ChainId("expected-chain".to_string())),
+            ..Default::default()
+        };
+        let mut synced = 0;
+        assert_eq!(
+            should_proceed(&status("other-chain", false), &config, &mut synced),
+            ControlFlow::Break(Err(()))
+        );
+        assert_eq!(
+            should_proceed(
+                &status("expected-chain", false),
+                &config,
+                &mut synced
+            ),
+            ControlFlow::Break(Ok(()))
+        );
+    }
+
+    /// Test that the node must report to be synced in a number of
+    /// consecutive checks, before the broadcaster starts.
+    #[test]
+    fn test_should_proceed_debounce() {
+        let config = BroadcasterConfig {
+            synced_checks: 3,
+            ..Default::default()
+        };
+        let synced_status = status("chain", false);
+        let mut synced = 0;
+        assert_eq!(
+            should_proceed(&synced_status, &config, &mut synced),
+            ControlFlow::Continue(())
+        );
+        assert_eq!(
+            should_proceed(&synced_status, &config, &mut synced),
+            ControlFlow::Continue(())
+        );
+        // catching up again resets the count
+        assert_eq!(
+            should_proceed(&status("chain", true), &config, &mut synced),
+            ControlFlow::Continue(())
+        );
+        assert_eq!(synced, 0);
+        for _ in 0..2 {
+            assert_eq!(
+                should_proceed(&synced_status, &config, &mut synced),
+                ControlFlow::Continue(())
+            );
+        }
+        assert_eq!(
+            should_proceed(&synced_status, &config, &mut synced),
+            ControlFlow::Break(Ok(()))
+        );
+    }
+
+    /// A [`MetricsRecorder`] storing the updates it receives.
+    #[derive(Default)]
+    struct TestRecorder {
+        described: std::sync::Mutex<Vec<(&'static str, MetricKind)>>,
+        values: std::sync::Mutex<std::collections::HashMap<&'static str, u64>>,
+    }
+
+    impl MetricsRecorder for TestRecorder {
+        fn describe(
+            &self,
+            name: &'static str,
+            kind: MetricKind,
+            _description: &'static str,
+        ) {
+            self.described.lock().unwrap().push((name, kind));
+        }
+
+        fn increment_counter(&self, name: &'static str, value: u64) {
+            *self.values.lock().unwrap().entry(name).or_default() += value;
+        }
+
+        fn set_gauge(&self, name: &'static str, value: u64) {
+            self.values.lock().unwrap().insert(name, value);
+        }
+    }
+
+    /// Test that the broadcaster metrics are registered with, and
+    /// forwarded to, a metrics recorder.
+    #[test]
+    fn test_metrics_recorder() {
+        let recorder = Arc::new(TestRecorder::default());
+        describe_metrics(&*recorder);
+        assert_eq!(
+            *recorder.described.lock().unwrap(),
+            vec![
+                (BROADCASTS_SUCCEEDED, MetricKind::Counter),
+                (BROADCASTS_FAILED, MetricKind::Counter),
+                (BROADCASTS_TIMED_OUT, MetricKind::Counter),
+                (QUEUE_DEPTH, MetricKind::Gauge),
+                (RECONNECTS, MetricKind::Counter),
+            ]
+        );
+
+        let metrics = BroadcasterMetrics {
+            recorder: Some(recorder.clone() as Arc<dyn MetricsRecorder>),
+            ..Default::default()
+        };
+        metrics.record_broadcast(true);
+        metrics.record_broadcast(true);
+        metrics.record_broadcast(false);
+        metrics.record_broadcast_timeout();
+        metrics.record_reconnect();
+        metrics.set_queue_depth(5);
+        metrics.set_queue_depth(2);
+
+        assert_eq!(metrics.broadcasts_succeeded(), 2);
+        assert_eq!(metrics.broadcasts_failed(), 1);
+        assert_eq!(metrics.broadcasts_timed_out(), 1);
+        assert_eq!(metrics.reconnects(), 1);
+        assert_eq!(metrics.queue_depth(), 2);
+        let values = recorder.values.lock().unwrap();
+        assert_eq!(values[BROADCASTS_SUCCEEDED], 2);
+        assert_eq!(values[BROADCASTS_FAILED], 1);
+        assert_eq!(values[BROADCASTS_TIMED_OUT], 1);
+        assert_eq!(values[RECONNECTS], 1);
+        assert_eq!(values[QUEUE_DEPTH], 2);
+    }
+
+    /// A [`Clock`] whose time only advances while sleeping.
+    struct TestClock {
+        now: std::sync::Mutex<DateTimeUtc>,
+        slept: std::sync::Mutex<Vec<time::Duration>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Clock for TestClock {
+        fn now(&self) -> DateTimeUtc {
+            *self.now.lock().unwrap()
+        }
+
+        async fn sleep(&self, duration: time::Duration) {
+            let mut now = self.now.lock().unwrap();
+            *now = *now
+                + namada::types::time::Duration::from_std(duration).unwrap();
+            self.slept.lock().unwrap().push(duration);
         }
     }
+
+    /// Test that the broadcaster waits for the genesis time of the
+    /// chain, if it has not been reached yet.
+    #[tokio::test]
+    async fn test_wait_for_genesis() {
+        let start = DateTimeUtc::now();
+        let clock = TestClock {
+            now: std::sync::Mutex::new(start),
+            slept: Default::default(),
+        };
+        let genesis_time = start + namada::types::time::Duration::seconds(60);
+
+        wait_for_genesis(&clock, genesis_time).await;
+        assert_eq!(
+            *clock.slept.lock().unwrap(),
+            vec![time::Duration::from_secs(60)]
+        );
+        assert_eq!(clock.now(), genesis_time);
+
+        // past genesis, the broadcaster proceeds right away
+        wait_for_genesis(&clock, start).await;
+        wait_for_genesis(&clock, genesis_time).await;
+        assert_eq!(clock.slept.lock().unwrap().len(), 1);
+    }
 }
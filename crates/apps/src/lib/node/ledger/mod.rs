@@ -10,6 +10,7 @@ use std::convert::TryInto;
 use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::thread;
 
 use byte_unit::Byte;
@@ -34,7 +35,9 @@ use crate::config::utils::{convert_tm_addr_to_socket_addr, num_of_threads};
 use crate::config::{ethereum_bridge, TendermintMode};
 use crate::facade::tendermint::v0_37::abci::response;
 use crate::facade::tower_abci::{split, Server};
-use crate::node::ledger::broadcaster::Broadcaster;
+use crate::node::ledger::broadcaster::{
+    Broadcaster, BroadcasterConfig, BroadcasterExit, TracingRecorder,
+};
 use crate::node::ledger::ethereum_oracle as oracle;
 use crate::node::ledger::shell::{Error, MempoolTxType, Shell};
 use crate::node::ledger::shims::abcipp_shim::AbcippShim;
@@ -444,15 +447,32 @@ fn start_abci_broadcaster_shell(
     ) {
         let (bc_abort_send, bc_abort_recv) =
             tokio::sync::oneshot::channel::<()>();
+        let broadcaster_config = BroadcasterConfig {
+            chain_id: Some(config.chain_id.clone()),
+            ..Default::default()
+        };
 
         spawner
             .spawn_abortable("Broadcaster", move |aborter| async move {
                 // Construct a service for broadcasting protocol txs from
                 // the ledger
                 let mut broadcaster =
-                    Broadcaster::new(rpc_address, broadcaster_receiver);
-                broadcaster.run(bc_abort_recv, genesis_time).await;
-                tracing::info!("Broadcaster is no longer running.");
+                    Broadcaster::new(rpc_address, broadcaster_receiver)
+                        .with_config(broadcaster_config)
+                        .with_metrics_recorder(Arc::new(TracingRecorder));
+                let exit = broadcaster.run(bc_abort_recv, genesis_time).await;
+                match exit {
+                    BroadcasterExit::Aborted => {
+                        tracing::info!("Broadcaster is no longer running.");
+                    }
+                    BroadcasterExit::AbortSenderDropped
+                    | BroadcasterExit::LoopFailed => {
+                        tracing::error!(
+                            ?exit,
+                            "Broadcaster is no longer running."
+                        );
+                    }
+                }
 
                 drop(aborter);
             })
@@ -4,7 +4,7 @@ use std::pin::Pin;
 use std::task::{Context, Poll};
 
 use lazy_static::lazy_static;
-use namada::types::io::{prompt_aux, read_aux, Io};
+use namada::types::io::{prompt_aux, read_aux, read_line_aux, Io};
 use tempfile::tempdir;
 use tokio::io::{AsyncRead, ReadBuf};
 
@@ -118,6 +118,10 @@ impl Io for TestingIo {
         read_aux(&*TESTIN).await
     }
 
+    async fn read_line(&self) -> tokio::io::Result<String> {
+        read_line_aux(&*TESTIN).await
+    }
+
     async fn prompt(&self, question: impl AsRef<str>) -> String {
         prompt_aux(&*TESTIN, tokio::io::stdout(), question.as_ref()).await
     }
@@ -2,10 +2,12 @@
 //! Ethereum bridge pool
 
 use std::borrow::Cow;
+use std::str::FromStr;
 
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use borsh_ext::BorshSerializeExt;
 use ethabi::token::Token;
+use eyre::eyre;
 use namada_macros::StorageKeys;
 use serde::{Deserialize, Serialize};
 
@@ -109,6 +111,25 @@ impl std::fmt::Display for TransferToEthereumKind {
     }
 }
 
+impl FromStr for TransferToEthereumKind {
+    type Err = eyre::Error;
+
+    /// Parses a [`TransferToEthereumKind`] from its case insensitive
+    /// name, e.g. "erc20" or "nut".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("erc20") {
+            Ok(Self::Erc20)
+        } else if s.eq_ignore_ascii_case("nut") {
+            Ok(Self::Nut)
+        } else {
+            Err(eyre!(
+                "unknown transfer to Ethereum kind {s:?}, expected one of \
+                 \"erc20\" or \"nut\""
+            ))
+        }
+    }
+}
+
 /// Additional data appended to a [`TransferToEthereumEvent`] to
 /// construct a [`PendingTransfer`].
 #[derive(
@@ -271,6 +292,24 @@ impl PendingTransfer {
     }
 }
 
+impl std::fmt::Display for PendingTransfer {
+    /// Print a compact, one line summary of a [`PendingTransfer`].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} | {} -> {} | {} {} ({}) | fee: {} {}",
+            self.keccak256().to_hex(),
+            self.transfer.sender,
+            self.transfer.recipient,
+            self.transfer.amount,
+            self.transfer.asset,
+            self.transfer.kind,
+            self.gas_fee.amount,
+            self.gas_fee.token,
+        )
+    }
+}
+
 impl From<&PendingTransfer> for ethbridge_structs::Erc20Transfer {
     fn from(pending: &PendingTransfer) -> Self {
         let HashDigest(data_digest) = pending.appendix().checksum();
@@ -433,10 +472,76 @@ pub mod testing {
 
 #[cfg(test)]
 mod test_eth_bridge_pool_types {
+    use proptest::prelude::*;
+
+    use super::testing::arb_pending_transfer;
     use super::*;
     use crate::types::address::nam;
     use crate::types::address::testing::established_address_1;
 
+    proptest! {
+        /// Test that [`PendingTransfer`]s round-trip through their
+        /// borsh encoding, and that their keccak hash is preserved.
+        #[test]
+        fn test_pending_transfer_borsh_roundtrip(
+            pending in arb_pending_transfer(),
+        ) {
+            let bytes = pending.serialize_to_vec();
+            let decoded = PendingTransfer::try_from_slice(&bytes)
+                .expect("Test failed");
+            prop_assert_eq!(&decoded, &pending);
+            prop_assert_eq!(decoded.keccak256(), pending.keccak256());
+        }
+
+        /// Test that [`PendingTransfer`]s round-trip through their
+        /// json encoding, and that their keccak hash is preserved.
+        #[test]
+        fn test_pending_transfer_serde_roundtrip(
+            pending in arb_pending_transfer(),
+        ) {
+            let json = serde_json::to_string(&pending).expect("Test failed");
+            let decoded: PendingTransfer =
+                serde_json::from_str(&json).expect("Test failed");
+            prop_assert_eq!(&decoded, &pending);
+            prop_assert_eq!(decoded.keccak256(), pending.keccak256());
+        }
+
+        /// Test that the keccak hash of a [`PendingTransfer`] matches
+        /// the hash of the transfer event relayed to Namada.
+        #[test]
+        fn test_pending_transfer_keccak_matches_event(
+            pending in arb_pending_transfer(),
+        ) {
+            let event: TransferToEthereumEvent = (&pending).into();
+            prop_assert_eq!(pending.keccak256(), event.keccak256());
+        }
+    }
+
+    /// Test that the keccak hash of a [`PendingTransfer`] is stable,
+    /// against a known vector.
+    #[test]
+    fn test_pending_transfer_keccak_vector() {
+        let pending = PendingTransfer {
+            transfer: TransferToEthereum {
+                kind: TransferToEthereumKind::Erc20,
+                amount: 10u64.into(),
+                asset: EthAddress([0xaa; 20]),
+                recipient: EthAddress([0xbb; 20]),
+                sender: established_address_1(),
+            },
+            gas_fee: GasFee {
+                token: nam(),
+                amount: 10u64.into(),
+                payer: established_address_1(),
+            },
+        };
+        let expected = KeccakHash::try_from(
+            "4e3078d3880a8ee55a768c76d71883e88c98046b74c104715814f6ac9f82ffdd",
+        )
+        .expect("Test failed");
+        assert_eq!(pending.keccak256(), expected);
+    }
+
     /// Test that [`PendingTransfer`] and [`TransferToEthereum`]
     /// have the same keccak hash, after being ABI encoded.
     #[test]
@@ -458,4 +563,54 @@ mod test_eth_bridge_pool_types {
         let event: TransferToEthereumEvent = (&pending).into();
         assert_eq!(pending.keccak256(), event.keccak256());
     }
+
+    /// Test the one line summary of a [`PendingTransfer`].
+    #[test]
+    fn test_pending_transfer_display() {
+        let pending = PendingTransfer {
+            transfer: TransferToEthereum {
+                kind: TransferToEthereumKind::Nut,
+                amount: 10u64.into(),
+                asset: EthAddress([0xaa; 20]),
+                recipient: EthAddress([0xbb; 20]),
+                sender: established_address_1(),
+            },
+            gas_fee: GasFee {
+                token: nam(),
+                amount: 5u64.into(),
+                payer: established_address_1(),
+            },
+        };
+        let expected = format!(
+            "{} | {} -> {} | 10 {} (NUT) | fee: 5 {}",
+            pending.keccak256(),
+            established_address_1(),
+            EthAddress([0xbb; 20]),
+            EthAddress([0xaa; 20]),
+            nam(),
+        );
+        assert_eq!(pending.to_string(), expected);
+    }
+
+    /// Test that transfer to Ethereum kinds can be parsed from
+    /// their names, and that unknown kinds are rejected.
+    #[test]
+    fn test_parse_transfer_kind() {
+        for kind in [TransferToEthereumKind::Erc20, TransferToEthereumKind::Nut]
+        {
+            assert_eq!(
+                kind.to_string().parse::<TransferToEthereumKind>().ok(),
+                Some(kind)
+            );
+        }
+        assert_eq!(
+            "erc20".parse::<TransferToEthereumKind>().ok(),
+            Some(TransferToEthereumKind::Erc20)
+        );
+        assert_eq!(
+            "nut".parse::<TransferToEthereumKind>().ok(),
+            Some(TransferToEthereumKind::Nut)
+        );
+        assert!("erc721".parse::<TransferToEthereumKind>().is_err());
+    }
 }
@@ -3,9 +3,10 @@
 //! on Ethereum.
 use std::convert::{TryFrom, TryInto};
 use std::fmt;
+use std::str::FromStr;
 
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
-use data_encoding::HEXUPPER;
+use data_encoding::{HEXLOWER, HEXUPPER_PERMISSIVE};
 use ethabi::Token;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
@@ -49,6 +50,12 @@ impl KeccakHash {
     pub fn is_zero(&self) -> bool {
         self.0 == [0; 32]
     }
+
+    /// Format this [`KeccakHash`] as a `0x` prefixed, lowercase
+    /// hex string, as commonly expected by Ethereum tooling.
+    pub fn to_hex(&self) -> String {
+        format!("0x{}", HEXLOWER.encode(&self.0))
+    }
 }
 
 impl fmt::Display for KeccakHash {
@@ -84,6 +91,14 @@ impl TryFrom<&[u8]> for KeccakHash {
     }
 }
 
+impl FromStr for KeccakHash {
+    type Err = TryFromError;
+
+    fn from_str(string: &str) -> Result<Self, TryFromError> {
+        string.try_into()
+    }
+}
+
 impl TryFrom<String> for KeccakHash {
     type Error = TryFromError;
 
@@ -96,7 +111,8 @@ impl TryFrom<&str> for KeccakHash {
     type Error = TryFromError;
 
     fn try_from(string: &str) -> Result<Self, TryFromError> {
-        let bytes: Vec<u8> = HEXUPPER
+        let string = string.strip_prefix("0x").unwrap_or(string);
+        let bytes: Vec<u8> = HEXUPPER_PERMISSIVE
             .decode(string.as_bytes())
             .map_err(TryFromError::FromStringError)?;
         Self::try_from(bytes.as_slice())
@@ -181,4 +197,19 @@ mod tests {
 
         assert_eq!(deserialized, hash);
     }
+
+    #[test]
+    fn test_keccak_hex_roundtrip() {
+        let mut hash = KeccakHash([0; 32]);
+
+        for i in 0..32 {
+            hash.0[i] = i as u8;
+        }
+
+        let hex = hash.to_hex();
+        assert!(hex.starts_with("0x000102"));
+        assert_eq!(hex, hex.to_lowercase());
+        assert_eq!(KeccakHash::try_from(hex.as_str()).unwrap(), hash);
+        assert_eq!(KeccakHash::try_from(hash.to_string()).unwrap(), hash);
+    }
 }
@@ -5,6 +5,7 @@ use std::fmt::{Display, Formatter};
 use std::io::Read;
 use std::iter::Sum;
 use std::ops::{Add, AddAssign, Mul};
+use std::str::FromStr;
 
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use ethabi::ethereum_types as ethereum;
@@ -238,6 +239,44 @@ impl Display for FractionalVotingPower {
     }
 }
 
+impl FromStr for FractionalVotingPower {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_voting_power_threshold(s).map_err(|e| eyre!(e))
+    }
+}
+
+/// Parse a user supplied threshold of voting power, such as `2/3`,
+/// validating that it is a fraction between zero and one inclusive.
+pub fn parse_voting_power_threshold(
+    s: &str,
+) -> std::result::Result<FractionalVotingPower, String> {
+    let [numer, denom]: [&str; 2] = s
+        .split('/')
+        .collect::<Vec<&str>>()
+        .try_into()
+        .map_err(|_| {
+            format!("Expected a '/' separated pair of numbers, got {s:?}")
+        })?;
+    let numer = Uint::from_str_radix(numer.trim(), 10)
+        .map_err(|e| format!("Invalid numerator {numer:?}: {e}"))?;
+    let denom = Uint::from_str_radix(denom.trim(), 10)
+        .map_err(|e| format!("Invalid denominator {denom:?}: {e}"))?;
+    if denom.is_zero() {
+        return Err(format!(
+            "The denominator of the voting power threshold {s:?} can't be \
+             zero"
+        ));
+    }
+    if numer > denom {
+        return Err(format!(
+            "The voting power threshold {s:?} can't be greater than one"
+        ));
+    }
+    FractionalVotingPower::new(numer, denom).map_err(|e| e.to_string())
+}
+
 impl BorshSerialize for FractionalVotingPower {
     fn serialize<W: std::io::Write>(
         &self,
@@ -307,16 +346,9 @@ impl<'de> Visitor<'de> for VPVisitor {
     where
         E: de::Error,
     {
-        let [numer, denom]: [&str; 2] =
-            value.split('/').collect::<Vec<&str>>().try_into().or(Err(
-                de::Error::custom("Expected a '/' separated pair of numbers"),
-            ))?;
-        let numer = Uint::from_str_radix(numer.trim(), 10)
-            .map_err(|e| de::Error::custom(e.to_string()))?;
-        let denom = Uint::from_str_radix(denom.trim(), 10)
-            .map_err(|e| de::Error::custom(e.to_string()))?;
-        FractionalVotingPower::new(numer, denom)
-            .map_err(|e| de::Error::custom(e.to_string()))
+        value
+            .parse()
+            .map_err(|e: eyre::Report| de::Error::custom(e.to_string()))
     }
 
     fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
@@ -394,4 +426,49 @@ mod tests {
             serde_json::from_str(&serialized).expect("Test failed");
         assert_eq!(deserialized, vp);
     }
+
+    /// Test parsing [`FractionalVotingPower`] values from strings.
+    #[test]
+    fn test_parse_fractional_voting_power() {
+        let vp: FractionalVotingPower = "1/3".parse().expect("Test failed");
+        assert_eq!(vp, FractionalVotingPower::ONE_THIRD);
+        let vp: FractionalVotingPower = "0 / 1".parse().expect("Test failed");
+        assert_eq!(vp, FractionalVotingPower::NULL);
+        assert!("3/2".parse::<FractionalVotingPower>().is_err());
+        assert!("1/0".parse::<FractionalVotingPower>().is_err());
+        assert!("1/2/3".parse::<FractionalVotingPower>().is_err());
+        assert!("0.5".parse::<FractionalVotingPower>().is_err());
+    }
+
+    /// Test the validation of user supplied voting power thresholds.
+    #[test]
+    fn test_parse_voting_power_threshold() {
+        // boundaries of the valid range
+        assert_eq!(
+            parse_voting_power_threshold("0/5"),
+            Ok(FractionalVotingPower::NULL)
+        );
+        assert_eq!(
+            parse_voting_power_threshold("7/7"),
+            Ok(FractionalVotingPower::WHOLE)
+        );
+        assert_eq!(
+            parse_voting_power_threshold(" 2 / 3 "),
+            Ok(FractionalVotingPower::TWO_THIRDS)
+        );
+
+        // invalid inputs
+        for invalid in
+            ["", "1", "1/", "/2", "-1/2", "1/-2", "0.5/1", "1/2/3", "a/b"]
+        {
+            assert!(
+                parse_voting_power_threshold(invalid).is_err(),
+                "{invalid:?} should be rejected"
+            );
+        }
+        let err = parse_voting_power_threshold("0/0").unwrap_err();
+        assert!(err.contains("denominator"));
+        let err = parse_voting_power_threshold("4/3").unwrap_err();
+        assert!(err.contains("greater than one"));
+    }
 }
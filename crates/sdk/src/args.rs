@@ -7,12 +7,14 @@ use std::time::Duration as StdDuration;
 use namada_core::types::address::Address;
 use namada_core::types::chain::ChainId;
 use namada_core::types::dec::Dec;
+use namada_core::types::eth_bridge_pool::TransferToEthereumKind;
 use namada_core::types::ethereum_events::EthAddress;
 use namada_core::types::keccak::KeccakHash;
 use namada_core::types::key::{common, SchemeType};
 use namada_core::types::masp::PaymentAddress;
-use namada_core::types::storage::Epoch;
+use namada_core::types::storage::{BlockHeight, Epoch};
 use namada_core::types::time::DateTimeUtc;
+use namada_core::types::voting_power::FractionalVotingPower;
 use namada_core::types::{storage, token};
 use namada_governance::cli::onchain::{
     DefaultProposal, PgfFundingProposal, PgfStewardProposal,
@@ -88,6 +90,17 @@ pub struct BpConversionTableEntry {
     pub alias: String,
     /// Conversion rate from the given token to gwei.
     pub conversion_rate: f64,
+    /// The time at which the conversion rate was set, if known.
+    #[serde(default)]
+    pub as_of: Option<DateTimeUtc>,
+    /// The weight given to the fees paid in the token, relative to
+    /// the fees paid in other tokens, when recommending a batch of
+    /// transfers. Fees are valued at their equivalent in gwei, times
+    /// this weight.
+    ///
+    /// If unset, fees are valued at their equivalent in gwei.
+    #[serde(default)]
+    pub fee_weight: Option<f64>,
 }
 
 impl NamadaTypes for SdkTypes {
@@ -2233,17 +2246,115 @@ pub struct RecommendBatch<C: NamadaTypes = SdkTypes> {
     pub gas: Option<u64>,
     /// Bridge pool recommendations conversion rates table.
     pub conversion_table: C::BpConversionTable,
+    /// Fail if no batch of transfers yielding a net profit
+    /// can be recommended.
+    pub only_profitable: bool,
+    /// Transfers backed by more than this fraction of the voting
+    /// power are deemed likely to have already been relayed, and
+    /// are not recommended.
+    pub in_progress_threshold: FractionalVotingPower,
+    /// The maximum amount of time to wait for each query
+    /// to the ledger node to complete.
+    pub rpc_timeout: StdDuration,
+    /// The objective optimized by the recommended batch.
+    pub objective: Objective,
+    /// The transfers whose fees are paid in tokens with conversion
+    /// rates set longer than this amount of time ago are not
+    /// recommended.
+    ///
+    /// If unset, the age of conversion rates is not checked.
+    pub max_rate_age: Option<StdDuration>,
+    /// Additionally display the hashes of the recommended transfers
+    /// sorted lexicographically, such that the output is stable across
+    /// runs recommending the same batch.
+    pub sort_by_hash: bool,
+    /// The minimum number of transfers in a recommended batch, such
+    /// that the fixed cost of relaying it is amortized.
+    ///
+    /// If unset, batches of any size are recommended.
+    pub min_batch_size: Option<usize>,
+    /// Print the decisions of the recommender over each eligible
+    /// transfer, to audit why it was, or was not, recommended.
+    pub trace: bool,
+    /// The maximum number of signed transfers the recommendation
+    /// may process. Recommendations over larger pools fail.
+    pub max_transfers: usize,
+}
+
+/// The objective optimized by the Bridge pool recommender.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Objective {
+    /// Maximize the net profit of the relayer.
+    #[default]
+    MaxProfit,
+    /// Relay as many transfers as possible, as long as the
+    /// total cost of the relay stays within budget.
+    MaxThroughput,
+}
+
+impl std::str::FromStr for Objective {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "max-profit" => Ok(Self::MaxProfit),
+            "max-throughput" => Ok(Self::MaxThroughput),
+            _ => Err(format!(
+                "Unknown objective {s:?}, expected one of max-profit or \
+                 max-throughput"
+            )),
+        }
+    }
+}
+
+/// Histogram of the fees paid by the transfers in the Bridge pool.
+#[derive(Clone, Debug)]
+pub struct BridgePoolFeeHistogram<C: NamadaTypes = SdkTypes> {
+    /// The query parameters.
+    pub query: Query<C>,
+    /// Bridge pool recommendations conversion rates table.
+    pub conversion_table: C::BpConversionTable,
+    /// The number of buckets of the histogram.
+    pub buckets: u64,
+    /// Print the histogram as JSON, rather than as text.
+    pub json: bool,
+}
+
+/// Preview the relay of the batch of transfers recommended by
+/// the Bridge pool recommender, without relaying it.
+#[derive(Clone, Debug)]
+pub struct PreviewRelay<C: NamadaTypes = SdkTypes> {
+    /// The arguments of the batch recommendation.
+    pub recommend: RecommendBatch<C>,
+    /// The Namada address for receiving fees for relaying.
+    pub relayer: Address,
+    /// The Ethereum RPC endpoint.
+    pub eth_rpc_endpoint: C::EthereumAddress,
+    /// The address of the Ethereum wallet to dry-run the relay
+    /// from. If unset, the default wallet is used.
+    pub eth_addr: Option<EthAddress>,
+    /// The maximum amount of time to wait on each request
+    /// to the Ethereum node.
+    ///
+    /// If unset, the value of the
+    /// [`ENV_VAR_ETH_REQUEST_TIMEOUT`](bridge_pool::ENV_VAR_ETH_REQUEST_TIMEOUT)
+    /// environment variable is used, if present.
+    pub request_timeout: Option<StdDuration>,
 }
 
 /// A transfer to be added to the Ethereum bridge pool.
 #[derive(Clone, Debug)]
 pub struct EthereumBridgePool<C: NamadaTypes = SdkTypes> {
-    /// Whether the transfer is for a NUT.
+    /// The kind of the transfer, e.g. whether it is for a NUT.
     ///
     /// By default, we add wrapped ERC20s onto the
     /// Bridge pool.
-    pub nut: bool,
-    /// The args for building a tx to the bridge pool
+    pub kind: TransferToEthereumKind,
+    /// The args for building a tx to the bridge pool.
+    ///
+    /// These include the fee of the wrapper tx submitted to Namada,
+    /// which is distinct from the fee paid to relay the transfer
+    /// to Ethereum.
     pub tx: Tx<C>,
     /// The type of token
     pub asset: EthAddress,
@@ -2253,16 +2364,30 @@ pub struct EthereumBridgePool<C: NamadaTypes = SdkTypes> {
     pub sender: C::Address,
     /// The amount to be transferred
     pub amount: InputAmount,
-    /// The amount of gas fees
+    /// The decimal places of the ERC20 token being transferred.
+    ///
+    /// If set, the amount is validated against these decimal
+    /// places, rather than only against the denomination of the
+    /// wrapped token in Namada.
+    pub erc20_decimals: Option<u8>,
+    /// The amount of gas fees escrowed in the Bridge pool, paid
+    /// to the relayer of the transfer to Ethereum.
+    ///
+    /// This is not the fee of the wrapper tx, which is set in
+    /// [`EthereumBridgePool::tx`].
     pub fee_amount: InputAmount,
     /// The account of fee payer.
     ///
     /// If unset, it is the same as the sender.
     pub fee_payer: Option<C::Address>,
-    /// The token in which the gas is being paid
+    /// The token in which the gas escrowed in the Bridge pool
+    /// is being paid
     pub fee_token: C::AddrOrNativeToken,
     /// Path to the tx WASM code file
     pub code_path: PathBuf,
+    /// Describe the built transaction, rather than
+    /// signing and submitting it.
+    pub describe: bool,
 }
 
 impl<C: NamadaTypes> TxBuilder<C> for EthereumBridgePool<C> {
@@ -2278,12 +2403,25 @@ impl<C: NamadaTypes> TxBuilder<C> for EthereumBridgePool<C> {
 }
 
 impl<C: NamadaTypes> EthereumBridgePool<C> {
+    /// The kind of the transfer, e.g. whether it is for a NUT.
+    ///
+    /// By default, we add wrapped ERC20s onto the
+    /// Bridge pool.
+    pub fn kind(self, kind: TransferToEthereumKind) -> Self {
+        Self { kind, ..self }
+    }
+
     /// Whether the transfer is for a NUT.
     ///
     /// By default, we add wrapped ERC20s onto the
     /// Bridge pool.
+    #[deprecated(note = "Use `EthereumBridgePool::kind` instead")]
     pub fn nut(self, nut: bool) -> Self {
-        Self { nut, ..self }
+        self.kind(if nut {
+            TransferToEthereumKind::Nut
+        } else {
+            TransferToEthereumKind::Erc20
+        })
     }
 
     /// The type of token
@@ -2306,7 +2444,20 @@ impl<C: NamadaTypes> EthereumBridgePool<C> {
         Self { amount, ..self }
     }
 
-    /// The amount of gas fees
+    /// The decimal places of the ERC20 token being transferred
+    pub fn erc20_decimals(self, erc20_decimals: u8) -> Self {
+        Self {
+            erc20_decimals: Some(erc20_decimals),
+            ..self
+        }
+    }
+
+    /// The amount of gas fees escrowed in the Bridge pool, paid
+    /// to the relayer of the transfer to Ethereum.
+    ///
+    /// This shadows [`TxBuilder::fee_amount`]. Use
+    /// [`EthereumBridgePool::wrapper_fee_amount`] to set the fee
+    /// of the wrapper tx.
     pub fn fee_amount(self, fee_amount: InputAmount) -> Self {
         Self { fee_amount, ..self }
     }
@@ -2321,7 +2472,12 @@ impl<C: NamadaTypes> EthereumBridgePool<C> {
         }
     }
 
-    /// The token in which the gas is being paid
+    /// The token in which the gas escrowed in the Bridge pool
+    /// is being paid.
+    ///
+    /// This shadows [`TxBuilder::fee_token`]. Use
+    /// [`EthereumBridgePool::wrapper_fee_token`] to set the token
+    /// paying the fee of the wrapper tx.
     pub fn fee_token(self, fee_token: C::Address) -> Self {
         Self {
             fee_token: fee_token.into(),
@@ -2329,10 +2485,32 @@ impl<C: NamadaTypes> EthereumBridgePool<C> {
         }
     }
 
+    /// The amount being paid (for gas unit) to include the wrapper
+    /// tx in a Namada block
+    pub fn wrapper_fee_amount(self, fee_amount: InputAmount) -> Self {
+        self.tx(|tx| tx.fee_amount(fee_amount))
+    }
+
+    /// The token in which the fee of the wrapper tx is being paid
+    pub fn wrapper_fee_token(self, fee_token: C::Address) -> Self {
+        self.tx(|tx| tx.fee_token(fee_token))
+    }
+
+    /// The max amount of gas used to process the wrapper tx
+    pub fn wrapper_gas_limit(self, gas_limit: GasLimit) -> Self {
+        self.tx(|tx| tx.gas_limit(gas_limit))
+    }
+
     /// Path to the tx WASM code file
     pub fn code_path(self, code_path: PathBuf) -> Self {
         Self { code_path, ..self }
     }
+
+    /// Describe the built transaction, rather than
+    /// signing and submitting it.
+    pub fn describe(self, describe: bool) -> Self {
+        Self { describe, ..self }
+    }
 }
 
 impl EthereumBridgePool {
@@ -2360,6 +2538,149 @@ pub struct BridgePoolProof<C: NamadaTypes = SdkTypes> {
     /// the Bridge pool, to compensate the Ethereum relay
     /// procedure.
     pub relayer: Address,
+    /// Halt without prompting if any of the transfers have
+    /// surpassed the security threshold in Namada.
+    pub fail_on_warning: bool,
+    /// Compress the ABI encoded proof with gzip.
+    pub compress_proof: bool,
+    /// Bridge pool recommendations conversion rates table.
+    ///
+    /// If set, the total fees of the proof are also expressed
+    /// in gwei.
+    pub conversion_table: Option<C::BpConversionTable>,
+    /// The height at which to read the signed Bridge pool root
+    /// to construct the proof against.
+    ///
+    /// If unset, the latest signed root is used.
+    pub target_root: Option<BlockHeight>,
+    /// Report the marginal relay gas and the fees of each transfer
+    /// in the proof.
+    pub transfer_breakdown: bool,
+}
+
+/// Arguments to keep track of the fees earned by relayers.
+#[derive(Debug, Clone)]
+pub struct RelayerFeeLedger<C: NamadaTypes = SdkTypes> {
+    /// The address of the ledger node as host:port
+    pub ledger_address: C::TendermintAddress,
+    /// Path to the file where the relayed batches are recorded.
+    pub ledger_file: PathBuf,
+    /// Path to a proof output by the proof construction command,
+    /// whose batch is recorded as relayed.
+    pub record: Option<PathBuf>,
+    /// The Ethereum RPC endpoint, used to check that the batch
+    /// being recorded has been relayed.
+    ///
+    /// Required to record a batch.
+    pub eth_rpc_endpoint: Option<C::EthereumAddress>,
+    /// Only aggregate the fees of the batches recorded since
+    /// this time.
+    pub since: Option<DateTimeUtc>,
+}
+
+/// Arguments to query the contents of the Ethereum Bridge pool.
+#[derive(Debug, Clone)]
+pub struct QueryBridgePool<C: NamadaTypes = SdkTypes> {
+    /// The address of the ledger node as host:port
+    pub ledger_address: C::TendermintAddress,
+    /// Annotate each transfer with the block heights and times
+    /// of the key events of its lifecycle.
+    ///
+    /// This requires additional queries to the ledger node.
+    pub lifecycle: bool,
+    /// Write the json payload of the query to this file,
+    /// instead of printing it out.
+    pub output: Option<PathBuf>,
+    /// The path of the RPC of the ledger node at which the
+    /// Ethereum bridge queries are exposed.
+    ///
+    /// If unset, the default path is used. Storage and block
+    /// queries always go through the default `shell` path.
+    pub rpc_path: Option<String>,
+    /// The maximum number of transfers the query may process.
+    /// Queries of larger pools fail.
+    pub max_transfers: usize,
+}
+
+/// Arguments to query the contents of the Ethereum Bridge pool
+/// covered by the latest signed root.
+#[derive(Debug, Clone)]
+pub struct QuerySignedBridgePool<C: NamadaTypes = SdkTypes> {
+    /// The address of the ledger node as host:port
+    pub ledger_address: C::TendermintAddress,
+    /// Write the json payload of the query to this file,
+    /// instead of printing it out.
+    pub output: Option<PathBuf>,
+    /// The path of the RPC of the ledger node at which the
+    /// Ethereum bridge queries are exposed.
+    ///
+    /// If unset, the default path is used. Storage and block
+    /// queries always go through the default `shell` path.
+    pub rpc_path: Option<String>,
+    /// The maximum number of transfers the query may process.
+    /// Queries of larger pools fail.
+    pub max_transfers: usize,
+}
+
+/// Arguments to query a summary of the status of the Ethereum
+/// Bridge pool.
+#[derive(Debug, Clone)]
+pub struct BridgePoolStatus<C: NamadaTypes = SdkTypes> {
+    /// The address of the ledger node as host:port
+    pub ledger_address: C::TendermintAddress,
+    /// The Ethereum RPC endpoint, used to query the nonce of
+    /// the Bridge contract.
+    ///
+    /// If unset, the nonce of the Bridge contract is not queried.
+    pub eth_rpc_endpoint: Option<C::EthereumAddress>,
+}
+
+/// Arguments to check the consistency of the latest signed Bridge
+/// pool root with the Bridge contract on Ethereum.
+#[derive(Debug, Clone)]
+pub struct BridgeConsistency<C: NamadaTypes = SdkTypes> {
+    /// The address of the ledger node as host:port
+    pub ledger_address: C::TendermintAddress,
+    /// The Ethereum RPC endpoint.
+    pub eth_rpc_endpoint: C::EthereumAddress,
+}
+
+/// Arguments to query a set of transfers in the Ethereum Bridge pool.
+#[derive(Debug, Clone)]
+pub struct BridgePoolTransfers<C: NamadaTypes = SdkTypes> {
+    /// The address of the ledger node as host:port
+    pub ledger_address: C::TendermintAddress,
+    /// The keccak hashes of the transfers to query.
+    pub transfers: Vec<KeccakHash>,
+}
+
+/// Arguments to query the relay progress of transfers to Ethereum.
+#[derive(Debug, Clone)]
+pub struct RelayProgress<C: NamadaTypes = SdkTypes> {
+    /// The address of the ledger node as host:port
+    pub ledger_address: C::TendermintAddress,
+    /// If set, keep polling the relay progress at this interval,
+    /// reporting the rate at which transfers gain voting power.
+    pub watch: Option<StdDuration>,
+    /// The amount of time after which a watched transfer that
+    /// has not gained any voting power is flagged as stalled.
+    pub stall_timeout: StdDuration,
+    /// Write the json payload of the query to this file,
+    /// instead of printing it out.
+    ///
+    /// This is ignored when watching the relay progress.
+    pub output: Option<PathBuf>,
+}
+
+/// Arguments to monitor the growth of the Ethereum Bridge pool.
+#[derive(Debug, Clone)]
+pub struct MonitorBridgePoolGrowth<C: NamadaTypes = SdkTypes> {
+    /// The address of the ledger node as host:port
+    pub ledger_address: C::TendermintAddress,
+    /// The interval at which the Bridge pool is polled.
+    pub interval: StdDuration,
+    /// The window of time over which growth rates are estimated.
+    pub window: StdDuration,
 }
 
 /// Arguments to an Ethereum Bridge pool relay operation.
@@ -2371,7 +2692,13 @@ pub struct RelayBridgePoolProof<C: NamadaTypes = SdkTypes> {
     pub transfers: Vec<KeccakHash>,
     /// The Namada address for receiving fees for relaying
     pub relayer: Address,
-    /// The number of confirmations to wait for on Ethereum
+    /// The number of confirmations to wait for on Ethereum,
+    /// i.e. the number of blocks, counting the block including
+    /// the relay transaction. One confirmation means the relay
+    /// transaction was included in a block.
+    ///
+    /// Zero confirmations are raised to
+    /// [`MIN_RELAY_CONFIRMATIONS`](bridge_pool::MIN_RELAY_CONFIRMATIONS).
     pub confirmations: u64,
     /// The Ethereum RPC endpoint.
     pub eth_rpc_endpoint: C::EthereumAddress,
@@ -2381,6 +2708,162 @@ pub struct RelayBridgePoolProof<C: NamadaTypes = SdkTypes> {
     /// The price of Ethereum gas, during the
     /// relay call.
     pub gas_price: Option<u64>,
+    /// The percentage by which to bump the gas price of the
+    /// relay call, if it is rejected as underpriced.
+    ///
+    /// If unset, underpriced relay calls are not resubmitted.
+    pub gas_price_bump: Option<u64>,
+    /// The maximum gas price the relay call can be
+    /// escalated to.
+    pub max_gas_price: Option<u64>,
+    /// Only relay if the current Ethereum gas price, in gwei,
+    /// does not exceed this ceiling.
+    ///
+    /// The break-even gas price of a batch, as reported by the
+    /// Bridge pool recommender, is a natural ceiling.
+    pub max_gas_price_gwei: Option<u64>,
+    /// The address of the Ethereum wallet to pay the gas fees.
+    /// If unset, the default wallet is used.
+    pub eth_addr: Option<EthAddress>,
+    /// Synchronize with the network, or exit immediately,
+    /// if the Ethereum node has fallen behind.
+    pub sync: bool,
+    /// Safe mode overrides keyboard interrupt signals, to ensure
+    /// Ethereum transfers aren't canceled midway through.
+    pub safe_mode: bool,
+    /// The interval between polls of the Ethereum node, while
+    /// waiting for the relay transaction to be confirmed.
+    ///
+    /// If unset, the provider's default interval is used. Lower
+    /// values decrease the confirmation latency, at the expense
+    /// of a higher volume of RPC requests.
+    pub poll_interval: Option<StdDuration>,
+    /// The amount of time the relay transaction may be neither
+    /// pending in the mempool nor mined, before it is re-broadcast.
+    ///
+    /// If set, the Ethereum node is polled with an exponential
+    /// backoff while waiting for confirmations, starting at the
+    /// `poll_interval`. Otherwise, the relay transaction is never
+    /// re-broadcast.
+    pub rebroadcast_after: Option<StdDuration>,
+    /// The maximum number of times the relay transaction is
+    /// re-broadcast, before giving up on it.
+    ///
+    /// If unset, the
+    /// [`DEFAULT_MAX_REBROADCASTS`](bridge_pool::DEFAULT_MAX_REBROADCASTS)
+    /// is used.
+    pub max_rebroadcasts: Option<u64>,
+    /// The maximum amount of time to wait for the relay
+    /// transaction to be confirmed.
+    ///
+    /// If unset, the relay is awaited until it is confirmed.
+    pub confirmation_timeout: Option<StdDuration>,
+    /// The maximum amount of time to wait on each request
+    /// to the Ethereum node.
+    ///
+    /// If unset, the value of the
+    /// [`ENV_VAR_ETH_REQUEST_TIMEOUT`](bridge_pool::ENV_VAR_ETH_REQUEST_TIMEOUT)
+    /// environment variable is used, if present. Otherwise, requests
+    /// are only constrained by the timeout of the Ethereum middleware.
+    pub request_timeout: Option<StdDuration>,
+    /// Path to a file where the state of the relay operation
+    /// is persisted, once the relay transaction has been sent.
+    ///
+    /// If the file is present when a relay is started, the
+    /// previously sent transaction is resumed, rather than
+    /// relaying the transfers again.
+    pub state_file: Option<PathBuf>,
+    /// Path to a proof output by the proof construction
+    /// command, to relay instead of generating a new one.
+    ///
+    /// Compressed proofs are decompressed before being relayed.
+    /// The proof must cover exactly the requested transfers, if
+    /// any, which otherwise default to those of the proof.
+    pub proof_file: Option<PathBuf>,
+    /// The height at which to read the signed Bridge pool root
+    /// to construct the proof against.
+    ///
+    /// If unset, the latest signed root is used.
+    pub target_root: Option<BlockHeight>,
+    /// Halt without prompting if any of the transfers have
+    /// surpassed the security threshold in Namada.
+    pub fail_on_warning: bool,
+    /// The Ethereum gas estimated by the Bridge pool recommender
+    /// for the relayed transfers.
+    ///
+    /// If set, a report comparing it against the gas actually
+    /// used by the relay is emitted once the relay is confirmed.
+    pub estimated_gas: Option<u64>,
+    /// URL of a private transaction relay, to which the signed relay
+    /// transaction is submitted with `eth_sendPrivateTransaction`.
+    ///
+    /// If unset, the relay transaction is broadcast through the
+    /// public mempool of the Ethereum node.
+    pub private_relay_url: Option<String>,
+    /// The maximum size of the calldata of the relay transaction,
+    /// in bytes. Larger proofs are not relayed.
+    ///
+    /// If unset, the
+    /// [`DEFAULT_MAX_RELAY_CALLDATA_SIZE`](bridge_pool::DEFAULT_MAX_RELAY_CALLDATA_SIZE)
+    /// is used.
+    pub max_calldata_size: Option<u64>,
+    /// Attach an EIP-2930 access list to the relay transaction,
+    /// generated with `eth_createAccessList`, to reduce its gas cost.
+    pub access_list: bool,
+    /// Path to a file to which a line of json is appended for each
+    /// relay attempt, recording its gas settings and outcome.
+    pub audit_log: Option<PathBuf>,
+    /// Print the destination and the calldata of the relay
+    /// transaction, instead of sending it to Ethereum.
+    pub print_calldata: bool,
+}
+
+/// Arguments to export a Bridge pool proof, along with everything
+/// required to relay it to Ethereum without access to Namada.
+#[derive(Debug, Clone)]
+pub struct ExportRelayBundle<C: NamadaTypes = SdkTypes> {
+    /// The address of the ledger node as host:port
+    pub ledger_address: C::TendermintAddress,
+    /// The hashes of the transfers to be relayed
+    pub transfers: Vec<KeccakHash>,
+    /// The Namada address for receiving fees for relaying
+    pub relayer: Address,
+    /// The id of the Ethereum chain the proof is to be relayed to.
+    pub eth_chain_id: u64,
+    /// Path to the file where the relay bundle is written.
+    pub bundle_file: PathBuf,
+    /// The height at which to read the signed Bridge pool root
+    /// to construct the proof against.
+    ///
+    /// If unset, the latest signed root is used.
+    pub target_root: Option<BlockHeight>,
+    /// Halt without prompting if any of the transfers have
+    /// surpassed the security threshold in Namada.
+    pub fail_on_warning: bool,
+}
+
+/// Arguments to relay a bundle exported with [`ExportRelayBundle`]
+/// to Ethereum.
+#[derive(Debug, Clone)]
+pub struct RelayFromBundle<C: NamadaTypes = SdkTypes> {
+    /// Path to the relay bundle.
+    pub bundle_file: PathBuf,
+    /// The Ethereum RPC endpoint.
+    pub eth_rpc_endpoint: C::EthereumAddress,
+    /// The number of confirmations to wait for on Ethereum.
+    ///
+    /// Zero confirmations are raised to
+    /// [`MIN_RELAY_CONFIRMATIONS`](bridge_pool::MIN_RELAY_CONFIRMATIONS).
+    pub confirmations: u64,
+    /// The Ethereum gas that can be spent during
+    /// the relay call.
+    pub gas: Option<u64>,
+    /// The price of Ethereum gas, during the
+    /// relay call.
+    pub gas_price: Option<u64>,
+    /// Only relay if the current Ethereum gas price, in gwei,
+    /// does not exceed this ceiling.
+    pub max_gas_price_gwei: Option<u64>,
     /// The address of the Ethereum wallet to pay the gas fees.
     /// If unset, the default wallet is used.
     pub eth_addr: Option<EthAddress>,
@@ -2390,6 +2873,38 @@ pub struct RelayBridgePoolProof<C: NamadaTypes = SdkTypes> {
     /// Safe mode overrides keyboard interrupt signals, to ensure
     /// Ethereum transfers aren't canceled midway through.
     pub safe_mode: bool,
+    /// The interval between polls of the Ethereum node, while
+    /// waiting for the relay transaction to be confirmed.
+    ///
+    /// If unset, the provider's default interval is used.
+    pub poll_interval: Option<StdDuration>,
+    /// The amount of time the relay transaction may be neither
+    /// pending in the mempool nor mined, before it is re-broadcast.
+    ///
+    /// If unset, the relay transaction is never re-broadcast.
+    pub rebroadcast_after: Option<StdDuration>,
+    /// The maximum number of times the relay transaction is
+    /// re-broadcast, before giving up on it.
+    ///
+    /// If unset, the
+    /// [`DEFAULT_MAX_REBROADCASTS`](bridge_pool::DEFAULT_MAX_REBROADCASTS)
+    /// is used.
+    pub max_rebroadcasts: Option<u64>,
+    /// The maximum amount of time to wait for the relay
+    /// transaction to be confirmed.
+    ///
+    /// If unset, the relay is awaited until it is confirmed.
+    pub confirmation_timeout: Option<StdDuration>,
+    /// The maximum amount of time to wait on each request
+    /// to the Ethereum node.
+    ///
+    /// If unset, the value of the
+    /// [`ENV_VAR_ETH_REQUEST_TIMEOUT`](bridge_pool::ENV_VAR_ETH_REQUEST_TIMEOUT)
+    /// environment variable is used, if present.
+    pub request_timeout: Option<StdDuration>,
+    /// Path to a file to which a line of json is appended for each
+    /// relay attempt, recording its gas settings and outcome.
+    pub audit_log: Option<PathBuf>,
 }
 
 /// Bridge validator set arguments.
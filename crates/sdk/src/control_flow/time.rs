@@ -1,6 +1,8 @@
 //! Time related logic for futures.
 
+use std::collections::hash_map::RandomState;
 use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
 use std::ops::ControlFlow;
 
 use thiserror::Error;
@@ -144,6 +146,115 @@ where
     }
 }
 
+/// Configurable retry policy of fallible tasks, such as reconnecting
+/// to a node, with an exponential and optionally jittered backoff.
+///
+/// The `n`-th retry (starting at one) sleeps for `base * 2^(n - 1)`,
+/// bounded by `max_delay`. The default policy matches the startup
+/// loop of the ledger's broadcaster, doubling the backoff from two
+/// seconds onwards and retrying forever.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// The backoff before the first retry.
+    pub base: Duration,
+    /// The upper bound on the backoff between retries. Backoffs
+    /// are never shorter than `base`, however.
+    pub max_delay: Duration,
+    /// The fraction of each backoff, in the range `[0, 1]`, by which
+    /// it is randomly increased or decreased, to keep clients from
+    /// retrying in lockstep.
+    pub jitter: f64,
+    /// The maximum number of times to run the task, if any.
+    pub max_attempts: Option<usize>,
+    /// The maximum amount of time to spend retrying the task,
+    /// if any.
+    pub overall_deadline: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            base: Duration::from_secs(2),
+            max_delay: Duration::from_secs(256),
+            jitter: 0.0,
+            max_attempts: None,
+            overall_deadline: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Return the backoff before the given retry, without jitter.
+    ///
+    /// The first retry is numbered one.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = 1u32
+            .checked_shl(retry.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        self.base
+            .saturating_mul(factor)
+            .min(self.max_delay)
+            .max(self.base)
+    }
+
+    /// Return the backoff before the given retry, with a random
+    /// jitter applied to it.
+    pub fn jittered_delay(&self, retry: u32) -> Duration {
+        let delay = self.delay(retry);
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        if jitter == 0.0 {
+            return delay;
+        }
+        // NB: the randomly seeded hasher of the std lib is a good
+        // enough source of entropy for jitter
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u32(retry);
+        let unit = (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64;
+        delay.mul_f64(1.0 - jitter + 2.0 * jitter * unit)
+    }
+
+    /// Run a fallible task until it succeeds, retrying it according
+    /// to this policy.
+    ///
+    /// Fails if the task could not be completed within the maximum
+    /// number of attempts, or before the overall deadline.
+    pub async fn run<T, F, G>(&self, future_gen: G) -> Result<T, Error>
+    where
+        G: FnMut() -> F,
+        F: Future<Output = ControlFlow<T>>,
+    {
+        let sleep = Sleep { strategy: *self };
+        let attempts = async {
+            match self.max_attempts {
+                Some(max_attempts) => {
+                    sleep.retry(max_attempts, future_gen).await
+                }
+                None => Ok(sleep.run(future_gen).await),
+            }
+        };
+        match self.overall_deadline {
+            Some(deadline) => timeout(deadline, attempts).await?,
+            None => attempts.await,
+        }
+    }
+}
+
+impl SleepStrategy for RetryPolicy {
+    type State = u32;
+
+    fn new_state() -> u32 {
+        0
+    }
+
+    fn backoff(&self, state: &u32) -> Duration {
+        self.jittered_delay(*state)
+    }
+
+    fn next_state(&self, state: &mut u32) {
+        *state = state.saturating_add(1);
+    }
+}
+
 /// A [`SleepStrategy`] adaptor, to run async tasks with custom
 /// sleep durations.
 #[repr(transparent)]
@@ -328,6 +439,18 @@ pub async fn sleep(dur: Duration) {
     internal_sleep(dur).await;
 }
 
+/// Run a future to completion, or time out if it does not
+/// complete within the given duration.
+#[inline]
+pub async fn timeout<F: Future>(
+    dur: Duration,
+    future: F,
+) -> Result<F::Output, Error> {
+    internal_timeout_at(Instant::now() + dur, future)
+        .await
+        .map_err(|_| Error::Elapsed)
+}
+
 #[cfg(target_family = "wasm")]
 #[allow(missing_docs)]
 mod internal {
@@ -375,3 +498,52 @@ mod internal {
 }
 
 pub use internal::*;
+
+#[cfg(test)]
+mod test_time {
+    use super::*;
+
+    /// Test that retry backoffs grow exponentially, up to their
+    /// upper bound, and that jitter keeps them within its range.
+    #[test]
+    fn test_retry_policy_backoff() {
+        let policy = RetryPolicy {
+            max_delay: Duration::from_secs(20),
+            ..Default::default()
+        };
+        let delays: Vec<_> =
+            (1..=6).map(|retry| policy.delay(retry).as_secs()).collect();
+        assert_eq!(delays, vec![2, 4, 8, 16, 20, 20]);
+        assert_eq!(policy.delay(u32::MAX), Duration::from_secs(20));
+
+        let policy = RetryPolicy {
+            jitter: 0.5,
+            ..policy
+        };
+        for retry in 1..=6 {
+            let delay = policy.delay(retry);
+            let jittered = policy.jittered_delay(retry);
+            assert!(jittered >= delay / 2 && jittered <= delay * 3 / 2);
+        }
+    }
+
+    /// Test that retry policies give up after their maximum number
+    /// of attempts.
+    #[tokio::test]
+    async fn test_retry_policy_max_attempts() {
+        let policy = RetryPolicy {
+            base: Duration::from_millis(1),
+            max_attempts: Some(3),
+            ..Default::default()
+        };
+        let attempts = std::cell::Cell::new(0);
+        let result: Result<(), _> = policy
+            .run(|| async {
+                attempts.set(attempts.get() + 1);
+                ControlFlow::Continue(())
+            })
+            .await;
+        assert!(matches!(result, Err(Error::MaxRetriesExceeded)));
+        assert_eq!(attempts.get(), 3);
+    }
+}
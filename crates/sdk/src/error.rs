@@ -344,6 +344,15 @@ pub enum EthereumBridgeError {
     /// Ethereum RPC error.
     #[error("RPC error: {0}")]
     Rpc(String),
+    /// Error writing the output of a Bridge pool query.
+    #[error("Failed to write the output of the query: {0}")]
+    QueryOutput(String),
+    /// Error submitting a transaction to a private relay.
+    #[error("Private relay error: {0}")]
+    PrivateRelay(String),
+    /// No Bridge pool root has been signed yet.
+    #[error("No Bridge pool root has been signed yet")]
+    NoSignedRoot,
     /// Error reading the signed Bridge pool.
     #[error("Failed to read signed Bridge pool: {0}")]
     ReadSignedBridgePool(String),
@@ -356,11 +365,53 @@ pub enum EthereumBridgeError {
     /// Error querying Ethereum voting powers.
     #[error("Failed to query Ethereum voting powers: {0}")]
     QueryVotingPowers(String),
+    /// No Ethereum voting powers were found at some block height.
+    #[error("No validator voting powers found at height {0}")]
+    NoVotingPowers(u64),
     /// Ethereum node timeout error.
     #[error(
         "Timed out while attempting to communicate with the Ethereum node"
     )]
     NodeTimeout,
+    /// Ethereum RPC request timeout error.
+    #[error("Timed out waiting for the Ethereum node to {0}")]
+    RequestTimeout(String),
+    /// The relay transaction fell out of the mempool for good.
+    #[error("The relay transaction was dropped from the mempool: {0}")]
+    RelayDropped(String),
+    /// The relay transaction was not confirmed in time.
+    #[error("The relay transaction {0} was not confirmed")]
+    RelayUnconfirmed(String),
+    /// Targeted Bridge pool root skips over unrelayed roots.
+    #[error(
+        "The targeted Bridge pool root with nonce {root_nonce} cannot be \
+         relayed before the roots preceding it, as the Bridge contract is at \
+         nonce {contract_nonce}"
+    )]
+    NonSequentialRoot {
+        /// The nonce of the targeted root.
+        root_nonce: String,
+        /// The nonce of the Bridge contract.
+        contract_nonce: String,
+    },
+    /// A root relayed by a pipeline of relays does not follow the
+    /// previously relayed root.
+    #[error(
+        "Expected the next relayed Bridge pool root to have nonce \
+         {expected}, got {got}"
+    )]
+    PipelineNonceGap {
+        /// The nonce following the previously relayed root.
+        expected: String,
+        /// The nonce of the next root to relay.
+        got: String,
+    },
+    /// The Ethereum bridge is not active on the chain.
+    #[error("The Ethereum bridge is not active on this chain")]
+    BridgeInactive,
+    /// Ledger node query timeout error.
+    #[error("Timed out waiting for the ledger node to {0}")]
+    QueryTimeout(String),
     /// Error generating Bridge pool proof.
     #[error("Failed to generate Bridge pool proof: {0}")]
     GenBridgePoolProof(String),
@@ -373,18 +424,215 @@ pub enum EthereumBridgeError {
     /// Invalid Bridge pool nonce error.
     #[error("The Bridge pool nonce is invalid")]
     InvalidBpNonce,
+    /// No transfers were specified to construct a proof for.
+    #[error("No transfers specified")]
+    NoTransfersSpecified,
+    /// No profitable batch of transfers could be recommended.
+    #[error("No profitable batch of transfers to relay: {0}")]
+    NoProfitableBatch(String),
+    /// A transfer cannot be included in a recommended batch, at
+    /// any fee.
+    #[error("The transfer cannot make the next recommended batch: {0}")]
+    UnreachableBatch(String),
+    /// Some of the requested transfers have already surpassed the
+    /// security threshold in Namada.
+    #[error(
+        "Halted generating Bridge pool proof, since the following transfers \
+         have surpassed the security threshold in Namada: {0}"
+    )]
+    TransfersPastSecurityThreshold(String),
+    /// Unsupported Bridge pool proof version error.
+    #[error(
+        "Unsupported Bridge pool proof version {got}, expected version \
+         {expected}"
+    )]
+    UnsupportedProofVersion {
+        /// The version of the received proof.
+        got: u8,
+        /// The proof version supported by the client.
+        expected: u8,
+    },
+    /// The current Ethereum gas price exceeds the ceiling set
+    /// for a relay.
+    #[error(
+        "The current Ethereum gas price of {gas_price} wei exceeds the \
+         ceiling of {ceiling_gwei} gwei"
+    )]
+    GasPriceAboveCeiling {
+        /// The current gas price, in wei.
+        gas_price: String,
+        /// The gas price ceiling, in gwei.
+        ceiling_gwei: u64,
+    },
+    /// The signed Bridge pool root changed since a proof was generated.
+    #[error(
+        "The Bridge pool proof for nonce {proof_nonce} is stale, since the \
+         latest signed root has nonce {signed_nonce}"
+    )]
+    StaleProof {
+        /// The nonce the proof was generated for.
+        proof_nonce: String,
+        /// The nonce of the latest signed Bridge pool root.
+        signed_nonce: String,
+    },
+    /// The relay calldata of a Bridge pool proof is too large.
+    #[error(
+        "The relay calldata of {size} bytes exceeds the maximum of \
+         {max_size} bytes. Consider splitting the batch of transfers into \
+         smaller ones"
+    )]
+    ProofTooLarge {
+        /// The size of the relay calldata, in bytes.
+        size: u64,
+        /// The maximum calldata size, in bytes.
+        max_size: u64,
+    },
+    /// Invalid number of fee histogram buckets error.
+    #[error(
+        "The fee histogram must have between 1 and {max} buckets, but \
+         {buckets} were requested"
+    )]
+    InvalidHistogramBuckets {
+        /// The requested number of buckets.
+        buckets: u64,
+        /// The maximum number of buckets.
+        max: u64,
+    },
     /// Invalid fee token error.
     #[error("An invalid fee token was provided: {0}")]
     InvalidFeeToken(Address),
+    /// Invalid fee payer error.
+    #[error("An invalid fee payer was provided: {payer}: {reason}")]
+    InvalidFeePayer {
+        /// The address of the fee payer.
+        payer: Address,
+        /// The reason why the fee payer is invalid.
+        reason: String,
+    },
     /// Not whitelisted error.
-    #[error("ERC20 is not whitelisted: {0}")]
+    #[error("ERC20 is not whitelisted by the Ethereum bridge: {0}")]
     Erc20NotWhitelisted(EthAddress),
     /// Exceeded token caps error.
     #[error("ERC20 token caps exceeded: {0}")]
     Erc20TokenCapsExceeded(EthAddress),
+    /// Mismatching ERC20 decimal places error.
+    #[error(
+        "The ERC20 token {asset} has {expected} decimal places, but \
+         {provided} were specified"
+    )]
+    Erc20DecimalsMismatch {
+        /// The address of the ERC20 token.
+        asset: EthAddress,
+        /// The decimal places of the wrapped token in Namada.
+        expected: u8,
+        /// The decimal places specified by the user.
+        provided: u8,
+    },
+    /// Invalid transfer amount for an ERC20 token.
+    #[error(
+        "The amount {amount} has a higher precision than the {decimals} \
+         decimal places of the ERC20 token {asset}"
+    )]
+    Erc20AmountPrecision {
+        /// The address of the ERC20 token.
+        asset: EthAddress,
+        /// The amount specified by the user.
+        amount: String,
+        /// The decimal places of the ERC20 token.
+        decimals: u8,
+    },
+    /// Invalid amount of tokens transferred to Ethereum.
+    #[error(
+        "Failed to validate the Bridge pool transfer amount {amount} of \
+         token {token}: {reason}"
+    )]
+    InvalidTransferAmount {
+        /// The address of the transferred token in Namada.
+        token: Address,
+        /// The amount specified by the user.
+        amount: String,
+        /// The reason why the amount is invalid.
+        reason: String,
+    },
+    /// Invalid amount of Bridge pool fees.
+    #[error(
+        "Failed to validate the Bridge pool fee amount {amount} of token \
+         {token}: {reason}"
+    )]
+    InvalidFeeAmount {
+        /// The address of the fee token.
+        token: Address,
+        /// The amount specified by the user.
+        amount: String,
+        /// The reason why the amount is invalid.
+        reason: String,
+    },
+    /// Transfer of tokens held as NUTs error.
+    #[error(
+        "The sender does not hold enough wrapped ERC20 tokens of {0}, but it \
+         does hold enough NUTs of it. NUTs may be redeemed for the ERC20 held \
+         in escrow on Ethereum by adding a NUT transfer to the Bridge pool \
+         (e.g. with `--kind nut`)"
+    )]
+    TransferOfHeldNuts(EthAddress),
     /// Transfer already in pool error.
     #[error("An identical transfer is already present in the Bridge pool")]
     TransferAlreadyInPool,
+    /// Error accessing a relayer fee ledger.
+    #[error("Failed to access the relayer fee ledger: {0}")]
+    FeeLedger(String),
+    /// Error accessing the persisted state of a relay operation.
+    #[error("Failed to access the persisted relay state: {0}")]
+    RelayState(String),
+    /// Error appending to a relay audit log.
+    #[error("Failed to append to the relay audit log: {0}")]
+    RelayAuditLog(String),
+    /// Error accessing, or relaying, a relay bundle.
+    #[error("Invalid relay bundle: {0}")]
+    RelayBundle(String),
+    /// A stored Bridge pool proof does not cover the transfers
+    /// requested to be relayed.
+    #[error(
+        "The stored Bridge pool proof does not cover the requested \
+         transfers: {0}"
+    )]
+    StoredProofMismatch(String),
+    /// A Bridge pool query returned more transfers than allowed.
+    #[error(
+        "The Bridge pool holds {count} transfers, more than the maximum of \
+         {max} transfers that may be queried"
+    )]
+    TooManyTransfers {
+        /// The number of transfers returned by the query.
+        count: usize,
+        /// The maximum number of transfers that may be queried.
+        max: usize,
+    },
+    /// A relay transaction was mined, but reverted.
+    #[error("The relay transaction {tx_hash} reverted: {reason}")]
+    RelayReverted {
+        /// The hash of the relay transaction.
+        tx_hash: String,
+        /// The reason the Bridge contract reverted with.
+        reason: String,
+    },
+    /// A relay was aborted through its abort handle.
+    #[error(
+        "The relay was aborted. If the relay transaction had already been \
+         sent, it may still be mined"
+    )]
+    RelayAborted,
+    /// The Ethereum node is connected to an unexpected chain.
+    #[error(
+        "Expected to relay to Ethereum chain {expected}, but the Ethereum \
+         node is connected to chain {got}"
+    )]
+    EthChainIdMismatch {
+        /// The id of the expected Ethereum chain.
+        expected: u64,
+        /// The id of the chain of the Ethereum node.
+        got: String,
+    },
 }
 
 /// Checks if the given error is an invalid viewing key
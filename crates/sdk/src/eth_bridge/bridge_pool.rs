@@ -2,65 +2,224 @@
 
 use std::borrow::Cow;
 use std::cmp::Ordering;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::future::Future;
+use std::io::{Read, Write};
+use std::num::NonZeroUsize;
+use std::path::Path;
 use std::sync::Arc;
 
+use borsh::BorshDeserialize;
 use borsh_ext::BorshSerializeExt;
+use data_encoding::{HEXLOWER, HEXLOWER_PERMISSIVE};
 use ethbridge_bridge_contract::Bridge;
-use ethers::providers::Middleware;
-use futures::future::FutureExt;
+use ethers::contract::EthError;
+use ethers::providers::{
+    Http, Middleware, MiddlewareError, PendingTransaction, Provider,
+};
+use ethers::types::transaction::eip2718::TypedTransaction;
+use ethers::types::transaction::eip2930::{
+    AccessListWithGasUsed, Eip2930TransactionRequest,
+};
+use ethers::types::{BlockId, BlockNumber, TransactionReceipt, H256, U256};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use futures::future::{self, AbortRegistration, Abortable, Either, FutureExt};
+use futures::stream::{self, StreamExt};
 use namada_core::types::address::{Address, InternalAddress};
+use namada_core::types::dec::{Dec, POS_DECIMAL_PRECISION};
 use namada_core::types::eth_abi::Encode;
 use namada_core::types::eth_bridge_pool::{
-    erc20_token_address, GasFee, PendingTransfer, TransferToEthereum,
-    TransferToEthereumKind,
+    erc20_nut_address, erc20_token_address, GasFee, PendingTransfer,
+    TransferToEthereum, TransferToEthereumKind,
+};
+use namada_core::types::ethereum_events::{EthAddress, Uint as EthUint};
+use namada_core::types::keccak::{keccak_hash, KeccakHash};
+use namada_core::types::key::{secp256k1, Signable, SignableEthMessage};
+use namada_core::types::storage::BlockHeight;
+use namada_core::types::time::DateTimeUtc;
+use namada_core::types::uint::{Uint, I256};
+use namada_core::types::voting_power::{
+    EthBridgeVotingPower, FractionalVotingPower,
+};
+use namada_ethereum_bridge::storage::active_key;
+use namada_ethereum_bridge::storage::bridge_pool::{
+    get_key_from_hash, get_pending_key, get_signed_root_key,
 };
-use namada_core::types::ethereum_events::EthAddress;
-use namada_core::types::keccak::KeccakHash;
-use namada_core::types::voting_power::FractionalVotingPower;
-use namada_ethereum_bridge::storage::bridge_pool::get_pending_key;
+use namada_ethereum_bridge::storage::eth_bridge_queries::{
+    EthBridgeEnabled, EthBridgeStatus,
+};
+use namada_ethereum_bridge::storage::proof::BridgePoolRootProof;
 use namada_token::storage_key::balance_key;
-use namada_token::Amount;
-use namada_tx::Tx;
+use namada_token::{Amount, DenominatedAmount, Denomination};
+use namada_tx::{Section, Tx};
 use num_traits::ops::checked::CheckedSub;
 use owo_colors::OwoColorize;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use super::{block_on_eth_sync, eth_sync_or_exit, BlockOnEthSync};
 use crate::control_flow::install_shutdown_signal;
-use crate::control_flow::time::{Duration, Instant};
+use crate::control_flow::time::{self, Duration, Instant, RetryPolicy};
 use crate::error::{
     EncodingError, Error, EthereumBridgeError, QueryError, TxSubmitError,
 };
 use crate::eth_bridge::ethers::abi::AbiDecode;
 use crate::internal_macros::echo_error;
-use crate::io::Io;
+use crate::io::{prompt_yes_no, Io};
 use crate::queries::{
-    Client, GenBridgePoolProofReq, GenBridgePoolProofRsp, TransferToErcArgs,
-    TransferToEthereumStatus, RPC,
+    BridgePoolTransferHeights, Client, EthBridge, GenBridgePoolProofReq,
+    GenBridgePoolProofRsp, GenVersionedBridgePoolProofRsp, TransferToErcArgs,
+    TransferToEthereumStatus, BRIDGE_POOL_PROOF_VERSION, RPC,
+};
+use crate::rpc::{
+    known_address, query_epoch, query_storage_value, query_storage_value_bytes,
+    query_wasm_code_hash, validate_amount,
 };
-use crate::rpc::{query_storage_value, query_wasm_code_hash, validate_amount};
-use crate::signing::aux_signing_data;
-use crate::tx::prepare_tx;
+use crate::signing::{aux_signing_data, default_sign};
+use crate::tx::{prepare_tx, ProcessTxResponse};
 use crate::{
-    args, display, display_line, edisplay_line, MaybeSync, Namada,
-    SigningTxData,
+    args, display_line, edisplay_line, MaybeSync, Namada, SigningTxData,
 };
 
+/// Env var to set the maximum amount of time to wait on each
+/// request to the Ethereum node, during a Bridge pool relay.
+pub const ENV_VAR_ETH_REQUEST_TIMEOUT: &str = "NAMADA_ETH_REQUEST_TIMEOUT";
+
+/// Env var to set the [`OutputStyle`] of the warnings and errors
+/// printed by Bridge pool commands. One of `blink`, `bold` or `plain`.
+pub const ENV_VAR_BRIDGE_POOL_OUTPUT_STYLE: &str =
+    "NAMADA_BRIDGE_POOL_OUTPUT_STYLE";
+
+/// The styling of the warning and error labels printed by Bridge
+/// pool commands.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OutputStyle {
+    /// Bold, colored and blinking labels.
+    Blink,
+    /// Bold and colored labels.
+    Bold,
+    /// Unstyled labels.
+    Plain,
+}
+
+impl std::str::FromStr for OutputStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "blink" => Ok(Self::Blink),
+            "bold" => Ok(Self::Bold),
+            "plain" => Ok(Self::Plain),
+            _ => Err(format!(
+                "Unknown output style {s:?}, expected one of blink, bold or \
+                 plain"
+            )),
+        }
+    }
+}
+
+impl OutputStyle {
+    /// Read the output style from [`ENV_VAR_BRIDGE_POOL_OUTPUT_STYLE`],
+    /// falling back to the capabilities of the terminal if it is unset
+    /// or invalid.
+    pub fn from_env() -> Self {
+        std::env::var(ENV_VAR_BRIDGE_POOL_OUTPUT_STYLE)
+            .ok()
+            .and_then(|style| style.parse().ok())
+            .unwrap_or_else(Self::detect)
+    }
+
+    /// Pick the richest style supported by the terminal. Blinking text
+    /// is never picked, since many terminals render it as garbage.
+    fn detect() -> Self {
+        use std::io::IsTerminal;
+
+        let no_color =
+            std::env::var_os("NO_COLOR").map_or(false, |val| !val.is_empty());
+        let dumb_term =
+            std::env::var("TERM").map_or(true, |term| term == "dumb");
+        if no_color || dumb_term || !std::io::stdout().is_terminal() {
+            Self::Plain
+        } else {
+            Self::Bold
+        }
+    }
+
+    /// Style a `label` printed on top of a background `color`.
+    fn label(self, label: &str, color: owo_colors::AnsiColors) -> String {
+        match self {
+            Self::Blink => label.on_color(color).bold().blink().to_string(),
+            Self::Bold => label.on_color(color).bold().to_string(),
+            Self::Plain => label.to_string(),
+        }
+    }
+}
+
+/// The label of the warnings printed to the user.
+fn warning_label() -> String {
+    OutputStyle::from_env().label("Warning", owo_colors::AnsiColors::Yellow)
+}
+
+/// The label of the errors printed to the user.
+fn error_label() -> String {
+    OutputStyle::from_env().label("Error", owo_colors::AnsiColors::Red)
+}
+
+/// Progress of a long running Bridge pool operation, reported to
+/// frontends (e.g. GUIs) through a [`ProgressSender`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProgressEvent {
+    /// The contents of the Bridge pool are being fetched.
+    FetchingPool,
+    /// The costs of relaying the transfers are being computed.
+    ComputingCosts,
+    /// A proof is being generated by the ledger.
+    GeneratingProof,
+    /// A proof of the given size, in bytes, has been generated.
+    ProofGenerated {
+        /// The size of the ABI encoded proof.
+        bytes: usize,
+    },
+    /// The relay transaction is being submitted to Ethereum.
+    Relaying,
+    /// The relay transaction was confirmed on Ethereum.
+    Confirmed {
+        /// The hash of the relay transaction.
+        tx_hash: H256,
+    },
+}
+
+/// The sending half of a channel of [`ProgressEvent`]s.
+pub type ProgressSender = tokio::sync::mpsc::Sender<ProgressEvent>;
+
+/// Report a [`ProgressEvent`], if some frontend is listening.
+///
+/// Events are dropped if the channel of the frontend is full, such
+/// that slow frontends never hold up the reported operation.
+fn report_progress(progress: Option<&ProgressSender>, event: ProgressEvent) {
+    if let Some(progress) = progress {
+        // NB: the operation proceeds if the frontend went away
+        _ = progress.try_send(event);
+    }
+}
+
 /// Craft a transaction that adds a transfer to the Ethereum bridge pool.
 pub async fn build_bridge_pool_tx(
     context: &impl Namada,
     args::EthereumBridgePool {
         tx: tx_args,
-        nut,
+        kind,
         asset,
         recipient,
         sender,
         amount,
+        erc20_decimals,
         fee_amount,
         fee_payer,
         fee_token,
         code_path,
+        describe: _,
     }: args::EthereumBridgePool,
 ) -> Result<(Tx, SigningTxData), Error> {
     let sender_ = sender.clone();
@@ -68,11 +227,12 @@ pub async fn build_bridge_pool_tx(
         validate_bridge_pool_tx(
             context,
             tx_args.force,
-            nut,
+            kind,
             asset,
             recipient,
             sender,
             amount,
+            erc20_decimals,
             fee_amount,
             fee_payer,
             fee_token,
@@ -103,6 +263,8 @@ pub async fn build_bridge_pool_tx(
     )
     .add_data(transfer);
 
+    // NB: the wrapper tx pays the fees set in `tx_args`, whereas
+    // the gas fee of the transfer is escrowed in the Bridge pool
     prepare_tx(
         context,
         &tx_args,
@@ -115,42 +277,367 @@ pub async fn build_bridge_pool_tx(
     Ok((tx, signing_data))
 }
 
-/// Perform client validation checks on a Bridge pool transfer.
+/// The maximum number of Bridge pool transactions built concurrently
+/// by [`build_bridge_pool_txs`].
+pub const MAX_CONCURRENT_BRIDGE_POOL_TXS: usize = 8;
+
+/// Craft a transaction for each of the given Bridge pool transfers.
+///
+/// If `fee_payer` is set, it pays the Bridge pool fees of every
+/// transfer. A transfer that fails validation does not prevent the
+/// remaining transactions from being built; instead, the error is
+/// reported at the index of the offending transfer.
+///
+/// Up to [`MAX_CONCURRENT_BRIDGE_POOL_TXS`] transactions are built,
+/// and their amounts validated, concurrently.
+pub async fn build_bridge_pool_txs(
+    context: &impl Namada,
+    transfers: Vec<args::EthereumBridgePool>,
+    fee_payer: Option<Address>,
+) -> Vec<Result<(Tx, SigningTxData), Error>> {
+    stream::iter(transfers)
+        .map(|mut transfer| {
+            if let Some(fee_payer) = &fee_payer {
+                transfer.fee_payer = Some(fee_payer.clone());
+            }
+            build_bridge_pool_tx(context, transfer)
+        })
+        .buffered(MAX_CONCURRENT_BRIDGE_POOL_TXS)
+        .collect()
+        .await
+}
+
+/// The specification of a Bridge pool transfer, as laid out in
+/// a file read by [`load_bridge_pool_transfers`].
+#[derive(Debug, Clone, Deserialize)]
+struct BridgePoolTransferSpec {
+    asset: String,
+    recipient: String,
+    sender: String,
+    amount: String,
+    #[serde(default)]
+    kind: Option<String>,
+    #[serde(default)]
+    fee_amount: Option<String>,
+    #[serde(default)]
+    fee_payer: Option<String>,
+    #[serde(default)]
+    fee_token: Option<String>,
+}
+
+impl BridgePoolTransferSpec {
+    /// Build the arguments of a Bridge pool transfer from this spec,
+    /// taking the arguments it does not specify from `template`.
+    fn into_args(
+        self,
+        template: &args::EthereumBridgePool,
+    ) -> Result<args::EthereumBridgePool, String> {
+        fn parse<T>(field: &str, value: &str) -> Result<T, String>
+        where
+            T: std::str::FromStr,
+            T::Err: std::fmt::Display,
+        {
+            value
+                .trim()
+                .parse()
+                .map_err(|e| format!("Invalid {field} {value:?}: {e}"))
+        }
+
+        let mut args = template.clone();
+        args.asset = parse("asset", &self.asset)?;
+        args.recipient = parse("recipient", &self.recipient)?;
+        args.sender = parse("sender", &self.sender)?;
+        args.amount =
+            args::InputAmount::Unvalidated(parse("amount", &self.amount)?);
+        if let Some(kind) = self.kind {
+            args.kind = parse("kind", &kind)?;
+        }
+        if let Some(fee_amount) = self.fee_amount {
+            args.fee_amount = args::InputAmount::Unvalidated(parse(
+                "fee amount",
+                &fee_amount,
+            )?);
+        }
+        if let Some(fee_payer) = self.fee_payer {
+            args.fee_payer = Some(parse("fee payer", &fee_payer)?);
+        }
+        if let Some(fee_token) = self.fee_token {
+            args.fee_token = parse("fee token", &fee_token)?;
+        }
+        Ok(args)
+    }
+}
+
+/// Load the specs of Bridge pool transfers from a JSON file, to be
+/// built with [`build_bridge_pool_txs`].
+///
+/// The file must contain an array of objects, with the fields
+/// `asset`, `recipient`, `sender` and `amount`. The optional fields
+/// `kind`, `fee_amount`, `fee_payer` and `fee_token` take their
+/// value from `template` when left unset. Each transfer is parsed
+/// independently, such that an invalid entry is reported in the
+/// output without preventing the remaining entries from being loaded.
+pub fn load_bridge_pool_transfers(
+    path: &Path,
+    template: &args::EthereumBridgePool,
+) -> Result<Vec<Result<args::EthereumBridgePool, Error>>, Error> {
+    let contents = std::fs::read_to_string(path).map_err(|e| {
+        Error::Other(format!(
+            "Failed to read Bridge pool transfers from {}: {e}",
+            path.display()
+        ))
+    })?;
+    Ok(parse_transfer_specs(&contents)?
+        .into_iter()
+        .enumerate()
+        .map(|(index, spec)| {
+            serde_json::from_value::<BridgePoolTransferSpec>(spec)
+                .map_err(|e| e.to_string())
+                .and_then(|spec| spec.into_args(template))
+                .map_err(|e| {
+                    Error::Other(format!(
+                        "Invalid Bridge pool transfer at index {index}: {e}"
+                    ))
+                })
+        })
+        .collect())
+}
+
+/// Parse a JSON array of Bridge pool transfer specs, leaving the
+/// validation of each spec to the caller.
+fn parse_transfer_specs(
+    contents: &str,
+) -> Result<Vec<serde_json::Value>, Error> {
+    serde_json::from_str(contents).map_err(|e| {
+        Error::Encode(EncodingError::Serde(format!(
+            "Expected a JSON array of Bridge pool transfers: {e}"
+        )))
+    })
+}
+
+/// Decode the transfer added to the Bridge pool by a transaction
+/// built with [`build_bridge_pool_tx`].
+pub fn bridge_pool_tx_transfer(tx: &Tx) -> Result<PendingTransfer, Error> {
+    tx.data()
+        .ok_or_else(|| {
+            Error::Other("The Bridge pool transaction has no data".into())
+        })
+        .and_then(|data| {
+            PendingTransfer::try_from_slice(&data).map_err(|e| {
+                Error::Encode(EncodingError::Decoding(e.to_string()))
+            })
+        })
+}
+
+/// The outcome of [`ensure_in_bridge_pool`].
+#[derive(Debug)]
+pub enum EnsureInBridgePool {
+    /// The transfer with the given hash was already pending in the
+    /// Bridge pool, so no transaction was submitted.
+    AlreadyPending(KeccakHash),
+    /// A transaction adding the transfer with the given hash to the
+    /// Bridge pool was submitted.
+    Submitted {
+        /// The hash of the transfer.
+        hash: KeccakHash,
+        /// The response to the submission of the transaction.
+        response: ProcessTxResponse,
+    },
+}
+
+/// Add a transfer to the Ethereum bridge pool, unless an identical
+/// transfer is already pending in it.
+///
+/// This makes adding transfers idempotent, such that scripts can be
+/// re-run without submitting the same transfer twice. The public key
+/// of the sender is expected to have been revealed already.
+pub async fn ensure_in_bridge_pool(
+    context: &impl Namada,
+    args: args::EthereumBridgePool,
+) -> Result<EnsureInBridgePool, Error> {
+    // check the pool before building the transaction, since building
+    // it fails if an identical transfer is already pending
+    let transfer = denominate_bridge_pool_transfer(
+        context,
+        args.tx.force,
+        args.kind,
+        args.asset,
+        args.recipient,
+        args.sender.clone(),
+        args.amount,
+        args.erc20_decimals,
+        args.fee_amount,
+        args.fee_payer.clone(),
+        args.fee_token.clone(),
+    )
+    .await?;
+    let hash = transfer.keccak256();
+    let pending = RPC
+        .shell()
+        .storage_has_key(context.client(), &get_pending_key(&transfer))
+        .await
+        .map_err(|e| Error::Query(QueryError::General(e.to_string())))?;
+    if pending {
+        display_line!(
+            context.io(),
+            "Transfer {} is already pending in the Bridge pool",
+            hash.to_hex()
+        );
+        return Ok(EnsureInBridgePool::AlreadyPending(hash));
+    }
+    let tx_args = args.tx.clone();
+    let (mut tx, signing_data) = build_bridge_pool_tx(context, args).await?;
+    context
+        .sign(&mut tx, &tx_args, signing_data, default_sign, ())
+        .await?;
+    let response = context.submit(tx, &tx_args).await?;
+    Ok(EnsureInBridgePool::Submitted { hash, response })
+}
+
+/// Describe the contents of a transaction built with
+/// [`build_bridge_pool_tx`] in a human readable form.
+///
+/// This allows users to inspect the transfer they are about
+/// to submit, before signing it.
+pub fn describe_bridge_pool_tx(tx: &Tx) -> Result<String, Error> {
+    let transfer = bridge_pool_tx_transfer(tx)?;
+    let (code_hash, code_tag) =
+        match tx.get_section(tx.code_sechash()).as_ref().map(Cow::as_ref) {
+            Some(Section::Code(code)) => (code.code.hash(), code.tag.clone()),
+            _ => {
+                return Err(Error::Other(
+                    "The Bridge pool transaction has no code".into(),
+                ));
+            }
+        };
+    let header = tx.header();
+
+    let mut description = String::new();
+    let mut line = |key: &str, value: String| {
+        description.push_str(&format!("{key:>16}: {value}\n"));
+    };
+    line("Transfer hash", transfer.keccak256().to_hex());
+    line("Kind", transfer.transfer.kind.to_string());
+    line("Asset", transfer.transfer.asset.to_string());
+    line("Sender", transfer.transfer.sender.to_string());
+    line("Recipient", transfer.transfer.recipient.to_string());
+    line("Amount", transfer.transfer.amount.to_string());
+    line("Fee token", transfer.gas_fee.token.to_string());
+    line("Fee amount", transfer.gas_fee.amount.to_string());
+    line("Fee payer", transfer.gas_fee.payer.to_string());
+    line(
+        "Code hash",
+        match code_tag {
+            Some(tag) => format!("{code_hash} ({tag})"),
+            None => code_hash.to_string(),
+        },
+    );
+    line("Chain ID", header.chain_id.to_string());
+    line(
+        "Expiration",
+        header
+            .expiration
+            .map(|expiration| expiration.to_string())
+            .unwrap_or_else(|| "none".into()),
+    );
+    if let Some(redemption) = describe_nut_redemption(&transfer) {
+        description.push_str(&format!("\n{redemption}\n"));
+    }
+    Ok(description)
+}
+
+/// Describe how a NUT transfer to Ethereum redeems the NUTs of
+/// its sender.
+///
+/// Returns `None` if the transfer is not a NUT transfer.
+pub fn describe_nut_redemption(transfer: &PendingTransfer) -> Option<String> {
+    if transfer.transfer.kind != TransferToEthereumKind::Nut {
+        return None;
+    }
+    Some(format!(
+        "This transfer burns {amount} NUTs of the ERC20 token {asset} held by \
+         {sender} in Namada. NUTs are minted in place of wrapped tokens that \
+         could not be transferred to Namada, and are redeemed once the \
+         transfer is relayed, at which point the Bridge contract releases \
+         {amount} {asset} held in escrow to {recipient} on Ethereum.",
+        amount = transfer.transfer.amount,
+        asset = transfer.transfer.asset,
+        sender = transfer.transfer.sender,
+        recipient = transfer.transfer.recipient,
+    ))
+}
+
+/// Validate the amount of a Bridge pool transfer and the amount of
+/// its fee, given along with the tokens they are denominated in.
+///
+/// Both amounts are validated concurrently, and the first error
+/// encountered is returned.
+pub async fn validate_transfer_amounts(
+    context: &impl Namada,
+    force: bool,
+    (token, amount): (&Address, args::InputAmount),
+    (fee_token, fee_amount): (&Address, args::InputAmount),
+) -> Result<(DenominatedAmount, DenominatedAmount), Error> {
+    let input_amount = describe_input_amount(&amount);
+    let validate_token_amount = validate_amount(context, amount, token, force)
+        .map(|result| {
+            result.map_err(|e| {
+                Error::EthereumBridge(
+                    EthereumBridgeError::InvalidTransferAmount {
+                        token: token.clone(),
+                        amount: input_amount,
+                        reason: e.to_string(),
+                    },
+                )
+            })
+        });
+
+    let input_fee_amount = describe_input_amount(&fee_amount);
+    let validate_fee_amount =
+        validate_amount(context, fee_amount, fee_token, force).map(|result| {
+            result.map_err(|e| {
+                Error::EthereumBridge(EthereumBridgeError::InvalidFeeAmount {
+                    token: fee_token.clone(),
+                    amount: input_fee_amount,
+                    reason: e.to_string(),
+                })
+            })
+        });
+
+    futures::try_join!(validate_token_amount, validate_fee_amount)
+}
+
+/// Build the [`PendingTransfer`] added to the Bridge pool by a
+/// transaction, denominating its amounts.
 #[allow(clippy::too_many_arguments)]
-async fn validate_bridge_pool_tx(
+async fn denominate_bridge_pool_transfer(
     context: &impl Namada,
     force: bool,
-    nut: bool,
+    kind: TransferToEthereumKind,
     asset: EthAddress,
     recipient: EthAddress,
     sender: Address,
     amount: args::InputAmount,
+    erc20_decimals: Option<u8>,
     fee_amount: args::InputAmount,
     fee_payer: Option<Address>,
     fee_token: Address,
 ) -> Result<PendingTransfer, Error> {
     let token_addr = erc20_token_address(&asset);
-    let validate_token_amount =
-        validate_amount(context, amount, &token_addr, force).map(|result| {
-            result.map_err(|e| {
-                Error::Other(format!(
-                    "Failed to validate Bridge pool transfer amount: {e}"
-                ))
-            })
-        });
-
-    let validate_fee_amount =
-        validate_amount(context, fee_amount, &fee_token, force).map(|result| {
-            result.map_err(|e| {
-                Error::Other(format!(
-                    "Failed to validate Bridge pool fee amount: {e}",
-                ))
-            })
-        });
-
-    // validate amounts
-    let (tok_denominated, fee_denominated) =
-        futures::try_join!(validate_token_amount, validate_fee_amount)?;
+    let amount = match erc20_decimals {
+        Some(decimals) => {
+            validate_erc20_decimals(context, force, &asset, amount, decimals)
+                .await?
+        }
+        None => amount,
+    };
+    let (tok_denominated, fee_denominated) = validate_transfer_amounts(
+        context,
+        force,
+        (&token_addr, amount),
+        (&fee_token, fee_amount),
+    )
+    .await?;
 
     // build pending Bridge pool transfer
     let fee_payer = fee_payer.unwrap_or_else(|| sender.clone());
@@ -160,11 +647,7 @@ async fn validate_bridge_pool_tx(
             recipient,
             sender,
             amount: tok_denominated.amount(),
-            kind: if nut {
-                TransferToEthereumKind::Nut
-            } else {
-                TransferToEthereumKind::Erc20
-            },
+            kind,
         },
         gas_fee: GasFee {
             token: fee_token,
@@ -172,6 +655,38 @@ async fn validate_bridge_pool_tx(
             payer: fee_payer,
         },
     };
+    Ok(transfer)
+}
+
+/// Perform client validation checks on a Bridge pool transfer.
+#[allow(clippy::too_many_arguments)]
+async fn validate_bridge_pool_tx(
+    context: &impl Namada,
+    force: bool,
+    kind: TransferToEthereumKind,
+    asset: EthAddress,
+    recipient: EthAddress,
+    sender: Address,
+    amount: args::InputAmount,
+    erc20_decimals: Option<u8>,
+    fee_amount: args::InputAmount,
+    fee_payer: Option<Address>,
+    fee_token: Address,
+) -> Result<PendingTransfer, Error> {
+    let transfer = denominate_bridge_pool_transfer(
+        context,
+        force,
+        kind,
+        asset,
+        recipient,
+        sender,
+        amount,
+        erc20_decimals,
+        fee_amount,
+        fee_payer,
+        fee_token,
+    )
+    .await?;
 
     if force {
         return Ok(transfer);
@@ -205,6 +720,27 @@ async fn validate_bridge_pool_tx(
             ))
         })?;
 
+    // validate gas fee payer
+    let fee_payer = &transfer.gas_fee.payer;
+    if fee_payer != &transfer.transfer.sender {
+        let reason = match fee_payer {
+            Address::Internal(_) => Some("internal addresses can't pay fees"),
+            Address::Established(_) => {
+                let exists = known_address(context.client(), fee_payer).await?;
+                (!exists).then_some("the account does not exist on chain")
+            }
+            Address::Implicit(_) => None,
+        };
+        if let Some(reason) = reason {
+            return Err(Error::EthereumBridge(
+                EthereumBridgeError::InvalidFeePayer {
+                    payer: fee_payer.clone(),
+                    reason: reason.into(),
+                },
+            ));
+        }
+    }
+
     // validate gas fee token
     match &transfer.gas_fee.token {
         Address::Internal(InternalAddress::Nut(_)) => {
@@ -220,26 +756,33 @@ async fn validate_bridge_pool_tx(
         _ => {}
     }
 
-    // validate wnam token caps + whitelist
-    if transfer.transfer.asset == wnam_addr {
+    // validate erc20 whitelist + wnam token caps
+    //
+    // NB: NUTs are minted in place of wrapped tokens that are
+    // not whitelisted, so they may always be sent back to Ethereum
+    let is_wnam = transfer.transfer.asset == wnam_addr;
+    if is_wnam || transfer.transfer.kind == TransferToEthereumKind::Erc20 {
+        let asset = transfer.transfer.asset;
         let flow_control = RPC
             .shell()
             .eth_bridge()
-            .get_erc20_flow_control(context.client(), &wnam_addr)
+            .get_erc20_flow_control(context.client(), &asset)
             .await
             .map_err(|e| {
                 Error::Query(QueryError::General(format!(
-                    "Failed to read wrapped NAM flow control data: {e}"
+                    "Failed to read the flow control data of ERC20 {asset}: \
+                     {e}"
                 )))
             })?;
 
         if !flow_control.whitelisted {
             return Err(Error::EthereumBridge(
-                EthereumBridgeError::Erc20NotWhitelisted(wnam_addr),
+                EthereumBridgeError::Erc20NotWhitelisted(asset),
             ));
         }
 
-        if flow_control.exceeds_token_caps(transfer.transfer.amount) {
+        if is_wnam && flow_control.exceeds_token_caps(transfer.transfer.amount)
+        {
             return Err(Error::EthereumBridge(
                 EthereumBridgeError::Erc20TokenCapsExceeded(wnam_addr),
             ));
@@ -247,6 +790,10 @@ async fn validate_bridge_pool_tx(
     }
 
     // validate balances
+    //
+    // NB: NUT transfers debit the sender's NUTs, rather than
+    // its wrapped ERC20 tokens
+    let token_addr = transfer.token_address();
     let maybe_balance_error = if token_addr == transfer.gas_fee.token {
         let expected_debit = transfer.transfer.amount + transfer.gas_fee.amount;
         let balance: Amount = query_storage_value(
@@ -298,6 +845,27 @@ async fn validate_bridge_pool_tx(
         err_tokens.or(err_fees)
     };
     if let Some((token, amount)) = maybe_balance_error {
+        if token == erc20_token_address(&transfer.transfer.asset) {
+            // the sender may be attempting to transfer tokens it
+            // holds as NUTs, received from an invalid transfer
+            // to Namada
+            let nut_balance: Amount = query_storage_value(
+                context.client(),
+                &balance_key(
+                    &erc20_nut_address(&transfer.transfer.asset),
+                    &transfer.transfer.sender,
+                ),
+            )
+            .await
+            .unwrap_or_default();
+            if nut_balance >= transfer.transfer.amount {
+                return Err(Error::EthereumBridge(
+                    EthereumBridgeError::TransferOfHeldNuts(
+                        transfer.transfer.asset,
+                    ),
+                ));
+            }
+        }
         return Err(Error::Tx(TxSubmitError::NegativeBalanceAfterTransfer(
             Box::new(transfer.transfer.sender),
             amount.to_string(),
@@ -308,1313 +876,9921 @@ async fn validate_bridge_pool_tx(
     Ok(transfer)
 }
 
+/// Format an amount input by the user, before it is validated.
+fn describe_input_amount(amount: &args::InputAmount) -> String {
+    match amount {
+        args::InputAmount::Validated(amount)
+        | args::InputAmount::Unvalidated(amount) => amount.to_string(),
+    }
+}
+
+/// Validate the amount of a Bridge pool transfer against the
+/// decimal places of the ERC20 token being transferred.
+///
+/// The decimal places must match the denomination of the wrapped
+/// token in Namada, if one exists.
+async fn validate_erc20_decimals(
+    context: &impl Namada,
+    force: bool,
+    asset: &EthAddress,
+    amount: args::InputAmount,
+    decimals: u8,
+) -> Result<args::InputAmount, Error> {
+    let token_addr = erc20_token_address(asset);
+    let denom = RPC
+        .vp()
+        .token()
+        .denomination(context.client(), &token_addr)
+        .await
+        .map_err(|e| Error::Query(QueryError::General(e.to_string())))?;
+    match denom {
+        Some(denom) if denom.0 != decimals && !force => {
+            return Err(Error::EthereumBridge(
+                EthereumBridgeError::Erc20DecimalsMismatch {
+                    asset: *asset,
+                    expected: denom.0,
+                    provided: decimals,
+                },
+            ));
+        }
+        _ => {}
+    }
+
+    let args::InputAmount::Unvalidated(input_amount) = amount else {
+        return Ok(amount);
+    };
+    let input_amount = input_amount.canonical();
+    if input_amount.denom().0 > decimals {
+        return if force {
+            Ok(amount)
+        } else {
+            Err(Error::EthereumBridge(
+                EthereumBridgeError::Erc20AmountPrecision {
+                    asset: *asset,
+                    amount: input_amount.to_string(),
+                    decimals,
+                },
+            ))
+        };
+    }
+    input_amount
+        .increase_precision(decimals.into())
+        .map(args::InputAmount::Validated)
+        .map_err(|e| Error::Encode(EncodingError::Conversion(e.to_string())))
+}
+
 /// A json serializable representation of the Ethereum
 /// bridge pool.
 #[derive(Serialize)]
 struct BridgePoolResponse<'pool> {
-    bridge_pool_contents: &'pool HashMap<String, PendingTransfer>,
+    /// Transfers in the Bridge pool, sorted from oldest to newest.
+    #[serde(serialize_with = "serialize_pool_entries")]
+    bridge_pool_contents: Vec<(&'pool str, BridgePoolResponseEntry<'pool>)>,
 }
 
-/// Query the contents of the Ethereum bridge pool.
-/// Prints out a json payload.
-pub async fn query_bridge_pool(
-    client: &(impl Client + Sync),
-    io: &impl Io,
-) -> Result<HashMap<String, PendingTransfer>, Error> {
-    let response: Vec<PendingTransfer> = RPC
-        .shell()
-        .eth_bridge()
-        .read_ethereum_bridge_pool(client)
-        .await
-        .map_err(|e| {
-            Error::EthereumBridge(EthereumBridgeError::ReadBridgePool(
-                e.to_string(),
-            ))
-        })?;
-    let pool_contents: HashMap<String, PendingTransfer> = response
-        .into_iter()
-        .map(|transfer| (transfer.keccak256().to_string(), transfer))
-        .collect();
-    if pool_contents.is_empty() {
-        display_line!(io, "Bridge pool is empty.");
-        return Ok(pool_contents);
-    }
-    let contents = BridgePoolResponse {
-        bridge_pool_contents: &pool_contents,
-    };
-    display_line!(
-        io,
-        "{}",
-        serde_json::to_string_pretty(&contents)
-            .map_err(|e| EncodingError::Serde(e.to_string()))?
-    );
-    Ok(pool_contents)
+/// A json serializable representation of a transfer in the
+/// Ethereum bridge pool, along with its human readable amounts.
+#[derive(Serialize)]
+struct BridgePoolResponseEntry<'pool> {
+    #[serde(flatten)]
+    pending: &'pool PendingTransfer,
+    /// The amount of tokens being transferred, formatted with
+    /// the token's decimal places.
+    denominated_amount: DenominatedAmount,
+    /// The amount of gas fees paid by the transfer, formatted
+    /// with the fee token's decimal places.
+    denominated_gas_fee: DenominatedAmount,
+    /// The block height at which the transfer was added to
+    /// the Bridge pool, if it is still present in it.
+    added_height: Option<BlockHeight>,
+    /// The number of blocks the transfer has been sitting in
+    /// the Bridge pool for.
+    age: Option<u64>,
+    /// Whether the transfer has been in the Bridge pool for
+    /// over half of the time it is allowed to remain in it,
+    /// before expiring.
+    stale: bool,
+    /// The key events of the lifecycle of the transfer, if
+    /// they were requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lifecycle: Option<TransferLifecycle>,
 }
 
-/// Query the contents of the Ethereum bridge pool that
-/// is covered by the latest signed root.
-/// Prints out a json payload.
-pub async fn query_signed_bridge_pool(
-    client: &(impl Client + Sync),
-    io: &impl Io,
-) -> Result<HashMap<String, PendingTransfer>, Error> {
-    let response: Vec<PendingTransfer> = RPC
-        .shell()
-        .eth_bridge()
-        .read_signed_ethereum_bridge_pool(client)
-        .await
-        .map_err(|e| {
-            Error::EthereumBridge(EthereumBridgeError::ReadSignedBridgePool(
-                e.to_string(),
-            ))
-        })?;
-    let pool_contents: HashMap<String, PendingTransfer> = response
-        .into_iter()
-        .map(|transfer| (transfer.keccak256().to_string(), transfer))
-        .collect();
-    if pool_contents.is_empty() {
-        display_line!(io, "Bridge pool is empty.");
-        return Ok(pool_contents);
+/// The key events of the lifecycle of a transfer in the
+/// Ethereum bridge pool.
+#[derive(Serialize)]
+struct TransferLifecycle {
+    /// The time of the block at which the transfer was added
+    /// to the Bridge pool.
+    added_time: Option<DateTimeUtc>,
+    /// The block height of the first signed Bridge pool root
+    /// covering the transfer, if any.
+    covering_root_height: Option<BlockHeight>,
+    /// The time of the block of the first signed Bridge pool
+    /// root covering the transfer, if any.
+    covering_root_time: Option<DateTimeUtc>,
+    /// The block height at which a validator first saw the
+    /// transfer being relayed to Ethereum, if any.
+    event_seen_height: Option<BlockHeight>,
+    /// The time of the block at which a validator first saw
+    /// the transfer being relayed to Ethereum, if any.
+    event_seen_time: Option<DateTimeUtc>,
+    /// The fraction of the voting power that has seen the
+    /// transfer being relayed to Ethereum, if any.
+    seen_voting_power: Option<FractionalVotingPower>,
+}
+
+/// Serialize the entries of a [`BridgePoolResponse`] as a json
+/// object, preserving their order.
+fn serialize_pool_entries<S>(
+    entries: &[(&str, BridgePoolResponseEntry<'_>)],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_map(entries.iter().map(|(hash, entry)| (hash, entry)))
+}
+
+impl<'pool> BridgePoolResponse<'pool> {
+    /// Build a [`BridgePoolResponse`], denominating the amounts
+    /// of each transfer in the Bridge pool, and annotating them
+    /// with their age.
+    async fn new(
+        client: &(impl Client + Sync),
+        pool_contents: &'pool HashMap<String, PendingTransfer>,
+        heights: &BridgePoolTransferHeights,
+    ) -> Result<BridgePoolResponse<'pool>, Error> {
+        let denoms = query_denominations(
+            client,
+            pool_contents.values().flat_map(|pending| {
+                [pending.token_address(), pending.gas_fee.token.clone()]
+            }),
+        )
+        .await?;
+        let mut bridge_pool_contents: Vec<_> = pool_contents
+            .iter()
+            .map(|(hash, pending)| {
+                let added_height =
+                    heights.heights.get(&pending.keccak256()).copied();
+                let age = added_height.map(|height| {
+                    heights.queried_height.0.saturating_sub(height.0)
+                });
+                let entry = BridgePoolResponseEntry {
+                    pending,
+                    denominated_amount: DenominatedAmount::new(
+                        pending.transfer.amount,
+                        denoms[&pending.token_address()],
+                    ),
+                    denominated_gas_fee: DenominatedAmount::new(
+                        pending.gas_fee.amount,
+                        denoms[&pending.gas_fee.token],
+                    ),
+                    added_height,
+                    age,
+                    stale: age
+                        .map(|age| age >= heights.expiry_offset / 2)
+                        .unwrap_or(false),
+                    lifecycle: None,
+                };
+                (hash.as_str(), entry)
+            })
+            .collect();
+        // transfers whose insertion height is unknown go last
+        bridge_pool_contents.sort_by(|(hash_a, a), (hash_b, b)| {
+            let height_a = a.added_height.unwrap_or(BlockHeight(u64::MAX));
+            let height_b = b.added_height.unwrap_or(BlockHeight(u64::MAX));
+            height_a.cmp(&height_b).then_with(|| hash_a.cmp(hash_b))
+        });
+        Ok(Self {
+            bridge_pool_contents,
+        })
     }
-    let contents = BridgePoolResponse {
-        bridge_pool_contents: &pool_contents,
-    };
-    display_line!(
-        io,
-        "{}",
-        serde_json::to_string_pretty(&contents)
-            .map_err(|e| EncodingError::Serde(e.to_string()))?
-    );
-    Ok(pool_contents)
 }
 
-/// Iterates over all ethereum events
-/// and returns the amount of voting power
-/// backing each `TransferToEthereum` event.
+impl BridgePoolResponse<'_> {
+    /// Annotate each transfer in the [`BridgePoolResponse`] with
+    /// the key events of its lifecycle.
+    ///
+    /// This joins the contents of the Bridge pool with its latest
+    /// signed root, and the relay progress of the transfers.
+    async fn with_lifecycle(
+        mut self,
+        client: &(impl Client + Sync),
+        router: &EthBridge,
+    ) -> Result<Self, Error> {
+        let signed_pool: HashSet<_> = router
+            .read_signed_ethereum_bridge_pool(client)
+            .await
+            .map_err(|e| {
+                Error::EthereumBridge(
+                    EthereumBridgeError::ReadSignedBridgePool(e.to_string()),
+                )
+            })?
+            .into_iter()
+            .map(|pending| pending.keccak256())
+            .collect();
+        let progress = query_transfer_to_ethereum_progress_via(
+            client,
+            router,
+            TRANSFER_TO_ETHEREUM_PROGRESS_PAGE_SIZE,
+        )
+        .await?;
+        let seen_heights = router
+            .transfer_to_ethereum_seen_heights(client)
+            .await
+            .map_err(|e| Error::Query(QueryError::General(e.to_string())))?;
+
+        let mut block_times = HashMap::new();
+        let mut covering_roots = HashMap::new();
+        for (_, entry) in self.bridge_pool_contents.iter_mut() {
+            let hash = entry.pending.keccak256();
+            let added_time = match entry.added_height {
+                Some(height) => {
+                    query_block_time(client, &mut block_times, height).await?
+                }
+                None => None,
+            };
+            let covering_root_height = match entry.added_height {
+                Some(added_height) if signed_pool.contains(&hash) => {
+                    query_first_covering_root(
+                        client,
+                        &mut covering_roots,
+                        added_height,
+                    )
+                    .await?
+                }
+                _ => None,
+            };
+            let covering_root_time = match covering_root_height {
+                Some(height) => {
+                    query_block_time(client, &mut block_times, height).await?
+                }
+                None => None,
+            };
+            let event_seen_height = seen_heights.get(&hash).copied();
+            let event_seen_time = match event_seen_height {
+                Some(height) => {
+                    query_block_time(client, &mut block_times, height).await?
+                }
+                None => None,
+            };
+            entry.lifecycle = Some(TransferLifecycle {
+                added_time,
+                covering_root_height,
+                covering_root_time,
+                event_seen_height,
+                event_seen_time,
+                seen_voting_power: progress.get(entry.pending).copied(),
+            });
+        }
+        Ok(self)
+    }
+}
+
+/// Query the block height of the first signed Bridge pool root
+/// covering the transfers added to the Bridge pool at
+/// `added_height`, caching it in `covering_roots`.
 ///
-/// Prints a json payload.
-pub async fn query_relay_progress(
+/// Returns `None` if not even the latest signed root covers
+/// these transfers.
+async fn query_first_covering_root(
     client: &(impl Client + Sync),
-    io: &impl Io,
-) -> Result<(), Error> {
-    let resp = RPC
+    covering_roots: &mut HashMap<BlockHeight, BlockHeight>,
+    added_height: BlockHeight,
+) -> Result<Option<BlockHeight>, Error> {
+    if let Some(root_height) = covering_roots.get(&added_height) {
+        return Ok(Some(*root_height));
+    }
+    // NB: transfers covered by some signed root are
+    // covered by the latest one
+    let mut root_height = match query_signed_root(client).await? {
+        Some((_, root_height)) if root_height >= added_height => root_height,
+        _ => return Ok(None),
+    };
+    let last_height = RPC
         .shell()
-        .eth_bridge()
-        .transfer_to_ethereum_progress(client)
+        .last_block(client)
         .await
-        .map_err(|e| {
-            Error::EthereumBridge(EthereumBridgeError::TransferToEthProgress(
-                e.to_string(),
+        .map_err(|e| Error::Query(QueryError::General(e.to_string())))?
+        .map(|block| block.height)
+        .ok_or_else(|| {
+            Error::Query(QueryError::General(
+                "No block has been committed yet".into(),
             ))
         })?;
-    display_line!(
-        io,
-        "{}",
-        serde_json::to_string_pretty(&resp)
-            .map_err(|e| EncodingError::Serde(e.to_string()))?
-    );
-    Ok(())
+
+    // binary search the first height at which a covering root is
+    // in storage. NB: a root is signed after the block of its
+    // height has been committed, so no root covering the transfers
+    // can be in storage as of `added_height`
+    let mut lower = added_height.next_height();
+    let mut upper = last_height;
+    while lower < upper {
+        let height = BlockHeight(lower.0 + (upper.0 - lower.0) / 2);
+        match query_signed_root_at(client, Some(height)).await? {
+            Some((_, found)) if found >= added_height => {
+                root_height = found;
+                upper = height;
+            }
+            _ => lower = height.next_height(),
+        }
+    }
+    covering_roots.insert(added_height, root_height);
+    Ok(Some(root_height))
 }
 
-/// Internal method to construct a proof that a set of transfers are in the
-/// bridge pool.
-async fn construct_bridge_pool_proof(
+/// Query the latest signed Bridge pool root, along with the
+/// block height at which it was signed, if any root has been
+/// signed yet.
+async fn query_signed_root(
     client: &(impl Client + Sync),
-    io: &(impl Io + MaybeSync),
-    args: GenBridgePoolProofReq<'_, '_>,
-) -> Result<GenBridgePoolProofRsp, Error> {
-    let in_progress = RPC
+) -> Result<Option<(BridgePoolRootProof, BlockHeight)>, Error> {
+    query_signed_root_at(client, None).await
+}
+
+/// Query the latest Bridge pool root signed as of the block at
+/// `height`, or as of the last committed block if unset, along
+/// with the block height of the root.
+async fn query_signed_root_at(
+    client: &(impl Client + Sync),
+    height: Option<BlockHeight>,
+) -> Result<Option<(BridgePoolRootProof, BlockHeight)>, Error> {
+    let data = RPC
         .shell()
-        .eth_bridge()
-        .transfer_to_ethereum_progress(client)
+        .storage_value(client, None, height, false, &get_signed_root_key())
         .await
-        .map_err(|e| {
-            Error::EthereumBridge(EthereumBridgeError::TransferToEthProgress(
-                e.to_string(),
-            ))
-        })?;
-
-    let warnings: Vec<_> = in_progress
-        .into_iter()
-        .filter_map(|(ref transfer, voting_power)| {
-            if voting_power >= FractionalVotingPower::ONE_THIRD {
-                let hash = transfer.keccak256();
-                args.transfers.contains(&hash).then_some(hash)
-            } else {
-                None
-            }
+        .map_err(|err| {
+            Error::Query(QueryError::General(format!(
+                "Failed to query Bridge pool proof: {err}"
+            )))
+        })?
+        .data;
+    if data.is_empty() {
+        return Ok(None);
+    }
+    <(BridgePoolRootProof, BlockHeight)>::try_from_slice(&data)
+        .map(Some)
+        .map_err(|err| {
+            Error::Encode(EncodingError::Decoding(format!(
+                "Failed to decode Bridge pool proof: {err}"
+            )))
         })
-        .collect();
+}
 
-    if !warnings.is_empty() {
-        let warning = "Warning".on_yellow();
-        let warning = warning.bold();
-        let warning = warning.blink();
-        display_line!(
-            io,
-            "{warning}: The following hashes correspond to transfers that \
-             have surpassed the security threshold in Namada, therefore have \
-             likely been relayed to Ethereum, but do not yet have a quorum of \
-             validator signatures behind them in Namada; thus they are still \
-             in the Bridge pool:\n{warnings:?}",
-        );
-        display!(io, "\nDo you wish to proceed? (y/n): ");
-        io.flush();
-        loop {
-            let resp = io.read().await.map_err(|e| {
-                Error::Other(echo_error!(
-                    io,
-                    "Encountered error reading from STDIN: {e:?}"
-                ))
-            })?;
-            match resp.trim() {
-                "y" => break,
-                "n" => {
-                    return Err(Error::Other(
-                        "Aborted generating Bridge pool proof".into(),
-                    ));
-                }
-                _ => {
-                    display!(io, "Expected 'y' or 'n'. Please try again: ");
-                    io.flush();
-                }
-            }
-        }
+/// Query the time of the block at the given height, caching
+/// it in `block_times`.
+async fn query_block_time(
+    client: &(impl Client + Sync),
+    block_times: &mut HashMap<BlockHeight, Option<DateTimeUtc>>,
+    height: BlockHeight,
+) -> Result<Option<DateTimeUtc>, Error> {
+    if let Some(time) = block_times.get(&height) {
+        return Ok(*time);
     }
+    let time: Option<DateTimeUtc> = client
+        .block(height.0 as u32)
+        .await
+        .map_err(|e| Error::Query(QueryError::General(e.to_string())))?
+        .block
+        .header
+        .time
+        .try_into()
+        .ok();
+    block_times.insert(height, time);
+    Ok(time)
+}
 
-    let data = args.serialize_to_vec();
-    let response = RPC
-        .shell()
-        .eth_bridge()
-        .generate_bridge_pool_proof(client, Some(data), None, false)
+/// Query the block heights at which the transfers in the
+/// Bridge pool were added to it.
+async fn query_bridge_pool_transfer_heights(
+    client: &(impl Client + Sync),
+    router: &EthBridge,
+) -> Result<BridgePoolTransferHeights, Error> {
+    router
+        .read_bridge_pool_transfer_heights(client)
         .await
         .map_err(|e| {
-            edisplay_line!(
-                io,
-                "Encountered error constructing proof:\n{:?}",
-                e
-            );
-            Error::EthereumBridge(EthereumBridgeError::GenBridgePoolProof(
+            Error::EthereumBridge(EthereumBridgeError::ReadBridgePool(
                 e.to_string(),
             ))
-        })?;
-
-    Ok(response.data)
+        })
 }
 
-/// A response from construction a bridge pool proof.
-#[derive(Serialize)]
-struct BridgePoolProofResponse {
-    hashes: Vec<KeccakHash>,
-    relayer_address: Address,
-    total_fees: HashMap<Address, Amount>,
-    abi_encoded_args: Vec<u8>,
+/// Query the denominations of the given tokens.
+///
+/// Tokens without a denomination in storage default to
+/// zero decimal places. Failing to query a denomination
+/// is an error, rather than a reason to misprint amounts.
+async fn query_denominations<C, I>(
+    client: &C,
+    tokens: I,
+) -> Result<HashMap<Address, Denomination>, Error>
+where
+    C: Client + Sync,
+    I: IntoIterator<Item = Address>,
+{
+    let tokens: HashSet<_> = tokens.into_iter().collect();
+    let mut denoms = HashMap::with_capacity(tokens.len());
+    for token in tokens {
+        let denom = RPC
+            .vp()
+            .token()
+            .denomination(client, &token)
+            .await
+            .map_err(|e| {
+                Error::Query(QueryError::General(format!(
+                    "the denomination of token {token}: {e}"
+                )))
+            })?
+            .unwrap_or_else(|| 0u8.into());
+        denoms.insert(token, denom);
+    }
+    Ok(denoms)
 }
 
-/// Construct a merkle proof of a batch of transfers in
-/// the bridge pool and return it to the user (as opposed
-/// to relaying it to ethereum).
-pub async fn construct_proof(
-    client: &(impl Client + Sync),
-    io: &(impl Io + MaybeSync),
-    args: args::BridgePoolProof,
+/// Write the json payload of a Bridge pool query to `output`,
+/// or print it out if no path is given.
+///
+/// Files are written atomically, by renaming a temporary file
+/// to `output` once the payload has been written, such that
+/// readers never observe a partially written payload. Missing
+/// parent directories are created.
+fn write_query_output<T: Serialize>(
+    io: &impl Io,
+    output: Option<&Path>,
+    payload: &T,
 ) -> Result<(), Error> {
-    let GenBridgePoolProofRsp {
-        abi_encoded_args,
-        appendices,
-    } = construct_bridge_pool_proof(
-        client,
-        io,
-        GenBridgePoolProofReq {
-            transfers: args.transfers.as_slice().into(),
-            relayer: Cow::Borrowed(&args.relayer),
-            with_appendix: true,
-        },
-    )
-    .await?;
-    let resp = BridgePoolProofResponse {
-        hashes: args.transfers,
-        relayer_address: args.relayer,
-        total_fees: appendices
-            .map(|appendices| {
-                appendices.into_iter().try_fold(
-                    HashMap::new(),
-                    |mut total_fees, app| {
-                        let GasFee { token, amount, .. } =
-                            app.gas_fee.into_owned();
-                        let fees = total_fees
-                            .entry(token)
-                            .or_insert_with(Amount::zero);
-                        fees.receive(&amount)
-                            .map_err(|e| Error::Other(e.to_string()))?;
-                        Ok::<_, Error>(total_fees)
-                    },
-                )
-            })
-            .transpose()?
-            .unwrap_or_default(),
-        abi_encoded_args,
+    let Some(path) = output else {
+        display_line!(
+            io,
+            "{}",
+            serde_json::to_string_pretty(payload)
+                .map_err(|e| EncodingError::Serde(e.to_string()))?
+        );
+        return Ok(());
     };
-    display_line!(
-        io,
-        "{}",
-        serde_json::to_string_pretty(&resp)
-            .map_err(|e| EncodingError::Serde(e.to_string()))?
-    );
-    Ok(())
+    let contents = serde_json::to_vec_pretty(payload)
+        .map_err(|e| Error::Encode(EncodingError::Serde(e.to_string())))?;
+    let write_err = |e: std::io::Error| {
+        Error::EthereumBridge(EthereumBridgeError::QueryOutput(format!(
+            "{}: {e}",
+            path.display()
+        )))
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(write_err)?;
+    }
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    std::fs::write(&tmp_path, contents).map_err(write_err)?;
+    std::fs::rename(&tmp_path, path).map_err(write_err)
 }
 
-/// Relay a validator set update, signed off for a given epoch.
-pub async fn relay_bridge_pool_proof<E>(
-    eth_client: Arc<E>,
-    client: &(impl Client + Sync),
-    io: &(impl Io + MaybeSync),
-    args: args::RelayBridgePoolProof,
-) -> Result<(), Error>
+/// Get the router of the Ethereum bridge queries, at the given
+/// path of the RPC of a ledger node.
+///
+/// If no path is given, the default `shell/eth_bridge` path of
+/// [`RPC`] is used. Overriding it allows querying nodes which
+/// expose the Ethereum bridge queries at a different route.
+///
+/// Only the queries of the Ethereum bridge router are affected.
+/// Storage and block queries, such as those of the latest signed
+/// Bridge pool root or of block times, always go through the
+/// default `shell` path.
+pub fn eth_bridge_router(path: Option<&str>) -> EthBridge {
+    match path {
+        Some(path) => EthBridge::sub(format!("/{}", path.trim_matches('/'))),
+        None => RPC.shell().eth_bridge(),
+    }
+}
+
+/// Check whether the Ethereum bridge is active on the chain, i.e.
+/// whether it has been enabled as of the current epoch.
+pub async fn bridge_active<C>(client: &C) -> Result<bool, Error>
 where
-    E: Middleware,
-    E::Error: std::fmt::Debug + std::fmt::Display,
+    C: Client + Sync,
 {
-    let _signal_receiver = args.safe_mode.then(install_shutdown_signal);
+    let (status, _) =
+        query_storage_value_bytes(client, &active_key(), None, false).await?;
+    // NB: the bridge is disabled if its status was never written
+    let Some(status) = status else {
+        return Ok(false);
+    };
+    let status = EthBridgeStatus::try_from_slice(&status)
+        .map_err(|e| Error::Encode(EncodingError::Decoding(e.to_string())))?;
+    Ok(match status {
+        EthBridgeStatus::Disabled => false,
+        EthBridgeStatus::Enabled(EthBridgeEnabled::AtGenesis) => true,
+        EthBridgeStatus::Enabled(EthBridgeEnabled::AtEpoch(enabled_epoch)) => {
+            query_epoch(client).await? >= enabled_epoch
+        }
+    })
+}
 
-    if args.sync {
-        block_on_eth_sync(
-            &*eth_client,
-            io,
-            BlockOnEthSync {
-                deadline: Instant::now() + Duration::from_secs(60),
-                delta_sleep: Duration::from_secs(1),
-            },
-        )
-        .await?;
-    } else {
-        eth_sync_or_exit(&*eth_client, io).await?;
+/// Halt if the Ethereum bridge is not active on the chain, rather
+/// than reporting empty or confusing query results.
+async fn ensure_bridge_active(
+    client: &(impl Client + Sync),
+    io: &impl Io,
+) -> Result<(), Error> {
+    if bridge_active(client).await? {
+        return Ok(());
     }
+    edisplay_line!(io, "Ethereum bridge is not active on this chain.");
+    Err(Error::EthereumBridge(EthereumBridgeError::BridgeInactive))
+}
 
-    let GenBridgePoolProofRsp {
-        abi_encoded_args, ..
-    } = construct_bridge_pool_proof(
-        client,
+/// The default maximum number of transfers processed by queries
+/// of the contents of the Ethereum bridge pool.
+pub const DEFAULT_MAX_QUERIED_TRANSFERS: usize = 100_000;
+
+/// Fail a query of the contents of the Ethereum bridge pool that
+/// returned more than `max_transfers` transfers, before they are
+/// processed any further.
+fn ensure_transfer_count(
+    io: &impl Io,
+    count: usize,
+    max_transfers: usize,
+) -> Result<(), Error> {
+    if count <= max_transfers {
+        return Ok(());
+    }
+    edisplay_line!(
         io,
-        GenBridgePoolProofReq {
-            transfers: Cow::Owned(args.transfers),
-            relayer: Cow::Owned(args.relayer),
-            with_appendix: false,
+        "The Bridge pool holds {count} transfers, more than the maximum of \
+         {max_transfers} transfers that may be queried. Raise the maximum to \
+         query it anyway."
+    );
+    Err(Error::EthereumBridge(
+        EthereumBridgeError::TooManyTransfers {
+            count,
+            max: max_transfers,
         },
-    )
-    .await?;
-    let bridge =
-        match RPC.shell().eth_bridge().read_bridge_contract(client).await {
-            Ok(address) => Bridge::new(address.address, eth_client),
-            Err(err_msg) => {
-                let error = "Error".on_red();
-                let error = error.bold();
-                let error = error.blink();
-                display_line!(
-                    io,
-                    "Unable to decode the generated proof: {:?}",
-                    error
-                );
-                return Err(Error::EthereumBridge(
-                    EthereumBridgeError::RetrieveContract(err_msg.to_string()),
-                ));
-            }
-        };
-
-    let (validator_set, signatures, bp_proof): TransferToErcArgs =
-        AbiDecode::decode(&abi_encoded_args).map_err(|error| {
-            EncodingError::Decoding(echo_error!(
-                io,
-                "Unable to decode the generated proof: {:?}",
-                error
-            ))
-        })?;
+    ))
+}
 
-    // NOTE: this operation costs no gas on Ethereum
-    let contract_nonce = bridge
-        .transfer_to_erc_20_nonce()
-        .call()
+/// Read the transfers returned by the Bridge pool query at
+/// `path`, failing with `read_error` if the query itself fails.
+///
+/// The number of transfers is read from the length prefix of
+/// the Borsh encoded response, such that responses holding
+/// more than `max_transfers` transfers are rejected before
+/// they are decoded.
+async fn read_transfers_checked(
+    client: &(impl Client + Sync),
+    io: &impl Io,
+    path: String,
+    max_transfers: usize,
+    read_error: fn(String) -> EthereumBridgeError,
+) -> Result<Vec<PendingTransfer>, Error> {
+    let response = client
+        .request(path, None, None, false)
         .await
-        .map_err(|e| {
-            Error::EthereumBridge(EthereumBridgeError::ContractCall(
-                e.to_string(),
+        .map_err(|e| Error::EthereumBridge(read_error(e.to_string())))?;
+    let count = response
+        .data
+        .get(..4)
+        .and_then(|prefix| <[u8; 4]>::try_from(prefix).ok())
+        .map(|prefix| u32::from_le_bytes(prefix) as usize)
+        .ok_or_else(|| {
+            Error::EthereumBridge(read_error(
+                "The response is too short to hold a list of transfers"
+                    .to_string(),
             ))
         })?;
+    ensure_transfer_count(io, count, max_transfers)?;
+    Vec::<PendingTransfer>::try_from_slice(&response.data)
+        .map_err(|e| Error::EthereumBridge(read_error(e.to_string())))
+}
 
-    match bp_proof.batch_nonce.cmp(&contract_nonce) {
-        Ordering::Equal => {}
-        Ordering::Less => {
-            let error = "Error".on_red();
-            let error = error.bold();
-            let error = error.blink();
-            display_line!(
-                io,
-                "{error}: The Bridge pool nonce in the smart contract is \
-                 {contract_nonce}, while the nonce in Namada is still {}. A \
-                 relay of the former one has already happened, but a proof \
-                 has yet to be crafted in Namada.",
-                bp_proof.batch_nonce
-            );
-            return Err(Error::EthereumBridge(
-                EthereumBridgeError::InvalidBpNonce,
-            ));
-        }
-        Ordering::Greater => {
-            let error = "Error".on_red();
-            let error = error.bold();
-            let error = error.blink();
-            display_line!(
-                io,
-                "{error}: The Bridge pool nonce in the smart contract is \
-                 {contract_nonce}, while the nonce in Namada is still {}. \
-                 Somehow, Namada's nonce is ahead of the contract's nonce!",
-                bp_proof.batch_nonce
-            );
-            return Err(Error::EthereumBridge(
-                EthereumBridgeError::InvalidBpNonce,
-            ));
-        }
-    }
+/// Query the contents of the Ethereum bridge pool through
+/// the given `router`.
+/// Prints out a json payload, with transfers sorted
+/// from oldest to newest, or writes it to `output`.
+///
+/// Pools holding more than [`DEFAULT_MAX_QUERIED_TRANSFERS`]
+/// transfers are not processed. Use
+/// [`query_bridge_pool_with_args`] to change the maximum.
+pub async fn query_bridge_pool(
+    client: &(impl Client + Sync),
+    io: &impl Io,
+    router: &EthBridge,
+    output: Option<&Path>,
+) -> Result<HashMap<String, PendingTransfer>, Error> {
+    query_bridge_pool_contents(
+        client,
+        io,
+        router,
+        false,
+        output,
+        DEFAULT_MAX_QUERIED_TRANSFERS,
+    )
+    .await
+}
 
-    let mut relay_op =
-        bridge.transfer_to_erc(validator_set, signatures, bp_proof);
-    if let Some(gas) = args.gas {
-        relay_op.tx.set_gas(gas);
-    }
-    if let Some(gas_price) = args.gas_price {
-        relay_op.tx.set_gas_price(gas_price);
+/// Query the contents of the Ethereum bridge pool through the
+/// given `router`, annotating each transfer with the block
+/// heights and times of the key events of its lifecycle.
+/// Prints out a json payload, with
+/// transfers sorted from oldest to newest, or writes it to
+/// `output`.
+///
+/// Compared to [`query_bridge_pool`], this requires additional
+/// queries to the ledger node.
+pub async fn query_bridge_pool_with_lifecycle(
+    client: &(impl Client + Sync),
+    io: &impl Io,
+    router: &EthBridge,
+    output: Option<&Path>,
+) -> Result<HashMap<String, PendingTransfer>, Error> {
+    query_bridge_pool_contents(
+        client,
+        io,
+        router,
+        true,
+        output,
+        DEFAULT_MAX_QUERIED_TRANSFERS,
+    )
+    .await
+}
+
+/// Query the contents of the Ethereum bridge pool, as
+/// configured by `args`.
+///
+/// Pools holding more than `args.max_transfers` transfers
+/// are not processed.
+pub async fn query_bridge_pool_with_args(
+    client: &(impl Client + Sync),
+    io: &impl Io,
+    args: &args::QueryBridgePool,
+) -> Result<HashMap<String, PendingTransfer>, Error> {
+    let router = eth_bridge_router(args.rpc_path.as_deref());
+    query_bridge_pool_contents(
+        client,
+        io,
+        &router,
+        args.lifecycle,
+        args.output.as_deref(),
+        args.max_transfers,
+    )
+    .await
+}
+
+/// Query the contents of the Ethereum bridge pool, optionally
+/// annotated with the lifecycle of each transfer.
+async fn query_bridge_pool_contents(
+    client: &(impl Client + Sync),
+    io: &impl Io,
+    router: &EthBridge,
+    lifecycle: bool,
+    output: Option<&Path>,
+    max_transfers: usize,
+) -> Result<HashMap<String, PendingTransfer>, Error> {
+    ensure_bridge_active(client, io).await?;
+    let response = read_transfers_checked(
+        client,
+        io,
+        router.read_ethereum_bridge_pool_path(),
+        max_transfers,
+        EthereumBridgeError::ReadBridgePool,
+    )
+    .await?;
+    let pool_contents: HashMap<String, PendingTransfer> = response
+        .into_iter()
+        .map(|transfer| (transfer.keccak256().to_hex(), transfer))
+        .collect();
+    if pool_contents.is_empty() && output.is_none() {
+        display_line!(io, "Bridge pool is empty.");
+        return Ok(pool_contents);
     }
-    if let Some(eth_addr) = args.eth_addr {
-        relay_op.tx.set_from(eth_addr.into());
+    let heights = query_bridge_pool_transfer_heights(client, router).await?;
+    let mut contents =
+        BridgePoolResponse::new(client, &pool_contents, &heights).await?;
+    if lifecycle {
+        contents = contents.with_lifecycle(client, router).await?;
     }
+    write_query_output(io, output, &contents)?;
+    Ok(pool_contents)
+}
 
-    let pending_tx = relay_op.send().await.map_err(|e| {
-        Error::EthereumBridge(EthereumBridgeError::ContractCall(e.to_string()))
-    })?;
-    let transf_result = pending_tx
-        .confirmations(args.confirmations as usize)
-        .await
-        .map_err(|e| {
-            Error::EthereumBridge(EthereumBridgeError::Rpc(e.to_string()))
-        })?;
+/// Query the contents of the Ethereum bridge pool that
+/// is covered by the latest signed root, through the
+/// given `router`.
+/// Prints out a json payload, with transfers sorted
+/// from oldest to newest, or writes it to `output`.
+///
+/// Pools holding more than [`DEFAULT_MAX_QUERIED_TRANSFERS`]
+/// signed transfers are not processed. Use
+/// [`query_signed_bridge_pool_with_args`] to change the maximum.
+pub async fn query_signed_bridge_pool(
+    client: &(impl Client + Sync),
+    io: &impl Io,
+    router: &EthBridge,
+    output: Option<&Path>,
+) -> Result<HashMap<String, PendingTransfer>, Error> {
+    query_signed_bridge_pool_contents(
+        client,
+        io,
+        router,
+        output,
+        DEFAULT_MAX_QUERIED_TRANSFERS,
+    )
+    .await
+}
 
-    display_line!(io, "{transf_result:?}");
-    Ok(())
+/// Query the contents of the Ethereum bridge pool that
+/// is covered by the latest signed root, as configured by
+/// `args`.
+///
+/// Pools holding more than `args.max_transfers` signed
+/// transfers are not processed.
+pub async fn query_signed_bridge_pool_with_args(
+    client: &(impl Client + Sync),
+    io: &impl Io,
+    args: &args::QuerySignedBridgePool,
+) -> Result<HashMap<String, PendingTransfer>, Error> {
+    let router = eth_bridge_router(args.rpc_path.as_deref());
+    query_signed_bridge_pool_contents(
+        client,
+        io,
+        &router,
+        args.output.as_deref(),
+        args.max_transfers,
+    )
+    .await
 }
 
-/// Query the status of a set of transfers to Ethreum, indexed
-/// by their keccak hash.
+/// Query the contents of the Ethereum bridge pool that
+/// is covered by the latest signed root, processing at
+/// most `max_transfers` transfers.
+async fn query_signed_bridge_pool_contents(
+    client: &(impl Client + Sync),
+    io: &impl Io,
+    router: &EthBridge,
+    output: Option<&Path>,
+    max_transfers: usize,
+) -> Result<HashMap<String, PendingTransfer>, Error> {
+    ensure_bridge_active(client, io).await?;
+    let response = read_transfers_checked(
+        client,
+        io,
+        router.read_signed_ethereum_bridge_pool_path(),
+        max_transfers,
+        EthereumBridgeError::ReadSignedBridgePool,
+    )
+    .await?;
+    let pool_contents: HashMap<String, PendingTransfer> = response
+        .into_iter()
+        .map(|transfer| (transfer.keccak256().to_hex(), transfer))
+        .collect();
+    if pool_contents.is_empty() && output.is_none() {
+        display_line!(io, "Bridge pool is empty.");
+        return Ok(pool_contents);
+    }
+    let heights = query_bridge_pool_transfer_heights(client, router).await?;
+    let contents =
+        BridgePoolResponse::new(client, &pool_contents, &heights).await?;
+    write_query_output(io, output, &contents)?;
+    Ok(pool_contents)
+}
+
+/// A summary of the status of the Ethereum bridge pool.
+#[derive(Debug, Clone)]
+pub struct BridgePoolStatus {
+    /// The number of transfers in the Bridge pool.
+    pub pending_transfers: usize,
+    /// The number of transfers covered by the latest signed root.
+    pub signed_transfers: usize,
+    /// The number of transfers that have been seen being relayed
+    /// to Ethereum by some validators.
+    pub in_progress_transfers: usize,
+    /// The total fees paid by the transfers in the Bridge pool,
+    /// per fee token.
+    pub fee_revenue: HashMap<Address, Amount>,
+    /// The block height at which the latest root was signed.
+    pub signed_root_height: Option<BlockHeight>,
+    /// The nonce of the latest signed root.
+    pub signed_root_nonce: Option<EthUint>,
+    /// The nonce of the Bridge contract on Ethereum, if an
+    /// Ethereum client was provided.
+    pub contract_nonce: Option<U256>,
+}
+
+/// Summarize the status of the Ethereum bridge pool, combining its
+/// contents, its latest signed root and the relay progress of its
+/// transfers.
 ///
-/// Any unrecognized hashes (i.e. not pertaining to transfers
-/// in Namada's event log nor the Bridge pool) will be flagged
-/// as such. Unrecognized transfers could have been relayed to
-/// Ethereum, or could have expired from the Bridge pool. If
-/// these scenarios verify, it should be possible to retrieve
-/// the status of these transfers by querying CometBFT's block
-/// data.
-pub async fn query_eth_transfer_status<C, T>(
+/// If an Ethereum client is provided, the nonce of the Bridge
+/// contract is queried as well.
+pub async fn bridge_pool_status<C, E>(
     client: &C,
-    transfers: T,
-) -> Result<TransferToEthereumStatus, Error>
+    eth_client: Option<Arc<E>>,
+) -> Result<BridgePoolStatus, Error>
 where
     C: Client + Sync,
-    T: Into<HashSet<KeccakHash>>,
+    E: Middleware,
+    E::Error: std::fmt::Display,
 {
-    RPC.shell()
+    let pool = RPC
+        .shell()
         .eth_bridge()
-        .pending_eth_transfer_status(
-            client,
-            Some(transfers.into().serialize_to_vec()),
-            None,
-            false,
-        )
+        .read_ethereum_bridge_pool(client)
         .await
-        .map_err(|e| Error::Query(QueryError::General(e.to_string())))
-        .map(|result| result.data)
-}
+        .map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::ReadBridgePool(
+                e.to_string(),
+            ))
+        })?;
+    let signed_transfers = RPC
+        .shell()
+        .eth_bridge()
+        .read_signed_ethereum_bridge_pool(client)
+        .await
+        .map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::ReadSignedBridgePool(
+                e.to_string(),
+            ))
+        })?
+        .len();
+    let progress = query_transfer_to_ethereum_progress(
+        client,
+        TRANSFER_TO_ETHEREUM_PROGRESS_PAGE_SIZE,
+    )
+    .await?;
+    let in_progress_transfers = pool
+        .iter()
+        .filter(|pending| progress.contains_key(pending))
+        .count();
 
-mod recommendations {
-    use std::collections::BTreeSet;
+    let mut fee_revenue: HashMap<Address, Amount> = HashMap::new();
+    for pending in &pool {
+        fee_revenue
+            .entry(pending.gas_fee.token.clone())
+            .or_insert_with(Amount::zero)
+            .receive(&pending.gas_fee.amount)
+            .map_err(|e| Error::Other(e.to_string()))?;
+    }
 
-    use borsh::BorshDeserialize;
-    use namada_core::types::ethereum_events::Uint as EthUint;
-    use namada_core::types::storage::BlockHeight;
-    use namada_core::types::uint::{self, Uint, I256};
-    use namada_vote_ext::validator_set_update::{
-        EthAddrBook, VotingPowersMap, VotingPowersMapExt,
-    };
+    let (signed_root_nonce, signed_root_height) =
+        match query_signed_root(client).await? {
+            Some((root, height)) => (Some(root.data.1), Some(height)),
+            None => (None, None),
+        };
 
-    use super::*;
-    use crate::edisplay_line;
-    use crate::eth_bridge::storage::bridge_pool::{
-        get_nonce_key, get_signed_root_key,
+    let contract_nonce = match eth_client {
+        Some(eth_client) => {
+            let address = RPC
+                .shell()
+                .eth_bridge()
+                .read_bridge_contract(client)
+                .await
+                .map_err(|e| {
+                    Error::EthereumBridge(
+                        EthereumBridgeError::RetrieveContract(e.to_string()),
+                    )
+                })?;
+            let nonce = Bridge::new(address.address, eth_client)
+                .transfer_to_erc_20_nonce()
+                .call()
+                .await
+                .map_err(|e| {
+                    Error::EthereumBridge(EthereumBridgeError::ContractCall(
+                        e.to_string(),
+                    ))
+                })?;
+            Some(nonce)
+        }
+        None => None,
     };
-    use crate::eth_bridge::storage::proof::BridgePoolRootProof;
-    use crate::io::Io;
 
-    const fn unsigned_transfer_fee() -> Uint {
-        Uint::from_u64(37_500_u64)
-    }
+    Ok(BridgePoolStatus {
+        pending_transfers: pool.len(),
+        signed_transfers,
+        in_progress_transfers,
+        fee_revenue,
+        signed_root_height,
+        signed_root_nonce,
+        contract_nonce,
+    })
+}
 
-    const fn transfer_fee() -> I256 {
-        I256(unsigned_transfer_fee())
-    }
+/// The consistency of the latest signed Bridge pool root in Namada
+/// with the nonce of the Bridge contract on Ethereum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BridgeConsistency {
+    /// No Bridge pool root has been signed in Namada yet.
+    NoSignedRoot,
+    /// The Bridge contract expects the nonce of the latest signed
+    /// root, whose relay is possible, or pending.
+    RelayPossible,
+    /// The latest signed root has been relayed to the Bridge
+    /// contract, but Namada has yet to observe the relay.
+    AwaitingObservation,
+    /// The Bridge contract expects a nonce other than the one of
+    /// the latest signed root, or the one following it, which
+    /// should never happen.
+    Anomaly,
+}
 
-    const fn signature_fee() -> Uint {
-        Uint::from_u64(24_500)
+impl std::fmt::Display for BridgeConsistency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSignedRoot => write!(f, "no Bridge pool root signed yet"),
+            Self::RelayPossible => {
+                write!(f, "the latest signed root can be relayed")
+            }
+            Self::AwaitingObservation => write!(
+                f,
+                "the latest signed root was relayed, awaiting its \
+                 observation in Namada"
+            ),
+            Self::Anomaly => write!(f, "out of sync with Namada (anomaly)"),
+        }
     }
+}
 
-    const fn valset_fee() -> Uint {
-        Uint::from_u64(2000)
-    }
+/// A report on the consistency of the latest signed Bridge pool root
+/// in Namada with the Bridge contract on Ethereum.
+#[derive(Debug, Clone)]
+pub struct ConsistencyReport {
+    /// The nonce of the latest signed root, if any.
+    pub signed_root_nonce: Option<U256>,
+    /// The block height at which the latest root was signed, if any.
+    pub signed_root_height: Option<BlockHeight>,
+    /// The nonce of the next batch expected by the Bridge contract.
+    pub contract_nonce: U256,
+    /// The consistency of the Bridge contract with Namada.
+    pub consistency: BridgeConsistency,
+}
 
-    /// The different states while trying to solve
-    /// for a recommended batch of transfers.
-    struct AlgorithState {
-        /// We are scanning transfers that increase
-        /// net profits to the relayer. However, we
-        /// are not in the feasible region.
-        profitable: bool,
-        /// We are scanning solutions that satisfy the
-        /// requirements of the input.
-        feasible_region: bool,
+/// Compare the nonce of the latest signed Bridge pool root with the
+/// nonce of the next batch expected by the Bridge contract.
+///
+/// Namada keeps signing roots over the same nonce until it observes
+/// a relay, so the contract either expects the nonce of the signed
+/// root, or the one following it, once a relay has happened. This
+/// matches the nonces accepted by [`check_bp_nonce`].
+fn bridge_consistency(
+    signed_root_nonce: Option<U256>,
+    contract_nonce: U256,
+) -> BridgeConsistency {
+    let Some(signed_root_nonce) = signed_root_nonce else {
+        return BridgeConsistency::NoSignedRoot;
+    };
+    if contract_nonce == signed_root_nonce {
+        BridgeConsistency::RelayPossible
+    } else if Some(contract_nonce) == signed_root_nonce.checked_add(1.into()) {
+        BridgeConsistency::AwaitingObservation
+    } else {
+        BridgeConsistency::Anomaly
     }
+}
 
-    /// The algorithm exhibits two different remmondation strategies
-    /// depending on whether the user is will to accept a positive cost
-    /// for relaying.
-    #[derive(PartialEq)]
-    enum AlgorithmMode {
-        /// Only keep profitable transactions
-        Greedy,
-        /// Allow transactions which are not profitable
-        Generous,
-    }
+/// Check whether the latest signed Bridge pool root in Namada can be
+/// relayed to the Bridge contract on Ethereum, has been relayed, or
+/// whether the nonce of the contract is out of sync with Namada, and
+/// print out the result.
+///
+/// This tells operators if the latest Bridge pool root needs relaying.
+pub async fn check_bridge_consistency<C, E>(
+    client: &C,
+    io: &impl Io,
+    eth_client: Arc<E>,
+) -> Result<ConsistencyReport, Error>
+where
+    C: Client + Sync,
+    E: Middleware,
+    E::Error: std::fmt::Display,
+{
+    ensure_bridge_active(client, io).await?;
+    let (signed_root_nonce, signed_root_height) =
+        match query_signed_root(client).await? {
+            Some((root, height)) => (Some(U256(root.data.1 .0)), Some(height)),
+            None => (None, None),
+        };
+    let bridge = read_bridge_contract(client, io, &eth_client).await?;
+    let contract_nonce =
+        query_contract_bp_nonce(&bridge, eth_request_timeout_from_env(io)?)
+            .await?;
+    let consistency = bridge_consistency(signed_root_nonce, contract_nonce);
 
-    /// Transfer to Ethereum that is eligible to be recommended
-    /// for a relay operation, generating a profit.
-    ///
-    /// This means that the underlying Ethereum event has not
-    /// been "seen" yet, and that the user provided appropriate
-    /// conversion rates to gwei for the gas fee token in
-    /// the transfer.
-    #[derive(Debug, Eq, PartialEq)]
-    struct EligibleRecommendation {
-        /// Pending transfer to Ethereum.
-        pending_transfer: PendingTransfer,
-        /// Hash of the [`PendingTransfer`].
-        transfer_hash: String,
-        /// Cost of relaying the transfer, in gwei.
-        cost: I256,
+    let signed_root = match (signed_root_nonce, signed_root_height) {
+        (Some(nonce), Some(height)) => {
+            format!("nonce {nonce}, signed at height {height}")
+        }
+        _ => "none".into(),
+    };
+    display_line!(io, "Latest signed Bridge pool root: {signed_root}");
+    display_line!(io, "Bridge contract nonce: {contract_nonce}");
+    display_line!(io, "Bridge contract status: {consistency}");
+    if consistency == BridgeConsistency::Anomaly {
+        let error = error_label();
+        edisplay_line!(
+            io,
+            "{error}: The Bridge contract expects nonce {contract_nonce}, \
+             which is neither the nonce of the latest signed Bridge pool \
+             root, nor the one following it."
+        );
     }
 
-    /// Batch of recommended transfers to Ethereum that generate
-    /// a profit after a relay operation.
-    #[derive(Debug, Eq, PartialEq)]
-    struct RecommendedBatch {
-        /// Hashes of the recommended transfers to be relayed.
-        transfer_hashes: Vec<String>,
-        /// Estimate of the total fees, measured in gwei, that will be paid
-        /// on Ethereum.
-        ethereum_gas_fees: Uint,
-        /// Net profitt in gwei, based on the conversion rates provided
-        /// to the algorithm.
-        net_profit: I256,
-        /// Gas fees paid by the transfers considered for relaying,
-        /// paid in various token types.
-        bridge_pool_gas_fees: HashMap<String, Uint>,
-    }
+    Ok(ConsistencyReport {
+        signed_root_nonce,
+        signed_root_height,
+        contract_nonce,
+        consistency,
+    })
+}
 
-    /// Recommend the most economical batch of transfers to relay based
-    /// on a conversion rate estimates from NAM to ETH and gas usage
-    /// heuristics.
-    pub async fn recommend_batch(
-        context: &impl Namada,
-        args: args::RecommendBatch,
-    ) -> Result<(), Error> {
-        // get transfers that can already been relayed but are awaiting a quorum
-        // of backing votes.
-        let in_progress = RPC
-            .shell()
-            .eth_bridge()
-            .transfer_to_ethereum_progress(context.client())
-            .await
-            .map_err(|e| {
-                Error::EthereumBridge(
-                    EthereumBridgeError::TransferToEthProgress(e.to_string()),
-                )
-            })?
-            .into_keys()
-            .map(|pending| pending.keccak256().to_string())
-            .collect::<BTreeSet<_>>();
+/// Query and print out a summary of the status of the Ethereum
+/// bridge pool.
+///
+/// See [`bridge_pool_status`] for the data being summarized.
+pub async fn query_bridge_pool_status<C, E>(
+    client: &C,
+    io: &impl Io,
+    eth_client: Option<Arc<E>>,
+) -> Result<BridgePoolStatus, Error>
+where
+    C: Client + Sync,
+    E: Middleware,
+    E::Error: std::fmt::Display,
+{
+    ensure_bridge_active(client, io).await?;
+    let status = bridge_pool_status(client, eth_client).await?;
+    let denoms =
+        query_denominations(client, status.fee_revenue.keys().cloned()).await?;
 
-        // get the signed bridge pool root so we can analyze the signatures
-        // the estimate the gas cost of verifying them.
-        let (bp_root, height) =
-            <(BridgePoolRootProof, BlockHeight)>::try_from_slice(
-                &RPC.shell()
-                    .storage_value(
-                        context.client(),
-                        None,
-                        None,
-                        false,
-                        &get_signed_root_key(),
-                    )
-                    .await
-                    .map_err(|err| {
-                        Error::Query(QueryError::General(echo_error!(
-                            context.io(),
-                            "Failed to query Bridge pool proof: {err}"
-                        )))
-                    })?
-                    .data,
-            )
-            .map_err(|err| {
-                Error::Encode(EncodingError::Decoding(echo_error!(
-                    context.io(),
-                    "Failed to decode Bridge pool proof: {err}"
-                )))
-            })?;
+    display_line!(io, "Pending transfers: {}", status.pending_transfers);
+    display_line!(
+        io,
+        "Transfers covered by the signed root: {}",
+        status.signed_transfers
+    );
+    display_line!(
+        io,
+        "Transfers being relayed: {}",
+        status.in_progress_transfers
+    );
+    match (status.signed_root_height, status.signed_root_nonce) {
+        (Some(height), Some(nonce)) => display_line!(
+            io,
+            "Latest signed root: nonce {nonce}, signed at height {height}"
+        ),
+        _ => display_line!(io, "No Bridge pool root has been signed yet"),
+    }
+    if let Some(nonce) = status.contract_nonce {
+        display_line!(io, "Bridge contract nonce: {nonce}");
+    }
+    if !status.fee_revenue.is_empty() {
+        display_line!(io, "Fee revenue:");
+        for (token, amount) in &status.fee_revenue {
+            display_line!(
+                io,
+                "  {token}: {}",
+                DenominatedAmount::new(*amount, denoms[token])
+            );
+        }
+    }
+    Ok(status)
+}
 
-        // get the latest bridge pool nonce
-        let latest_bp_nonce = EthUint::try_from_slice(
+/// Verify, locally, that the latest signed Bridge pool root is backed
+/// by a quorum of valid signatures of the validator set that signed it.
+///
+/// Prints out the voting power achieved by the valid signatures, and
+/// returns whether it constitutes a quorum.
+pub async fn verify_signed_root<C, IO>(
+    client: &C,
+    io: &IO,
+) -> Result<bool, Error>
+where
+    C: Client + Sync,
+    IO: Io,
+{
+    let (bp_root, height) =
+        <(BridgePoolRootProof, BlockHeight)>::try_from_slice(
             &RPC.shell()
                 .storage_value(
-                    context.client(),
+                    client,
                     None,
                     None,
                     false,
-                    &get_nonce_key(),
+                    &get_signed_root_key(),
                 )
                 .await
                 .map_err(|err| {
                     Error::Query(QueryError::General(echo_error!(
-                        context.io(),
-                        "Failed to query Bridge pool nonce: {err}"
+                        io,
+                        "Failed to query Bridge pool proof: {err}"
                     )))
                 })?
                 .data,
         )
         .map_err(|err| {
             Error::Encode(EncodingError::Decoding(echo_error!(
-                context.io(),
-                "Failed to decode Bridge pool nonce: {err}"
+                io,
+                "Failed to decode Bridge pool proof: {err}"
             )))
         })?;
+    let voting_powers = RPC
+        .shell()
+        .eth_bridge()
+        .voting_powers_at_height(client, &height)
+        .await
+        .map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::QueryVotingPowers(
+                e.to_string(),
+            ))
+        })?;
+    let total_power = voting_powers.values().copied().sum::<Amount>();
 
-        if latest_bp_nonce != bp_root.data.1 {
+    // reconstruct the message signed by the validators
+    let (root, nonce) = &bp_root.data;
+    let message = SignableEthMessage::as_signable(&keccak_hash(
+        [root.0, nonce.to_bytes()].concat(),
+    ));
+
+    let mut signed_power = Amount::zero();
+    for (addr_book, sig) in bp_root.signatures.iter() {
+        let Some(power) = voting_powers.get(addr_book) else {
             edisplay_line!(
-                context.io(),
-                "The signed Bridge pool nonce is not up to date, repeat this \
-                 query at a later time"
+                io,
+                "Ignoring signature of {}, which is not part of the \
+                 validator set at height {height}",
+                addr_book.hot_key_addr
+            );
+            continue;
+        };
+        if recover_eth_signer(&message, sig) == Some(addr_book.hot_key_addr) {
+            signed_power += *power;
+        } else {
+            edisplay_line!(
+                io,
+                "Ignoring invalid signature of {}",
+                addr_book.hot_key_addr
             );
-            return Err(Error::EthereumBridge(
-                EthereumBridgeError::InvalidBpNonce,
-            ));
         }
+    }
 
-        // Get the voting powers of each of validator who signed
-        // the above root.
-        let voting_powers = RPC
-            .shell()
-            .eth_bridge()
-            .voting_powers_at_height(context.client(), &height)
-            .await
+    let achieved_power =
+        FractionalVotingPower::new(signed_power.into(), total_power.into())
             .map_err(|e| {
-                Error::EthereumBridge(EthereumBridgeError::QueryVotingPowers(
-                    e.to_string(),
+                Error::Other(echo_error!(
+                    io,
+                    "Failed to compute the signed voting power: {e}"
                 ))
             })?;
-        let valset_size = Uint::from_u64(voting_powers.len() as u64);
-
-        // This is the gas cost for hashing the validator set and
-        // checking a quorum of signatures (in gwei).
-        let validator_gas = signature_fee()
-            * signature_checks(voting_powers, &bp_root.signatures)
-            + valset_fee() * valset_size;
-
-        // we don't recommend transfers that have already been relayed
-        let eligible = generate_eligible(
-            context.io(),
-            &args.conversion_table,
-            &in_progress,
-            query_signed_bridge_pool(context.client(), context.io()).await?,
-        )?;
+    let quorum = achieved_power > FractionalVotingPower::TWO_THIRDS;
+    display_line!(
+        io,
+        "Bridge pool root {root} with nonce {nonce}, signed at height \
+         {height}, is backed by {achieved_power} of the voting power ({}).",
+        if quorum { "quorum" } else { "no quorum" }
+    );
+    Ok(quorum)
+}
 
-        let max_gas =
-            args.max_gas.map(Uint::from_u64).unwrap_or(uint::MAX_VALUE);
-        let max_cost = args.gas.map(I256::from).unwrap_or_default();
+/// Recover the Ethereum address that signed the given message.
+fn recover_eth_signer(
+    message: &KeccakHash,
+    sig: &secp256k1::Signature,
+) -> Option<EthAddress> {
+    let (r, s, v) = sig.clone().into_eth_rsv();
+    let sig = ethers::types::Signature {
+        r: U256::from_big_endian(&r),
+        s: U256::from_big_endian(&s),
+        v: v.into(),
+    };
+    sig.recover(ethers::types::RecoveryMessage::Hash(H256(message.0)))
+        .ok()
+        .map(|address| EthAddress(address.0))
+}
 
-        generate_recommendations(
-            context.io(),
-            eligible,
-            &args.conversion_table,
-            validator_gas,
-            max_gas,
-            max_cost,
-        )?
-        .map(
-            |RecommendedBatch {
-                 transfer_hashes,
-                 ethereum_gas_fees,
-                 net_profit,
-                 bridge_pool_gas_fees,
-             }| {
-                display_line!(
-                    context.io(),
-                    "Recommended batch: {transfer_hashes:#?}"
-                );
-                display_line!(
-                    context.io(),
-                    "Estimated Ethereum transaction gas (in gwei): \
-                     {ethereum_gas_fees}",
-                );
-                display_line!(
-                    context.io(),
-                    "Estimated net profit (in gwei): {net_profit}"
-                );
-                display_line!(
-                    context.io(),
-                    "Total fees: {bridge_pool_gas_fees:#?}"
-                );
-            },
+/// Query a set of transfers in the Ethereum bridge pool, indexed by
+/// their keccak hash.
+///
+/// Transfers that are not present in the Bridge pool are mapped
+/// to `None`.
+pub async fn query_bridge_pool_transfers<C>(
+    client: &C,
+    hashes: &[KeccakHash],
+) -> Result<HashMap<KeccakHash, Option<PendingTransfer>>, Error>
+where
+    C: Client + Sync,
+{
+    let mut transfers = HashMap::with_capacity(hashes.len());
+    for hash in hashes {
+        let (maybe_bytes, _) = query_storage_value_bytes(
+            client,
+            &get_key_from_hash(hash),
+            None,
+            false,
         )
-        .unwrap_or_else(|| {
-            display_line!(
-                context.io(),
-                "Unable to find a recommendation satisfying the input \
-                 parameters."
-            );
-        });
-
-        Ok(())
+        .await?;
+        let maybe_transfer = maybe_bytes
+            .map(|bytes| PendingTransfer::try_from_slice(&bytes))
+            .transpose()
+            .map_err(|e| {
+                Error::Encode(EncodingError::Decoding(e.to_string()))
+            })?;
+        transfers.insert(hash.clone(), maybe_transfer);
     }
+    Ok(transfers)
+}
 
-    /// Given an ordered list of signatures, figure out the size of the first
-    /// subset constituting a 2 / 3 majority.
-    ///
-    /// The function is generic to make unit testing easier (otherwise a dev
-    /// dependency needs to be added).
-    fn signature_checks<T>(
-        voting_powers: VotingPowersMap,
-        sigs: &HashMap<EthAddrBook, T>,
-    ) -> Uint {
-        let voting_powers = voting_powers.get_sorted();
-        let total_power = voting_powers.iter().map(|(_, &y)| y).sum::<Amount>();
-
-        // Find the total number of signature checks Ethereum will make
-        let mut power = FractionalVotingPower::NULL;
-        Uint::from_u64(
-            voting_powers
-                .iter()
-                .filter_map(|(a, &p)| sigs.get(a).map(|_| p))
-                .take_while(|p| {
-                    if power <= FractionalVotingPower::TWO_THIRDS {
-                        power += FractionalVotingPower::new(
-                            (*p).into(),
-                            total_power.into(),
-                        )
-                        // NB: this unwrap is infallible, since we calculate
-                        // the total voting power beforehand. the fraction's
-                        // value will never exceed 1.0
-                        .unwrap();
-                        true
-                    } else {
-                        false
-                    }
-                })
-                .count() as u64,
-        )
+/// Query the status of a set of transfers in the Ethereum bridge
+/// pool.
+///
+/// Prints a table with the status of each transfer.
+pub async fn query_bridge_pool_transfers_status(
+    client: &(impl Client + Sync),
+    io: &impl Io,
+    args: args::BridgePoolTransfers,
+) -> Result<(), Error> {
+    ensure_bridge_active(client, io).await?;
+    let transfers =
+        query_bridge_pool_transfers(client, &args.transfers).await?;
+    let missing: HashSet<_> = transfers
+        .iter()
+        .filter_map(|(hash, maybe_transfer)| {
+            maybe_transfer.is_none().then(|| hash.clone())
+        })
+        .collect();
+    let status = if missing.is_empty() {
+        None
+    } else {
+        Some(query_eth_transfer_status(client, missing).await?)
+    };
+    for hash in &args.transfers {
+        match (&transfers[hash], &status) {
+            (Some(transfer), _) => {
+                display_line!(io, "present    | {transfer}");
+            }
+            (None, Some(status)) if status.relayed.contains(hash) => {
+                display_line!(io, "relayed    | {}", hash.to_hex());
+            }
+            (None, Some(status)) if status.expired.contains(hash) => {
+                display_line!(io, "expired    | {}", hash.to_hex());
+            }
+            (None, _) => {
+                display_line!(io, "not found  | {}", hash.to_hex());
+            }
+        }
     }
+    Ok(())
+}
 
-    /// Generate eligible recommendations.
-    fn generate_eligible<IO: Io>(
-        io: &IO,
-        conversion_table: &HashMap<Address, args::BpConversionTableEntry>,
-        in_progress: &BTreeSet<String>,
-        signed_pool: HashMap<String, PendingTransfer>,
-    ) -> Result<Vec<EligibleRecommendation>, Error> {
-        let mut eligible: Vec<_> = signed_pool
-            .into_iter()
-            .filter_map(|(pending_hash, pending)| {
-                if in_progress.contains(&pending_hash) {
-                    return None;
-                }
-
-                let conversion_rate = conversion_table
-                    .get(&pending.gas_fee.token)
-                    .and_then(|entry| match entry.conversion_rate {
-                        r if r == 0.0f64 => {
-                            edisplay_line!(
-                                io,
-                                "{}: Ignoring null conversion rate",
-                                pending.gas_fee.token,
-                            );
-                            None
-                        }
-                        r if r < 0.0f64 => {
-                            edisplay_line!(
-                                io,
-                                "{}: Ignoring negative conversion rate: {r:.1}",
-                                pending.gas_fee.token,
-                            );
-                            None
-                        }
-                        r if r > 1e9 => {
-                            edisplay_line!(
-                                io,
-                                "{}: Ignoring high conversion rate: {r:.1} > \
-                                 10^9",
-                                pending.gas_fee.token,
-                            );
-                            None
-                        }
-                        r => Some(r),
-                    })?;
+/// The number of transfers requested in each page, when querying
+/// the progress of transfers to Ethereum.
+pub const TRANSFER_TO_ETHEREUM_PROGRESS_PAGE_SIZE: u64 = 256;
 
-                // This is the amount of gwei a single gas token is worth
-                let gwei_per_gas_token =
-                    Uint::from_u64((1e9 / conversion_rate).floor() as u64);
+/// Iterates over all ethereum events, in pages of `page_size`
+/// transfers, and feeds each `TransferToEthereum` event along
+/// with the amount of voting power backing it to `f`.
+///
+/// This avoids loading the progress of every transfer to Ethereum
+/// into memory at once. Every page must be read at the height of
+/// the first one, otherwise the progress of some transfers may have
+/// been skipped or repeated, and an error is returned.
+///
+/// Since `f` may already have been fed some pages when an error is
+/// returned, checks relying on a consistent view of the progress of
+/// every transfer should use [`query_transfer_to_ethereum_snapshot`].
+pub async fn for_each_transfer_to_ethereum_progress<C, F>(
+    client: &C,
+    page_size: u64,
+    f: F,
+) -> Result<(), Error>
+where
+    C: Client + Sync,
+    F: FnMut(PendingTransfer, FractionalVotingPower),
+{
+    for_each_transfer_to_ethereum_progress_via(
+        client,
+        &RPC.shell().eth_bridge(),
+        page_size,
+        f,
+    )
+    .await
+}
 
-                Some(
-                    Uint::from(pending.gas_fee.amount)
-                        .checked_mul(gwei_per_gas_token)
-                        .ok_or_else(|| {
-                            "Overflowed calculating earned gwei".into()
-                        })
-                        .and_then(I256::try_from)
-                        .map_err(|err| err.to_string())
-                        .and_then(|amt_of_earned_gwei| {
-                            transfer_fee()
-                                .checked_sub(&amt_of_earned_gwei)
-                                .ok_or_else(|| {
-                                    "Underflowed calculating relaying cost"
-                                        .into()
-                                })
-                        })
-                        .map(|cost| EligibleRecommendation {
-                            cost,
-                            pending_transfer: pending,
-                            transfer_hash: pending_hash,
-                        }),
-                )
-            })
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|err| {
-                Error::EthereumBridge(EthereumBridgeError::RelayCost(
-                    echo_error!(io, "Failed to calculate relaying cost: {err}"),
-                ))
+/// Like [`for_each_transfer_to_ethereum_progress`], but querying
+/// the progress through the given `router`.
+async fn for_each_transfer_to_ethereum_progress_via<C, F>(
+    client: &C,
+    router: &EthBridge,
+    page_size: u64,
+    mut f: F,
+) -> Result<(), Error>
+where
+    C: Client + Sync,
+    F: FnMut(PendingTransfer, FractionalVotingPower),
+{
+    let mut first_height = None;
+    let mut next_page = Some(0u64);
+    while let Some(page) = next_page {
+        let resp = router
+            .transfer_to_ethereum_progress_page(client, &page, &page_size)
+            .await
+            .map_err(|e| {
+                Error::EthereumBridge(
+                    EthereumBridgeError::TransferToEthProgress(e.to_string()),
+                )
             })?;
+        let first_height = *first_height.get_or_insert(resp.height);
+        if resp.height != first_height {
+            return Err(Error::EthereumBridge(
+                EthereumBridgeError::TransferToEthProgress(format!(
+                    "Page {page} of the transfers to Ethereum was read at \
+                     height {}, but the first page was read at height \
+                     {first_height}",
+                    resp.height
+                )),
+            ));
+        }
+        for (transfer, voting_power) in resp.progress {
+            f(transfer, voting_power);
+        }
+        next_page = resp.next_page;
+    }
+    Ok(())
+}
+
+/// Query the amount of voting power backing each `TransferToEthereum`
+/// event at once, such that the progress of every transfer is read
+/// from the same block.
+pub async fn query_transfer_to_ethereum_snapshot<C>(
+    client: &C,
+) -> Result<HashMap<PendingTransfer, FractionalVotingPower>, Error>
+where
+    C: Client + Sync,
+{
+    RPC.shell()
+        .eth_bridge()
+        .transfer_to_ethereum_progress(client)
+        .await
+        .map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::TransferToEthProgress(
+                e.to_string(),
+            ))
+        })
+}
 
-        // sort transfers in increasing amounts of profitability
-        eligible.sort_by_key(|EligibleRecommendation { cost, .. }| *cost);
+/// Iterates over all ethereum events, in pages of `page_size`
+/// transfers, and returns the amount of voting power backing
+/// each `TransferToEthereum` event.
+pub async fn query_transfer_to_ethereum_progress<C>(
+    client: &C,
+    page_size: u64,
+) -> Result<HashMap<PendingTransfer, FractionalVotingPower>, Error>
+where
+    C: Client + Sync,
+{
+    query_transfer_to_ethereum_progress_via(
+        client,
+        &RPC.shell().eth_bridge(),
+        page_size,
+    )
+    .await
+}
 
-        Ok(eligible)
+/// Like [`query_transfer_to_ethereum_progress`], but querying the
+/// progress through the given `router`.
+async fn query_transfer_to_ethereum_progress_via<C>(
+    client: &C,
+    router: &EthBridge,
+    page_size: u64,
+) -> Result<HashMap<PendingTransfer, FractionalVotingPower>, Error>
+where
+    C: Client + Sync,
+{
+    let mut progress = HashMap::new();
+    for_each_transfer_to_ethereum_progress_via(
+        client,
+        router,
+        page_size,
+        |transfer, voting_power| {
+            progress.insert(transfer, voting_power);
+        },
+    )
+    .await?;
+    Ok(progress)
+}
+
+/// Iterates over all ethereum events
+/// and returns the amount of voting power
+/// backing each `TransferToEthereum` event.
+///
+/// Prints a one line summary of each transfer, or writes a
+/// json payload with all transfers to `output`.
+pub async fn query_relay_progress(
+    client: &(impl Client + Sync),
+    io: &impl Io,
+    output: Option<&Path>,
+) -> Result<(), Error> {
+    ensure_bridge_active(client, io).await?;
+    if output.is_some() {
+        let mut relay_progress = vec![];
+        for_each_transfer_to_ethereum_progress(
+            client,
+            TRANSFER_TO_ETHEREUM_PROGRESS_PAGE_SIZE,
+            |transfer, voting_power| {
+                relay_progress
+                    .push(RelayProgressResponse::new(&transfer, voting_power));
+            },
+        )
+        .await?;
+        return write_query_output(io, output, &relay_progress);
+    }
+    let mut any_transfers = false;
+    for_each_transfer_to_ethereum_progress(
+        client,
+        TRANSFER_TO_ETHEREUM_PROGRESS_PAGE_SIZE,
+        |transfer, voting_power| {
+            any_transfers = true;
+            display_line!(io, "{transfer} | voting power: {voting_power}");
+        },
+    )
+    .await?;
+    if !any_transfers {
+        display_line!(io, "No transfers to Ethereum are being relayed.");
     }
+    Ok(())
+}
 
-    /// Generates the actual recommendation from restrictions given by the
-    /// input parameters.
-    fn generate_recommendations<IO: Io>(
-        io: &IO,
-        contents: Vec<EligibleRecommendation>,
-        conversion_table: &HashMap<Address, args::BpConversionTableEntry>,
-        validator_gas: Uint,
-        max_gas: Uint,
-        max_cost: I256,
-    ) -> Result<Option<RecommendedBatch>, Error> {
-        let mut state = AlgorithState {
-            profitable: true,
-            feasible_region: false,
-        };
+/// The relay progress of a transfer to Ethereum, as written out
+/// by [`query_relay_progress`].
+///
+/// This is the json output contract of relay progress queries,
+/// which is independent of the shape of the RPC response it is
+/// built from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RelayProgressResponse {
+    /// The keccak hash of the transfer.
+    pub transfer_hash: String,
+    /// The fraction of the voting power backing the event of
+    /// the transfer, as a decimal number between zero and one,
+    /// truncated to the precision of a [`Dec`].
+    pub voting_power: String,
+    /// Whether the event of the transfer is backed by a quorum
+    /// of the voting power.
+    pub quorum_reached: bool,
+}
 
-        let mode = if max_cost <= I256::zero() {
-            AlgorithmMode::Greedy
-        } else {
-            AlgorithmMode::Generous
-        };
+impl RelayProgressResponse {
+    /// Describe the relay progress of `transfer`, whose event
+    /// is backed by the given `voting_power`.
+    pub fn new(
+        transfer: &PendingTransfer,
+        voting_power: FractionalVotingPower,
+    ) -> Self {
+        Self {
+            transfer_hash: transfer.keccak256().to_hex(),
+            voting_power: voting_power_dec(&voting_power).to_string(),
+            quorum_reached: voting_power > FractionalVotingPower::TWO_THIRDS,
+        }
+    }
+}
 
-        let mut total_gas = validator_gas;
-        let mut total_cost = I256::try_from(validator_gas).map_err(|err| {
-            Error::Encode(EncodingError::Conversion(echo_error!(
-                io,
-                "Failed to convert value to I256: {err}"
-            )))
-        })?;
-        let mut total_fees = HashMap::new();
-        let mut recommendation = vec![];
-        for EligibleRecommendation {
-            cost,
-            transfer_hash: hash,
-            pending_transfer: transfer,
-        } in contents.into_iter()
-        {
-            let next_total_gas = total_gas + unsigned_transfer_fee();
-            let next_total_cost = total_cost + cost;
-            if cost.is_negative() {
-                if next_total_gas <= max_gas && next_total_cost <= max_cost {
-                    state.feasible_region = true;
-                } else if state.feasible_region {
-                    // once we leave the feasible region, we will never re-enter
-                    // it.
-                    break;
-                }
-                recommendation.push(hash);
-            } else if mode == AlgorithmMode::Generous {
-                state.profitable = false;
-                let is_feasible =
-                    next_total_gas <= max_gas && next_total_cost <= max_cost;
-                // once we leave the feasible region, we will never re-enter it.
-                if state.feasible_region && !is_feasible {
-                    break;
-                } else {
-                    recommendation.push(hash);
-                }
-            } else {
-                break;
-            }
-            total_cost = next_total_cost;
-            total_gas = next_total_gas;
-            update_total_fees(&mut total_fees, transfer, conversion_table);
+/// The relay progress of a transfer to Ethereum, as observed by
+/// a [`RelayProgressMonitor`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferProgress {
+    /// The transfer being relayed.
+    pub transfer: PendingTransfer,
+    /// The voting power backing the event of the transfer.
+    pub voting_power: FractionalVotingPower,
+    /// The fraction of the total voting power gained by the
+    /// transfer per second, since it was last observed.
+    ///
+    /// This is `None` the first time a transfer is observed.
+    pub rate: Option<f64>,
+    /// Whether the transfer has not gained any voting power
+    /// for at least the stall timeout of the monitor.
+    pub stalled: bool,
+}
+
+/// The last observed relay progress of a transfer to Ethereum.
+#[derive(Debug, Clone)]
+struct ObservedProgress {
+    voting_power: FractionalVotingPower,
+    observed_at: Instant,
+    last_increase: Instant,
+}
+
+/// Monitor of the relay progress of transfers to Ethereum, which
+/// computes the rate at which transfers accumulate backing voting
+/// power across successive polls, and flags stalled transfers.
+#[derive(Debug, Clone)]
+pub struct RelayProgressMonitor {
+    stall_timeout: Duration,
+    observed: HashMap<KeccakHash, ObservedProgress>,
+}
+
+impl RelayProgressMonitor {
+    /// Create a new monitor, flagging transfers whose voting power
+    /// has not increased for `stall_timeout` as stalled.
+    pub fn new(stall_timeout: Duration) -> Self {
+        Self {
+            stall_timeout,
+            observed: HashMap::new(),
         }
+    }
 
-        Ok(if state.feasible_region && !recommendation.is_empty() {
-            Some(RecommendedBatch {
-                transfer_hashes: recommendation,
-                ethereum_gas_fees: total_gas,
-                net_profit: -total_cost,
-                bridge_pool_gas_fees: total_fees,
+    /// Record the relay progress of transfers to Ethereum, polled
+    /// at the instant `now`.
+    ///
+    /// Transfers absent from `progress` are no longer being relayed,
+    /// and are therefore forgotten by the monitor.
+    pub fn observe<I>(
+        &mut self,
+        now: Instant,
+        progress: I,
+    ) -> Vec<TransferProgress>
+    where
+        I: IntoIterator<Item = (PendingTransfer, FractionalVotingPower)>,
+    {
+        let mut observed = HashMap::new();
+        let report = progress
+            .into_iter()
+            .map(|(transfer, voting_power)| {
+                let hash = transfer.keccak256();
+                let (rate, last_increase) = match self.observed.remove(&hash) {
+                    Some(prev) => {
+                        let elapsed =
+                            now.duration_since(prev.observed_at).as_secs_f64();
+                        let gained = voting_power_fraction(&voting_power)
+                            - voting_power_fraction(&prev.voting_power);
+                        let rate = (elapsed > 0.0).then(|| gained / elapsed);
+                        let last_increase = if voting_power > prev.voting_power
+                        {
+                            now
+                        } else {
+                            prev.last_increase
+                        };
+                        (rate, last_increase)
+                    }
+                    None => (None, now),
+                };
+                let stalled =
+                    now.duration_since(last_increase) >= self.stall_timeout;
+                observed.insert(
+                    hash,
+                    ObservedProgress {
+                        voting_power,
+                        observed_at: now,
+                        last_increase,
+                    },
+                );
+                TransferProgress {
+                    transfer,
+                    voting_power,
+                    rate,
+                    stalled,
+                }
             })
-        } else {
-            edisplay_line!(
+            .collect();
+        self.observed = observed;
+        report
+    }
+}
+
+/// Convert some [`FractionalVotingPower`] to a [`Dec`] fraction of
+/// the total voting power, truncated to the precision of a [`Dec`].
+fn voting_power_dec(voting_power: &FractionalVotingPower) -> Dec {
+    // NB: the bridge voting power is at most 2^96, so scaling it
+    // to the precision of a `Dec` cannot overflow
+    let max = Uint::from(u128::from(EthBridgeVotingPower::MAX));
+    let voting_power =
+        Uint::from(u128::from(EthBridgeVotingPower::from(voting_power)));
+    let scale = Uint::exp10(POS_DECIMAL_PRECISION as usize);
+    Dec(I256(voting_power * scale / max))
+}
+
+/// Convert some [`FractionalVotingPower`] to a floating point
+/// fraction of the total voting power.
+fn voting_power_fraction(voting_power: &FractionalVotingPower) -> f64 {
+    let max = u128::from(EthBridgeVotingPower::MAX) as f64;
+    u128::from(EthBridgeVotingPower::from(voting_power)) as f64 / max
+}
+
+/// Periodically query the relay progress of transfers to Ethereum,
+/// printing the rate at which each transfer accumulates backing
+/// voting power, and flagging stalled transfers.
+///
+/// Runs until a shutdown signal (e.g. Ctrl-C) is received.
+pub async fn watch_relay_progress(
+    client: &(impl Client + Sync),
+    io: &impl Io,
+    interval: Duration,
+    stall_timeout: Duration,
+) -> Result<(), Error> {
+    ensure_bridge_active(client, io).await?;
+    let mut monitor = RelayProgressMonitor::new(stall_timeout);
+    let mut shutdown = install_shutdown_signal();
+    loop {
+        let mut progress = vec![];
+        for_each_transfer_to_ethereum_progress(
+            client,
+            TRANSFER_TO_ETHEREUM_PROGRESS_PAGE_SIZE,
+            |transfer, voting_power| progress.push((transfer, voting_power)),
+        )
+        .await?;
+        let report = monitor.observe(Instant::now(), progress);
+        if report.is_empty() {
+            display_line!(io, "No transfers to Ethereum are being relayed.");
+        }
+        for progress in report {
+            let rate = match progress.rate {
+                Some(rate) => format!("{:+.2}%/min", rate * 100.0 * 60.0),
+                None => "n/a".into(),
+            };
+            let stalled = if progress.stalled { " | STALLED" } else { "" };
+            display_line!(
                 io,
-                "Unable to find a recommendation satisfying the input \
-                 parameters."
+                "{} | voting power: {} | rate: {rate}{stalled}",
+                progress.transfer,
+                progress.voting_power,
             );
-            None
-        })
+        }
+        display_line!(io, "");
+        let sleep = Box::pin(time::sleep(interval));
+        if let Either::Right(_) = future::select(sleep, &mut shutdown).await {
+            return Ok(());
+        }
     }
+}
 
-    fn update_total_fees(
-        total_fees: &mut HashMap<String, Uint>,
-        transfer: PendingTransfer,
-        conversion_table: &HashMap<Address, args::BpConversionTableEntry>,
-    ) {
-        let GasFee { token, amount, .. } = transfer.gas_fee;
-        let fees = total_fees
-            .entry(
-                conversion_table
-                    .get(&token)
-                    .map(|entry| entry.alias.clone())
-                    .unwrap_or_else(|| token.to_string()),
-            )
-            .or_insert(uint::ZERO);
-        *fees += Uint::from(amount);
+/// The growth of the Ethereum bridge pool, as observed by a
+/// [`BridgePoolGrowthMonitor`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BridgePoolGrowth {
+    /// The number of transfers in the Bridge pool.
+    pub pool_size: usize,
+    /// The amount of time spanned by the observations of the
+    /// monitor that are still within its window.
+    pub span: Duration,
+    /// The number of transfers added to the Bridge pool per minute.
+    ///
+    /// This is `None` until the Bridge pool has been observed twice.
+    pub transfers_per_minute: Option<f64>,
+    /// The gas fees paid per minute by the transfers added to the
+    /// Bridge pool, per fee token.
+    pub fees_per_minute: HashMap<Address, Amount>,
+    /// The transfers which were in the Bridge pool during the
+    /// previous observation, but have since left it.
+    pub evicted: HashSet<KeccakHash>,
+}
+
+/// The reasons why transfers left the Ethereum bridge pool.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EvictionStats {
+    /// The number of transfers relayed to Ethereum.
+    pub relayed: usize,
+    /// The number of transfers which expired from the Bridge pool.
+    pub expired: usize,
+    /// The number of transfers whose fate could not be determined.
+    pub unrecognized: usize,
+}
+
+impl EvictionStats {
+    /// Classify the `evicted` transfers by their status, as reported
+    /// by the ledger.
+    pub fn new(
+        evicted: &HashSet<KeccakHash>,
+        status: &TransferToEthereumStatus,
+    ) -> Self {
+        let mut stats = Self::default();
+        for hash in evicted {
+            if status.relayed.contains(hash) {
+                stats.relayed += 1;
+            } else if status.expired.contains(hash) {
+                stats.expired += 1;
+            } else {
+                stats.unrecognized += 1;
+            }
+        }
+        stats
     }
+}
 
-    #[cfg(test)]
-    mod test_recommendations {
-        use namada_core::types::address::Address;
+/// A snapshot of the transfers added to the Bridge pool, since the
+/// previous observation of a [`BridgePoolGrowthMonitor`].
+#[derive(Debug, Clone)]
+struct GrowthSample {
+    observed_at: Instant,
+    added: usize,
+    fees: HashMap<Address, Amount>,
+}
 
-        use super::*;
-        use crate::io::StdIo;
+/// Monitor of the growth of the Ethereum bridge pool, which computes
+/// the rate at which transfers are added to it, and the rate at which
+/// their fees accumulate, over a rolling window of observations.
+#[derive(Debug, Clone)]
+pub struct BridgePoolGrowthMonitor {
+    window: Duration,
+    samples: VecDeque<GrowthSample>,
+    seen: HashSet<KeccakHash>,
+}
 
-        /// An established user address for testing & development
-        pub fn bertha_address() -> Address {
-            Address::decode("tnam1qyctxtpnkhwaygye0sftkq28zedf774xc5a2m7st")
-                .expect("The token address decoding shouldn't fail")
+impl BridgePoolGrowthMonitor {
+    /// Create a new monitor, estimating growth rates from the
+    /// observations made within the last `window`.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            samples: VecDeque::new(),
+            seen: HashSet::new(),
         }
+    }
 
-        /// Generate a pending transfer with the specified gas
-        /// fee.
-        pub fn transfer(gas_amount: u64) -> PendingTransfer {
-            PendingTransfer {
-                transfer: TransferToEthereum {
-                    kind: TransferToEthereumKind::Erc20,
-                    asset: EthAddress([1; 20]),
-                    recipient: EthAddress([2; 20]),
-                    sender: bertha_address(),
-                    amount: Default::default(),
-                },
-                gas_fee: GasFee {
-                    token: namada_core::types::address::nam(),
-                    amount: gas_amount.into(),
-                    payer: bertha_address(),
-                },
+    /// Record the contents of the Bridge pool, polled at the
+    /// instant `now`.
+    ///
+    /// The first observation only sets the baseline of the monitor,
+    /// such that transfers already in the Bridge pool are not counted
+    /// as additions.
+    pub fn observe<I>(&mut self, now: Instant, pool: I) -> BridgePoolGrowth
+    where
+        I: IntoIterator<Item = PendingTransfer>,
+    {
+        let first_observation = self.samples.is_empty();
+        let mut seen = HashSet::new();
+        let mut sample = GrowthSample {
+            observed_at: now,
+            added: 0,
+            fees: HashMap::new(),
+        };
+        for transfer in pool {
+            let hash = transfer.keccak256();
+            if !first_observation && !self.seen.contains(&hash) {
+                sample.added += 1;
+                accumulate_fees(
+                    &mut sample.fees,
+                    &transfer.gas_fee.token,
+                    transfer.gas_fee.amount,
+                );
             }
+            seen.insert(hash);
+        }
+        let pool_size = seen.len();
+        let evicted = self.seen.difference(&seen).cloned().collect();
+        self.seen = seen;
+        self.samples.push_back(sample);
+        while self.samples.len() > 1
+            && now.duration_since(self.samples[0].observed_at) > self.window
+        {
+            self.samples.pop_front();
         }
 
-        /// Convert transfers into a format that the
-        /// [`generate_recommendations`] function understands.
-        fn process_transfers(
-            transfers: Vec<PendingTransfer>,
-        ) -> Vec<EligibleRecommendation> {
-            transfers
-                .into_iter()
-                .map(|t| EligibleRecommendation {
-                    cost: transfer_fee() - t.gas_fee.amount.change(),
-                    transfer_hash: t.keccak256().to_string(),
-                    pending_transfer: t,
+        // NB: the oldest sample in the window only marks its start,
+        // since its additions happened before it
+        let span = now.duration_since(self.samples[0].observed_at);
+        let span_millis = span.as_millis() as u64;
+        let mut added = 0;
+        let mut fees = HashMap::new();
+        for sample in self.samples.iter().skip(1) {
+            added += sample.added;
+            for (token, amount) in &sample.fees {
+                accumulate_fees(&mut fees, token, *amount);
+            }
+        }
+        let transfers_per_minute = (span_millis > 0)
+            .then(|| added as f64 * 60_000.0 / span_millis as f64);
+        let fees_per_minute = if span_millis > 0 {
+            fees.into_iter()
+                .map(|(token, amount)| {
+                    let per_minute = amount
+                        .checked_mul(Amount::from(60_000))
+                        .map(|amount| amount / span_millis)
+                        .unwrap_or_else(Amount::max);
+                    (token, per_minute)
                 })
                 .collect()
+        } else {
+            HashMap::new()
+        };
+        BridgePoolGrowth {
+            pool_size,
+            span,
+            transfers_per_minute,
+            fees_per_minute,
+            evicted,
         }
+    }
+}
 
-        fn address_book(i: u8) -> EthAddrBook {
-            EthAddrBook {
-                hot_key_addr: EthAddress([i; 20]),
-                cold_key_addr: EthAddress([i; 20]),
-            }
-        }
+/// Add `amount` to the fees accumulated in `token`, like
+/// [`add_fees`], but saturating on overflows.
+fn accumulate_fees(
+    fees: &mut HashMap<Address, Amount>,
+    token: &Address,
+    amount: Amount,
+) {
+    if add_fees(fees, token.clone(), &amount).is_err() {
+        fees.insert(token.clone(), Amount::max());
+    }
+}
 
-        /// Data to pass to the [`test_generate_eligible_aux`] callback.
-        struct TestGenerateEligible<'a> {
-            pending: &'a PendingTransfer,
-            conversion_table:
-                &'a mut HashMap<Address, args::BpConversionTableEntry>,
-            in_progress: &'a mut BTreeSet<String>,
-            signed_pool: &'a mut HashMap<String, PendingTransfer>,
-            expected_eligible: &'a mut Vec<EligibleRecommendation>,
+/// Periodically query the contents of the Ethereum bridge pool,
+/// printing a rolling estimate of the rate at which transfers are
+/// added to it, and of the rate at which their fees accumulate.
+/// Transfers leaving the Bridge pool are evicted from the view of the
+/// monitor, reporting whether they were relayed or expired.
+///
+/// Runs until a shutdown signal (e.g. Ctrl-C) is received.
+pub async fn monitor_bridge_pool_growth(
+    client: &(impl Client + Sync),
+    io: &impl Io,
+    args: args::MonitorBridgePoolGrowth,
+) -> Result<(), Error> {
+    ensure_bridge_active(client, io).await?;
+    let mut monitor = BridgePoolGrowthMonitor::new(args.window);
+    let mut denoms = HashMap::new();
+    let mut shutdown = install_shutdown_signal();
+    loop {
+        let pool: Vec<PendingTransfer> = RPC
+            .shell()
+            .eth_bridge()
+            .read_ethereum_bridge_pool(client)
+            .await
+            .map_err(|e| {
+                Error::EthereumBridge(EthereumBridgeError::ReadBridgePool(
+                    e.to_string(),
+                ))
+            })?;
+        let growth = monitor.observe(Instant::now(), pool);
+        match growth.transfers_per_minute {
+            Some(rate) => display_line!(
+                io,
+                "Bridge pool size: {} | inflow: {rate:.2} transfers/min over \
+                 the last {}s",
+                growth.pool_size,
+                growth.span.as_secs(),
+            ),
+            None => display_line!(
+                io,
+                "Bridge pool size: {} | inflow: n/a",
+                growth.pool_size
+            ),
+        }
+        let missing: Vec<_> = growth
+            .fees_per_minute
+            .keys()
+            .filter(|token| !denoms.contains_key(*token))
+            .cloned()
+            .collect();
+        denoms.extend(query_denominations(client, missing).await?);
+        for (token, amount) in &growth.fees_per_minute {
+            display_line!(
+                io,
+                "  fees in {token}: {}/min",
+                DenominatedAmount::new(*amount, denoms[token])
+            );
+        }
+        if !growth.evicted.is_empty() {
+            let status =
+                query_eth_transfer_status(client, growth.evicted.clone())
+                    .await?;
+            let EvictionStats {
+                relayed,
+                expired,
+                unrecognized,
+            } = EvictionStats::new(&growth.evicted, &status);
+            display_line!(
+                io,
+                "  evicted {} transfers: {relayed} relayed, {expired} \
+                 expired, {unrecognized} unrecognized",
+                growth.evicted.len(),
+            );
+        }
+        let sleep = Box::pin(time::sleep(args.interval));
+        if let Either::Right(_) = future::select(sleep, &mut shutdown).await {
+            return Ok(());
         }
+    }
+}
 
-        impl TestGenerateEligible<'_> {
-            /// Add ETH to a conversion table.
-            fn add_eth_to_conversion_table(&mut self) {
-                self.conversion_table.insert(
-                    namada_core::types::address::eth(),
-                    args::BpConversionTableEntry {
-                        alias: "ETH".into(),
-                        conversion_rate: 1e9, // 1 ETH = 1e9 GWEI
-                    },
-                );
+/// Query the transfers to Ethereum whose events have been seen by
+/// some validators, but which do not yet have a quorum of voting
+/// power behind them in Namada.
+///
+/// These transfers have likely been relayed already, so relaying
+/// them again would most likely waste gas. The returned transfers
+/// are sorted in decreasing order of backing voting power.
+pub async fn query_seen_unconfirmed<C>(
+    client: &C,
+) -> Result<Vec<(PendingTransfer, FractionalVotingPower)>, Error>
+where
+    C: Client + Sync,
+{
+    let mut seen = HashMap::new();
+    for_each_transfer_to_ethereum_progress(
+        client,
+        TRANSFER_TO_ETHEREUM_PROGRESS_PAGE_SIZE,
+        |transfer, voting_power| {
+            if voting_power > FractionalVotingPower::NULL {
+                seen.insert(transfer, voting_power);
             }
-        }
+        },
+    )
+    .await?;
+    let mut seen: Vec<_> = seen.into_iter().collect();
+    seen.sort_by(|(transfer_a, power_a), (transfer_b, power_b)| {
+        power_b
+            .cmp(power_a)
+            .then_with(|| transfer_a.keccak256().cmp(&transfer_b.keccak256()))
+    });
+    Ok(seen)
+}
 
-        /// Helper function to test [`generate_eligible`].
-        fn test_generate_eligible_aux<F>(
-            mut callback: F,
-        ) -> Vec<EligibleRecommendation>
-        where
-            F: FnMut(TestGenerateEligible<'_>),
-        {
-            let pending = PendingTransfer {
-                transfer: TransferToEthereum {
-                    kind: TransferToEthereumKind::Erc20,
-                    asset: EthAddress([1; 20]),
-                    recipient: EthAddress([2; 20]),
-                    sender: bertha_address(),
-                    amount: Default::default(),
-                },
-                gas_fee: GasFee {
-                    token: namada_core::types::address::eth(),
-                    amount: 1_000_000_000_u64.into(), // 1 GWEI
-                    payer: bertha_address(),
-                },
-            };
-            let mut table = HashMap::new();
-            let mut in_progress = BTreeSet::new();
-            let mut signed_pool = HashMap::new();
-            let mut expected = vec![];
-            callback(TestGenerateEligible {
-                pending: &pending,
-                conversion_table: &mut table,
-                in_progress: &mut in_progress,
-                signed_pool: &mut signed_pool,
-                expected_eligible: &mut expected,
-            });
-            let eligible =
-                generate_eligible(&StdIo, &table, &in_progress, signed_pool)
-                    .unwrap();
-            assert_eq!(eligible, expected);
-            eligible
+/// The interval between each query performed by [`await_quorum`].
+const AWAIT_QUORUM_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The number of consecutive failed queries after which
+/// [`await_quorum`] gives up waiting.
+pub const AWAIT_QUORUM_MAX_QUERY_FAILURES: usize = 5;
+
+/// Wait until the transfer to Ethereum with the given hash is
+/// backed by a quorum of voting power in Namada.
+///
+/// Returns `true` if the transfer reached a quorum (or has been
+/// relayed already), and `false` if it expired from the Bridge
+/// pool or `timeout` elapsed beforehand. Waiting is aborted with
+/// an error once `cancel` resolves, or after
+/// [`AWAIT_QUORUM_MAX_QUERY_FAILURES`] consecutive failed queries.
+pub async fn await_quorum<C, F>(
+    client: &C,
+    hash: KeccakHash,
+    timeout: Duration,
+    cancel: F,
+) -> Result<bool, Error>
+where
+    C: Client + Sync,
+    F: Future<Output = ()>,
+{
+    let poll_quorum = time::timeout(timeout, async {
+        let mut failures = 0;
+        loop {
+            match transfer_quorum_status(client, &hash).await {
+                Ok(Some(reached_quorum)) => return Ok(reached_quorum),
+                Ok(None) => failures = 0,
+                Err(err) => {
+                    failures += 1;
+                    if failures >= AWAIT_QUORUM_MAX_QUERY_FAILURES {
+                        return Err(err);
+                    }
+                }
+            }
+            time::sleep(AWAIT_QUORUM_POLL_INTERVAL).await;
         }
+    });
+    futures::pin_mut!(poll_quorum, cancel);
+    match future::select(poll_quorum, cancel).await {
+        Either::Left((Ok(result), _)) => result,
+        // the timeout elapsed
+        Either::Left((Err(_), _)) => Ok(false),
+        Either::Right(_) => Err(Error::Other(format!(
+            "Interrupted while waiting for transfer {} to reach a quorum",
+            hash.to_hex()
+        ))),
+    }
+}
 
-        /// Test the happy path of generating eligible recommendations
-        /// for Bridge pool relayed transfers.
-        #[test]
-        fn test_generate_eligible_happy_path() {
-            test_generate_eligible_aux(|mut ctx| {
-                ctx.add_eth_to_conversion_table();
-                ctx.signed_pool.insert(
-                    ctx.pending.keccak256().to_string(),
-                    ctx.pending.clone(),
-                );
-                ctx.expected_eligible.push(EligibleRecommendation {
-                    transfer_hash: ctx.pending.keccak256().to_string(),
-                    cost: transfer_fee()
-                        - I256::try_from(ctx.pending.gas_fee.amount)
-                            .expect("Test failed"),
-                    pending_transfer: ctx.pending.clone(),
-                });
-            });
+/// Query whether the transfer to Ethereum with the given hash has
+/// been decided, i.e. if it is backed by a quorum of voting power
+/// or has been relayed (`Some(true)`), or if it expired from the
+/// Bridge pool (`Some(false)`).
+async fn transfer_quorum_status<C>(
+    client: &C,
+    hash: &KeccakHash,
+) -> Result<Option<bool>, Error>
+where
+    C: Client + Sync,
+{
+    let status = query_eth_transfer_status(client, [hash.clone()]).await?;
+    if status.relayed.contains(hash) {
+        return Ok(Some(true));
+    }
+    if status.expired.contains(hash) {
+        return Ok(Some(false));
+    }
+    let backing = RPC
+        .shell()
+        .eth_bridge()
+        .transfer_to_ethereum_backing(client, hash)
+        .await
+        .map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::TransferToEthProgress(
+                e.to_string(),
+            ))
+        })?;
+    Ok(backing
+        .filter(|power| *power > FractionalVotingPower::TWO_THIRDS)
+        .map(|_| true))
+}
+
+/// Halt if no transfers were specified to construct a proof for.
+///
+/// Empty proofs are meaningless, and would only lead to confusing
+/// errors further down the line.
+fn ensure_transfers_specified(
+    io: &impl Io,
+    transfers: &[KeccakHash],
+) -> Result<(), Error> {
+    if transfers.is_empty() {
+        edisplay_line!(io, "No transfers specified");
+        return Err(Error::EthereumBridge(
+            EthereumBridgeError::NoTransfersSpecified,
+        ));
+    }
+    Ok(())
+}
+
+/// Remove the duplicate hashes from a list of transfers, keeping the
+/// first occurrence of each, and warn about the removed hashes.
+fn dedup_transfers(io: &impl Io, transfers: &mut Vec<KeccakHash>) {
+    let mut unique = HashSet::new();
+    let mut duplicates = vec![];
+    transfers.retain(|hash| {
+        let is_unique = unique.insert(hash.clone());
+        if !is_unique {
+            duplicates.push(hash.to_hex());
         }
+        is_unique
+    });
+    if !duplicates.is_empty() {
+        let warning = warning_label();
+        edisplay_line!(
+            io,
+            "{warning}: Removed {} duplicate transfer hash(es): {}",
+            duplicates.len(),
+            duplicates.join(", ")
+        );
+    }
+}
 
-        /// Test that a transfer is not recommended if it
-        /// is in the process of being relayed (has >0 voting
-        /// power behind it).
-        #[test]
-        fn test_generate_eligible_with_in_progress() {
-            test_generate_eligible_aux(|mut ctx| {
-                ctx.add_eth_to_conversion_table();
-                ctx.signed_pool.insert(
-                    ctx.pending.keccak256().to_string(),
-                    ctx.pending.clone(),
-                );
-                ctx.in_progress.insert(ctx.pending.keccak256().to_string());
-            });
+/// Warn about the `transfers` that have surpassed the security
+/// threshold in Namada, and have therefore likely been relayed
+/// already, failing if `fail_on_warning` is set or if the user
+/// declines to proceed.
+async fn check_security_threshold(
+    client: &(impl Client + Sync),
+    io: &(impl Io + MaybeSync),
+    transfers: &[KeccakHash],
+    fail_on_warning: bool,
+) -> Result<(), Error> {
+    // NB: the security threshold is checked against a consistent
+    // view of the progress of every transfer
+    let warnings: Vec<_> = query_transfer_to_ethereum_snapshot(client)
+        .await?
+        .into_iter()
+        .filter_map(|(transfer, voting_power)| {
+            (voting_power >= FractionalVotingPower::ONE_THIRD
+                && transfers.contains(&transfer.keccak256()))
+            .then_some(transfer)
+        })
+        .collect();
+
+    if !warnings.is_empty() {
+        let warning = warning_label();
+        display_line!(
+            io,
+            "{warning}: The following transfers have surpassed the security \
+             threshold in Namada, therefore have likely been relayed to \
+             Ethereum, but do not yet have a quorum of validator signatures \
+             behind them in Namada; thus they are still in the Bridge pool:",
+        );
+        for transfer in &warnings {
+            display_line!(io, "{transfer}");
+        }
+        if fail_on_warning {
+            let hashes = warnings
+                .iter()
+                .map(|transfer| transfer.keccak256().to_hex())
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(Error::EthereumBridge(
+                EthereumBridgeError::TransfersPastSecurityThreshold(hashes),
+            ));
+        }
+        let proceed = prompt_yes_no(io, "\nDo you wish to proceed?")
+            .await
+            .map_err(|e| {
+                Error::Other(echo_error!(
+                    io,
+                    "Encountered error reading from STDIN: {e:?}"
+                ))
+            })?;
+        if !proceed {
+            return Err(Error::Other(
+                "Aborted generating Bridge pool proof".into(),
+            ));
         }
+    }
+    Ok(())
+}
 
-        /// Test that a transfer is not recommended if its gas
-        /// token is not found in the conversion table.
-        #[test]
-        fn test_generate_eligible_no_gas_token() {
-            test_generate_eligible_aux(|ctx| {
-                ctx.signed_pool.insert(
-                    ctx.pending.keccak256().to_string(),
-                    ctx.pending.clone(),
-                );
-            });
+/// Internal method to construct a proof that a set of transfers are in the
+/// bridge pool.
+///
+/// The proof is constructed against the root signed as of `target_root`,
+/// or the latest signed root if unset.
+///
+/// If `fail_on_warning` is set, the proof is not generated (and the user
+/// is not prompted) when any of the requested transfers has already
+/// surpassed the security threshold in Namada.
+async fn construct_bridge_pool_proof(
+    client: &(impl Client + Sync),
+    io: &(impl Io + MaybeSync),
+    args: GenBridgePoolProofReq<'_, '_>,
+    target_root: Option<BlockHeight>,
+    fail_on_warning: bool,
+) -> Result<GenVersionedBridgePoolProofRsp, Error> {
+    check_security_threshold(client, io, &args.transfers, fail_on_warning)
+        .await?;
+
+    let data = args.serialize_to_vec();
+    let eth_bridge = RPC.shell().eth_bridge();
+    let response = match target_root {
+        Some(root_height) => {
+            eth_bridge
+                .generate_bridge_pool_proof_at_root(
+                    client,
+                    Some(data),
+                    None,
+                    false,
+                    &root_height,
+                )
+                .await
+        }
+        None => {
+            eth_bridge
+                .generate_versioned_bridge_pool_proof(
+                    client,
+                    Some(data),
+                    None,
+                    false,
+                )
+                .await
         }
+    }
+    .map_err(|e| {
+        edisplay_line!(io, "Encountered error constructing proof:\n{:?}", e);
+        Error::EthereumBridge(EthereumBridgeError::GenBridgePoolProof(
+            e.to_string(),
+        ))
+    })?;
 
-        #[test]
-        fn test_signature_count() {
-            let voting_powers = VotingPowersMap::from([
-                (address_book(1), Amount::from(5)),
-                (address_book(2), Amount::from(1)),
-                (address_book(3), Amount::from(1)),
-            ]);
-            let signatures = HashMap::from([
-                (address_book(1), 0),
-                (address_book(2), 0),
-                (address_book(3), 0),
-            ]);
-            let checks = signature_checks(voting_powers, &signatures);
-            assert_eq!(checks, uint::ONE)
+    Ok(response.data)
+}
+
+/// The encoding of the ABI encoded arguments of a Bridge pool proof,
+/// when these are written out by [`construct_proof`].
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum ProofEncoding {
+    /// The arguments are left uncompressed.
+    #[default]
+    Raw,
+    /// The arguments are compressed with gzip.
+    Gzip,
+}
+
+impl ProofEncoding {
+    /// Encode the given ABI encoded arguments.
+    pub fn encode(self, abi_encoded_args: Vec<u8>) -> Result<Vec<u8>, Error> {
+        match self {
+            Self::Raw => Ok(abi_encoded_args),
+            Self::Gzip => {
+                let mut encoder =
+                    GzEncoder::new(Vec::new(), Compression::best());
+                encoder
+                    .write_all(&abi_encoded_args)
+                    .and_then(|_| encoder.finish())
+                    .map_err(|e| {
+                        Error::Encode(EncodingError::Encode(e.to_string()))
+                    })
+            }
         }
+    }
 
-        #[test]
-        fn test_signature_count_with_skips() {
-            let voting_powers = VotingPowersMap::from([
-                (address_book(1), Amount::from(5)),
-                (address_book(2), Amount::from(5)),
-                (address_book(3), Amount::from(1)),
-                (address_book(4), Amount::from(1)),
-            ]);
-            let signatures = HashMap::from([
-                (address_book(1), 0),
-                (address_book(3), 0),
-                (address_book(4), 0),
-            ]);
-            let checks = signature_checks(voting_powers, &signatures);
-            assert_eq!(checks, Uint::from_u64(3))
+    /// Recover the ABI encoded arguments from their encoded form.
+    pub fn decode(self, encoded: Vec<u8>) -> Result<Vec<u8>, Error> {
+        match self {
+            Self::Raw => Ok(encoded),
+            Self::Gzip => {
+                let mut abi_encoded_args = vec![];
+                GzDecoder::new(encoded.as_slice())
+                    .read_to_end(&mut abi_encoded_args)
+                    .map_err(|e| {
+                        Error::Encode(EncodingError::Decoding(e.to_string()))
+                    })?;
+                Ok(abi_encoded_args)
+            }
         }
+    }
+}
 
-        #[test]
-        fn test_only_profitable() {
-            let profitable = vec![transfer(100_000); 17];
-            let hash = profitable[0].keccak256().to_string();
-            let expected = vec![hash; 17];
-            let recommendation = generate_recommendations(
-                &StdIo,
-                process_transfers(profitable),
-                &Default::default(),
-                Uint::from_u64(800_000),
-                uint::MAX_VALUE,
+/// A response from construction a bridge pool proof.
+#[derive(Serialize)]
+struct BridgePoolProofResponse {
+    /// Version of the encoding of the proof.
+    version: u8,
+    #[serde(serialize_with = "serialize_hashes")]
+    hashes: Vec<KeccakHash>,
+    relayer_address: Address,
+    total_fees: HashMap<Address, Amount>,
+    /// The total fees, formatted with each token's decimal places.
+    total_fees_denominated: HashMap<Address, DenominatedAmount>,
+    /// The total fees expressed in gwei, if a conversion
+    /// table was provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_fees_gwei: Option<Uint>,
+    /// The economics of each transfer in the proof, if requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transfers: Option<Vec<TransferEconomics>>,
+    /// The encoding of `abi_encoded_args`.
+    abi_encoded_args_encoding: ProofEncoding,
+    #[serde(serialize_with = "serialize_hex_bytes")]
+    abi_encoded_args: Vec<u8>,
+}
+
+/// The contribution of a single transfer to the economics of
+/// relaying a Bridge pool proof.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct TransferEconomics {
+    /// The hash of the transfer.
+    hash: String,
+    /// The Ethereum gas added to the relay by the transfer.
+    marginal_gas: Uint,
+    /// The token the fees of the transfer are paid in.
+    fee_token: Address,
+    /// The fees paid by the transfer.
+    fee: DenominatedAmount,
+    /// The fees paid by the transfer expressed in gwei, if a
+    /// conversion table was provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fee_gwei: Option<Uint>,
+}
+
+impl TransferEconomics {
+    /// Break down the economics of relaying a `transfer`.
+    fn new(
+        transfer: &PendingTransfer,
+        denom: Denomination,
+        conversion_table: Option<
+            &HashMap<Address, args::BpConversionTableEntry>,
+        >,
+    ) -> Result<Self, Error> {
+        let GasFee { token, amount, .. } = &transfer.gas_fee;
+        let fee_gwei = conversion_table
+            .map(|conversion_table| {
+                recommendations::total_fees_in_gwei(
+                    &HashMap::from([(token.clone(), *amount)]),
+                    conversion_table,
+                )
+            })
+            .transpose()?;
+        Ok(Self {
+            hash: transfer.keccak256().to_hex(),
+            marginal_gas: recommendations::estimated_relay_gas(1, Uint::zero()),
+            fee_token: token.clone(),
+            fee: DenominatedAmount::new(*amount, denom),
+            fee_gwei,
+        })
+    }
+}
+
+/// Serialize a list of [`KeccakHash`] values as `0x` prefixed,
+/// lowercase hex strings.
+fn serialize_hashes<S>(
+    hashes: &[KeccakHash],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_seq(hashes.iter().map(KeccakHash::to_hex))
+}
+
+/// Serialize bytes as a `0x` prefixed, lowercase hex string.
+fn serialize_hex_bytes<S>(
+    bytes: &[u8],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(&format!("0x{}", HEXLOWER.encode(bytes)))
+}
+
+/// Deserialize bytes serialized by [`serialize_hex_bytes`], or
+/// as a list of numbers, as written by earlier versions of
+/// [`construct_proof`].
+fn deserialize_hex_bytes<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum HexOrBytes {
+        Hex(String),
+        Bytes(Vec<u8>),
+    }
+
+    match HexOrBytes::deserialize(deserializer)? {
+        HexOrBytes::Hex(hex) => HEXLOWER_PERMISSIVE
+            .decode(hex.strip_prefix("0x").unwrap_or(&hex).as_bytes())
+            .map_err(serde::de::Error::custom),
+        HexOrBytes::Bytes(bytes) => Ok(bytes),
+    }
+}
+
+/// The subset of a [`BridgePoolProofResponse`] required to relay
+/// a proof, which has been read back from a file.
+#[derive(Deserialize)]
+struct StoredBridgePoolProof {
+    /// Version of the encoding of the proof.
+    version: u8,
+    /// The hashes of the transfers covered by the proof.
+    hashes: Vec<KeccakHash>,
+    /// The encoding of `abi_encoded_args`. Proofs written
+    /// before compression was supported are uncompressed.
+    #[serde(default)]
+    abi_encoded_args_encoding: ProofEncoding,
+    #[serde(deserialize_with = "deserialize_hex_bytes")]
+    abi_encoded_args: Vec<u8>,
+}
+
+impl StoredBridgePoolProof {
+    /// Read a proof output by [`construct_proof`] from the given
+    /// path, and decompress its ABI encoded arguments.
+    fn load(path: &Path) -> Result<Self, Error> {
+        let contents = std::fs::read(path).map_err(|e| {
+            Error::Other(format!(
+                "Failed to read Bridge pool proof from {}: {e}",
+                path.display()
+            ))
+        })?;
+        let mut proof: Self = serde_json::from_slice(&contents)
+            .map_err(|e| Error::Encode(EncodingError::Serde(e.to_string())))?;
+        proof.abi_encoded_args = proof
+            .abi_encoded_args_encoding
+            .decode(std::mem::take(&mut proof.abi_encoded_args))?;
+        proof.abi_encoded_args_encoding = ProofEncoding::Raw;
+        Ok(proof)
+    }
+
+    /// Check that the proof covers exactly the requested
+    /// `transfers`, or adopt the transfers of the proof if
+    /// none were requested.
+    fn check_transfers(
+        &self,
+        transfers: &mut Vec<KeccakHash>,
+    ) -> Result<(), Error> {
+        if transfers.is_empty() {
+            *transfers = self.hashes.clone();
+            return Ok(());
+        }
+        let requested: HashSet<_> = transfers.iter().collect();
+        let proven: HashSet<_> = self.hashes.iter().collect();
+        if requested == proven {
+            return Ok(());
+        }
+        let missing = requested
+            .difference(&proven)
+            .map(|hash| hash.to_hex())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let extra = proven
+            .difference(&requested)
+            .map(|hash| hash.to_hex())
+            .collect::<Vec<_>>()
+            .join(", ");
+        Err(Error::EthereumBridge(
+            EthereumBridgeError::StoredProofMismatch(format!(
+                "not proven: [{missing}], not requested: [{extra}]"
+            )),
+        ))
+    }
+}
+
+/// Construct a merkle proof of a batch of transfers in
+/// the bridge pool and return it to the user (as opposed
+/// to relaying it to ethereum).
+pub async fn construct_proof(
+    client: &(impl Client + Sync),
+    io: &(impl Io + MaybeSync),
+    mut args: args::BridgePoolProof,
+    progress: Option<&ProgressSender>,
+) -> Result<(), Error> {
+    dedup_transfers(io, &mut args.transfers);
+    ensure_transfers_specified(io, &args.transfers)?;
+    report_progress(progress, ProgressEvent::GeneratingProof);
+    let proof_rsp = construct_bridge_pool_proof(
+        client,
+        io,
+        GenBridgePoolProofReq {
+            transfers: args.transfers.as_slice().into(),
+            relayer: Cow::Borrowed(&args.relayer),
+            with_appendix: true,
+        },
+        args.target_root,
+        args.fail_on_warning,
+    )
+    .await?;
+    let pending_transfers: Vec<_> = if args.transfer_breakdown {
+        proof_rsp.proof.clone().pending_transfers().collect()
+    } else {
+        vec![]
+    };
+    let GenVersionedBridgePoolProofRsp {
+        version,
+        proof:
+            GenBridgePoolProofRsp {
+                abi_encoded_args,
+                appendices,
+            },
+    } = proof_rsp;
+    report_progress(
+        progress,
+        ProgressEvent::ProofGenerated {
+            bytes: abi_encoded_args.len(),
+        },
+    );
+    let total_fees: HashMap<Address, Amount> = appendices
+        .map(|appendices| {
+            appendices.into_iter().try_fold(
+                HashMap::new(),
+                |mut total_fees, app| {
+                    let GasFee { token, amount, .. } = app.gas_fee.into_owned();
+                    add_fees(&mut total_fees, token, &amount)?;
+                    Ok::<_, Error>(total_fees)
+                },
+            )
+        })
+        .transpose()?
+        .unwrap_or_default();
+    let total_fees_gwei = args
+        .conversion_table
+        .as_ref()
+        .map(|conversion_table| {
+            recommendations::total_fees_in_gwei(&total_fees, conversion_table)
+        })
+        .transpose()?;
+    let denoms =
+        query_denominations(client, total_fees.keys().cloned()).await?;
+    let transfers = args
+        .transfer_breakdown
+        .then(|| {
+            pending_transfers
+                .iter()
+                .map(|transfer| {
+                    TransferEconomics::new(
+                        transfer,
+                        denoms[&transfer.gas_fee.token],
+                        args.conversion_table.as_ref(),
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?;
+    let abi_encoded_args_encoding = if args.compress_proof {
+        ProofEncoding::Gzip
+    } else {
+        ProofEncoding::Raw
+    };
+    let abi_encoded_args =
+        abi_encoded_args_encoding.encode(abi_encoded_args)?;
+    let resp = BridgePoolProofResponse {
+        version,
+        hashes: args.transfers,
+        relayer_address: args.relayer,
+        total_fees_denominated: total_fees
+            .iter()
+            .map(|(token, &amount)| {
+                (token.clone(), DenominatedAmount::new(amount, denoms[token]))
+            })
+            .collect(),
+        total_fees_gwei,
+        total_fees,
+        transfers,
+        abi_encoded_args_encoding,
+        abi_encoded_args,
+    };
+    display_line!(
+        io,
+        "{}",
+        serde_json::to_string_pretty(&resp)
+            .map_err(|e| EncodingError::Serde(e.to_string()))?
+    );
+    Ok(())
+}
+
+/// Add `amount` to the fees paid in `token`, in `total_fees`.
+fn add_fees(
+    total_fees: &mut HashMap<Address, Amount>,
+    token: Address,
+    amount: &Amount,
+) -> Result<(), Error> {
+    total_fees
+        .entry(token)
+        .or_insert_with(Amount::zero)
+        .receive(amount)
+        .map_err(|e| Error::Other(e.to_string()))
+}
+
+/// A batch of transfers relayed to Ethereum, as recorded in a
+/// [`RelayerFeeLedger`].
+///
+/// Batches can be read back from the json payload output by
+/// [`construct_proof`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelayedBatch {
+    /// The hashes of the relayed transfers.
+    #[serde(serialize_with = "serialize_hashes")]
+    pub hashes: Vec<KeccakHash>,
+    /// The address of the relayer compensated for the batch.
+    pub relayer_address: Address,
+    /// The total fees paid by the transfers of the batch, per token.
+    pub total_fees: HashMap<Address, Amount>,
+    /// The time at which the batch was recorded in the ledger.
+    #[serde(default)]
+    pub recorded_at: Option<DateTimeUtc>,
+}
+
+impl RelayedBatch {
+    /// Read a batch from a proof output by [`construct_proof`].
+    fn load(path: &Path) -> Result<Self, Error> {
+        let contents = std::fs::read(path).map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::FeeLedger(format!(
+                "{}: {e}",
+                path.display()
+            )))
+        })?;
+        serde_json::from_slice(&contents)
+            .map_err(|e| Error::Encode(EncodingError::Serde(e.to_string())))
+    }
+}
+
+/// Bookkeeping of the fees earned by relayers from the batches of
+/// transfers they relayed to Ethereum, persisted to a ledger file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelayerFeeLedger {
+    /// The recorded batches, from oldest to newest.
+    pub batches: Vec<RelayedBatch>,
+}
+
+impl RelayerFeeLedger {
+    /// Read a ledger from the given path, or start an empty
+    /// ledger if none has been written there yet.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = std::fs::read(path).map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::FeeLedger(format!(
+                "{}: {e}",
+                path.display()
+            )))
+        })?;
+        serde_json::from_slice(&contents)
+            .map_err(|e| Error::Encode(EncodingError::Serde(e.to_string())))
+    }
+
+    /// Record a relayed batch, unless a batch of the same
+    /// transfers has already been recorded.
+    ///
+    /// Returns whether the batch was recorded.
+    pub fn record(&mut self, batch: RelayedBatch) -> bool {
+        let hashes: HashSet<_> = batch.hashes.iter().collect();
+        let already_recorded = self.batches.iter().any(|recorded| {
+            recorded.hashes.iter().collect::<HashSet<_>>() == hashes
+        });
+        if !already_recorded {
+            self.batches.push(batch);
+        }
+        !already_recorded
+    }
+
+    /// Aggregate the fees earned by each relayer, per token, from
+    /// the batches recorded since the given time, or from all the
+    /// recorded batches if no time is given.
+    pub fn fees_per_relayer(
+        &self,
+        since: Option<DateTimeUtc>,
+    ) -> Result<HashMap<Address, HashMap<Address, Amount>>, Error> {
+        let mut fees: HashMap<Address, HashMap<Address, Amount>> =
+            HashMap::new();
+        for batch in self.batches_since(since) {
+            let relayer_fees =
+                fees.entry(batch.relayer_address.clone()).or_default();
+            for (token, amount) in &batch.total_fees {
+                add_fees(relayer_fees, token.clone(), amount)?;
+            }
+        }
+        Ok(fees)
+    }
+
+    /// Aggregate the fees earned by all relayers, per token, from
+    /// the batches recorded since the given time, or from all the
+    /// recorded batches if no time is given.
+    pub fn fees_per_token(
+        &self,
+        since: Option<DateTimeUtc>,
+    ) -> Result<HashMap<Address, Amount>, Error> {
+        let mut fees = HashMap::new();
+        for batch in self.batches_since(since) {
+            for (token, amount) in &batch.total_fees {
+                add_fees(&mut fees, token.clone(), amount)?;
+            }
+        }
+        Ok(fees)
+    }
+
+    /// Iterate over the batches recorded since the given time.
+    ///
+    /// Batches without a recording time are only included if
+    /// no time is given.
+    fn batches_since(
+        &self,
+        since: Option<DateTimeUtc>,
+    ) -> impl Iterator<Item = &RelayedBatch> {
+        self.batches.iter().filter(move |batch| match since {
+            Some(since) => batch
+                .recorded_at
+                .map(|recorded_at| recorded_at >= since)
+                .unwrap_or(false),
+            None => true,
+        })
+    }
+}
+
+/// Check that the batch of the proof stored at `proof_file` has
+/// been relayed, i.e. that the Bridge contract has moved past the
+/// nonce of the batch.
+async fn ensure_batch_relayed<E>(
+    client: &(impl Client + Sync),
+    io: &impl Io,
+    eth_client: &Arc<E>,
+    proof_file: &Path,
+) -> Result<(), Error>
+where
+    E: Middleware,
+{
+    let proof = StoredBridgePoolProof::load(proof_file)?;
+    let (_, _, bp_proof) = decode_relay_args(
+        io,
+        proof.version,
+        &proof.abi_encoded_args,
+        ProofRequest {
+            transfers: proof.hashes.len(),
+            relayer: None,
+        },
+    )?;
+    let bridge = read_bridge_contract(client, io, eth_client).await?;
+    let contract_nonce = query_contract_bp_nonce(&bridge, None).await?;
+    if contract_nonce > bp_proof.batch_nonce {
+        return Ok(());
+    }
+    Err(Error::EthereumBridge(EthereumBridgeError::FeeLedger(
+        echo_error!(
+            io,
+            "The Bridge pool batch {} of {} has not been relayed yet, since \
+             the Bridge contract is still at nonce {contract_nonce}.",
+            bp_proof.batch_nonce,
+            proof_file.display()
+        ),
+    )))
+}
+
+/// Record the batch of a proof output by [`construct_proof`] in a
+/// relayer fee ledger, if requested, and print out the fees earned
+/// by each relayer, per token.
+///
+/// A batch is only recorded once the Bridge contract on Ethereum,
+/// queried through `eth_client`, has moved past its nonce.
+pub async fn relayer_fee_ledger<E>(
+    client: &(impl Client + Sync),
+    io: &impl Io,
+    eth_client: Option<Arc<E>>,
+    args: args::RelayerFeeLedger,
+) -> Result<RelayerFeeLedger, Error>
+where
+    E: Middleware,
+{
+    let mut ledger = RelayerFeeLedger::load(&args.ledger_file)?;
+    if let Some(proof_file) = args.record.as_deref() {
+        let Some(eth_client) = eth_client.as_ref() else {
+            return Err(Error::EthereumBridge(EthereumBridgeError::FeeLedger(
+                "An Ethereum RPC endpoint is required to check that the \
+                 recorded batch has been relayed"
+                    .into(),
+            )));
+        };
+        ensure_batch_relayed(client, io, eth_client, proof_file).await?;
+        let mut batch = RelayedBatch::load(proof_file)?;
+        batch.recorded_at = Some(DateTimeUtc::now());
+        if ledger.record(batch) {
+            write_query_output(io, Some(&args.ledger_file), &ledger)?;
+            display_line!(
+                io,
+                "Recorded the relayed batch in {}.",
+                args.ledger_file.display()
+            );
+        } else {
+            display_line!(
+                io,
+                "The relayed batch has already been recorded in {}.",
+                args.ledger_file.display()
+            );
+        }
+    }
+
+    let fees = ledger.fees_per_relayer(args.since)?;
+    if fees.is_empty() {
+        display_line!(io, "No relayed batches have been recorded.");
+        return Ok(ledger);
+    }
+    let denoms = query_denominations(
+        client,
+        fees.values().flat_map(|fees| fees.keys().cloned()),
+    )
+    .await?;
+    for (relayer, relayer_fees) in &fees {
+        display_line!(io, "Fees earned by {relayer}:");
+        for (token, amount) in relayer_fees {
+            display_line!(
+                io,
+                "  {token}: {}",
+                DenominatedAmount::new(*amount, denoms[token])
+            );
+        }
+    }
+    Ok(ledger)
+}
+
+/// Relay a proof of a batch of transfers in the Bridge pool to the
+/// Bridge contract on Ethereum.
+///
+/// Validator set updates are relayed by the sibling `validator_set`
+/// module, which shares the gas parameters and the confirmation
+/// handling of this function.
+///
+/// The relay can be canceled at any point through the
+/// [`futures::future::AbortHandle`] paired with `abort`, in which
+/// case [`EthereumBridgeError::RelayAborted`] is returned. A relay
+/// transaction sent before it was aborted may still be mined, and
+/// can be tracked through the state file of the relay, if any.
+pub async fn relay_bridge_pool_proof<E>(
+    eth_client: Arc<E>,
+    client: &(impl Client + Sync),
+    io: &(impl Io + MaybeSync),
+    args: args::RelayBridgePoolProof,
+    progress: Option<&ProgressSender>,
+    abort: Option<AbortRegistration>,
+) -> Result<(), Error>
+where
+    E: Middleware,
+    E::Error: std::fmt::Debug + std::fmt::Display,
+{
+    abortable(
+        io,
+        relay_bridge_pool_proof_aux(eth_client, client, io, args, progress),
+        abort,
+    )
+    .await
+}
+
+/// Drive `operation` to completion, unless it is aborted through
+/// the handle paired with `abort`.
+async fn abortable<T>(
+    io: &impl Io,
+    operation: impl Future<Output = Result<T, Error>>,
+    abort: Option<AbortRegistration>,
+) -> Result<T, Error> {
+    let Some(abort) = abort else {
+        return operation.await;
+    };
+    match Abortable::new(operation, abort).await {
+        Ok(result) => result,
+        Err(_aborted) => {
+            edisplay_line!(io, "The relay was aborted.");
+            Err(Error::EthereumBridge(EthereumBridgeError::RelayAborted))
+        }
+    }
+}
+
+/// Relay a proof of a batch of transfers in the Bridge pool, without
+/// any means of aborting the relay.
+async fn relay_bridge_pool_proof_aux<E>(
+    eth_client: Arc<E>,
+    client: &(impl Client + Sync),
+    io: &(impl Io + MaybeSync),
+    mut args: args::RelayBridgePoolProof,
+    progress: Option<&ProgressSender>,
+) -> Result<(), Error>
+where
+    E: Middleware,
+    E::Error: std::fmt::Debug + std::fmt::Display,
+{
+    // NB: the transfers of stored proofs default to those of the
+    // proof itself
+    dedup_transfers(io, &mut args.transfers);
+    let stored_proof = match args.proof_file.as_deref() {
+        Some(proof_file) => {
+            let proof = StoredBridgePoolProof::load(proof_file)?;
+            proof.check_transfers(&mut args.transfers)?;
+            Some(proof)
+        }
+        None => {
+            ensure_transfers_specified(io, &args.transfers)?;
+            None
+        }
+    };
+    let confirmations = validate_relay_confirmations(io, args.confirmations);
+    let _signal_receiver = args.safe_mode.then(install_shutdown_signal);
+    let request_timeout = match args.request_timeout {
+        Some(timeout) => Some(timeout),
+        None => eth_request_timeout_from_env(io)?,
+    };
+
+    if args.sync {
+        block_on_eth_sync(
+            &*eth_client,
+            io,
+            BlockOnEthSync {
+                deadline: Instant::now() + Duration::from_secs(60),
+                delta_sleep: Duration::from_secs(1),
+            },
+        )
+        .await?;
+    } else {
+        eth_sync_or_exit(&*eth_client, io).await?;
+    }
+
+    if let Some(state_file) = args.state_file.as_deref() {
+        let relay_confirmed = resume_interrupted_relay(
+            &*eth_client,
+            io,
+            state_file,
+            &args.transfers,
+            confirmations,
+        )
+        .await?;
+        if relay_confirmed {
+            return Ok(());
+        }
+    }
+
+    if let Some(ceiling_gwei) = args.max_gas_price_gwei {
+        check_gas_price_ceiling(
+            &*eth_client,
+            io,
+            request_timeout,
+            ceiling_gwei,
+        )
+        .await?;
+    }
+
+    let (version, abi_encoded_args) = match stored_proof {
+        Some(proof) => {
+            check_security_threshold(
+                client,
+                io,
+                &args.transfers,
+                args.fail_on_warning,
+            )
+            .await?;
+            (proof.version, proof.abi_encoded_args)
+        }
+        None => {
+            report_progress(progress, ProgressEvent::GeneratingProof);
+            let GenVersionedBridgePoolProofRsp {
+                version,
+                proof:
+                    GenBridgePoolProofRsp {
+                        abi_encoded_args, ..
+                    },
+            } = construct_bridge_pool_proof(
+                client,
+                io,
+                GenBridgePoolProofReq {
+                    transfers: args.transfers.as_slice().into(),
+                    relayer: Cow::Borrowed(&args.relayer),
+                    with_appendix: false,
+                },
+                args.target_root,
+                args.fail_on_warning,
+            )
+            .await?;
+            report_progress(
+                progress,
+                ProgressEvent::ProofGenerated {
+                    bytes: abi_encoded_args.len(),
+                },
+            );
+            (version, abi_encoded_args)
+        }
+    };
+    let bridge = read_bridge_contract(client, io, &eth_client).await?;
+    let (validator_set, signatures, bp_proof) = decode_relay_args(
+        io,
+        version,
+        &abi_encoded_args,
+        ProofRequest {
+            transfers: args.transfers.len(),
+            relayer: args.proof_file.is_none().then_some(&args.relayer),
+        },
+    )?;
+
+    let contract_nonce =
+        query_contract_bp_nonce(&bridge, request_timeout).await?;
+
+    check_bp_nonce(io, bp_proof.batch_nonce, contract_nonce)?;
+
+    let batch_nonce = bp_proof.batch_nonce;
+    let relayed_transfers = bp_proof.transfers.len();
+    let mut relay_op =
+        bridge.transfer_to_erc(validator_set, signatures, bp_proof);
+
+    let calldata_size = relay_op
+        .calldata()
+        .map(|data| data.len() as u64)
+        .unwrap_or(0);
+    let max_calldata_size = args
+        .max_calldata_size
+        .unwrap_or(DEFAULT_MAX_RELAY_CALLDATA_SIZE);
+    if calldata_size > max_calldata_size {
+        let error = error_label();
+        display_line!(
+            io,
+            "{error}: The relay calldata of {calldata_size} bytes exceeds the \
+             maximum of {max_calldata_size} bytes."
+        );
+        let batches = split_transfer_batch(
+            &args.transfers,
+            calldata_size,
+            max_calldata_size,
+        );
+        if batches.len() > 1 {
+            display_line!(
+                io,
+                "Consider relaying the transfers in the following batches:"
+            );
+            for batch in batches {
+                let hashes: Vec<_> =
+                    batch.iter().map(KeccakHash::to_hex).collect();
+                display_line!(io, "  {}", hashes.join(" "));
+            }
+        }
+        return Err(Error::EthereumBridge(
+            EthereumBridgeError::ProofTooLarge {
+                size: calldata_size,
+                max_size: max_calldata_size,
+            },
+        ));
+    }
+    set_relay_tx_params(
+        &mut relay_op.tx,
+        args.gas,
+        args.gas_price,
+        args.eth_addr,
+    );
+    if args.print_calldata {
+        display_line!(io, "{}", RelayCalldata::new(&relay_op.tx));
+        return Ok(());
+    }
+
+    let submission = RelaySubmission {
+        batch_nonce,
+        transfers: &args.transfers,
+        confirmations,
+        request_timeout,
+        access_list: args.access_list,
+        gas_price_bump: args.gas_price_bump,
+        max_gas_price: args.max_gas_price,
+        private_relay_url: args.private_relay_url.as_deref(),
+        poll_interval: args.poll_interval,
+        rebroadcast_after: args.rebroadcast_after,
+        max_rebroadcasts: args.max_rebroadcasts,
+        confirmation_timeout: args.confirmation_timeout,
+        state_file: args.state_file.as_deref(),
+        audit_log: args.audit_log.as_deref(),
+    };
+    let relay_tx =
+        prepare_relay_tx(&*eth_client, io, relay_op.tx, &submission).await?;
+
+    // NB: the signed root may have moved on while the relay
+    // transaction was being prepared
+    ensure_signed_root_nonce(client, io, batch_nonce).await?;
+
+    let (_, transf_result) =
+        submit_relay_tx(&*eth_client, io, relay_tx, &submission, progress)
+            .await?;
+
+    let actual_gas = transf_result
+        .as_ref()
+        .and_then(|receipt| receipt.gas_used)
+        .and_then(|gas_used| u64::try_from(gas_used).ok());
+    if let Some(report) = args
+        .estimated_gas
+        .zip(actual_gas)
+        .map(|(estimated, actual)| RelayGasReport::new(estimated, actual))
+    {
+        tracing::info!(
+            batch_nonce = %batch_nonce,
+            relayed_transfers,
+            estimated_gas = report.estimated,
+            actual_gas = report.actual,
+            delta = report.delta(),
+            "Relay gas cost report"
+        );
+        display_line!(io, "{report}");
+    }
+    Ok(())
+}
+
+/// The parameters of the submission of a relay transaction to
+/// Ethereum, and of the wait for its confirmation, shared by the
+/// relay of freshly generated proofs and of [`RelayBundle`]s.
+struct RelaySubmission<'a> {
+    /// The nonce of the relayed Bridge pool batch.
+    batch_nonce: U256,
+    /// The hashes of the relayed transfers.
+    transfers: &'a [KeccakHash],
+    /// The number of confirmations to wait for.
+    confirmations: u64,
+    /// The maximum amount of time to wait on each request to the
+    /// Ethereum node.
+    request_timeout: Option<Duration>,
+    /// Attach an EIP-2930 access list to the relay transaction.
+    access_list: bool,
+    /// The percentage by which the gas price is bumped, whenever
+    /// the relay transaction is rejected as underpriced.
+    gas_price_bump: Option<u64>,
+    /// The maximum gas price the relay transaction may be
+    /// escalated to.
+    max_gas_price: Option<u64>,
+    /// The private relay to submit the relay transaction to.
+    private_relay_url: Option<&'a str>,
+    /// The interval between polls of the Ethereum node.
+    poll_interval: Option<Duration>,
+    /// The amount of time after which a vanished relay
+    /// transaction is re-broadcast.
+    rebroadcast_after: Option<Duration>,
+    /// The maximum number of re-broadcasts.
+    max_rebroadcasts: Option<u64>,
+    /// The maximum amount of time to wait for confirmations.
+    confirmation_timeout: Option<Duration>,
+    /// The file the state of the relay is persisted to.
+    state_file: Option<&'a Path>,
+    /// The file relay attempts are audited to.
+    audit_log: Option<&'a Path>,
+}
+
+/// Attach an access list to the `relay_tx`, and fill it in if its
+/// nonce and gas price must remain fixed across resubmissions.
+async fn prepare_relay_tx<E>(
+    eth_client: &E,
+    io: &impl Io,
+    mut relay_tx: TypedTransaction,
+    submission: &RelaySubmission<'_>,
+) -> Result<TypedTransaction, Error>
+where
+    E: Middleware,
+    E::Error: std::fmt::Display,
+{
+    if submission.access_list {
+        relay_tx = with_access_list(
+            eth_client,
+            io,
+            submission.request_timeout,
+            relay_tx,
+        )
+        .await?;
+    }
+    if submission.gas_price_bump.is_some()
+        || submission.private_relay_url.is_some()
+        || submission.rebroadcast_after.is_some()
+    {
+        // fix the nonce and gas price of the relay tx, such that
+        // resubmissions with escalated gas prices replace it, so
+        // it can be signed locally for private relays, and so its
+        // re-broadcasts yield the same signed tx
+        with_eth_request_timeout(
+            submission.request_timeout,
+            "fill in the relay transaction",
+            eth_client.fill_transaction(&mut relay_tx, None).map(|result| {
+                result.map_err(|e| {
+                    Error::EthereumBridge(EthereumBridgeError::Rpc(
+                        e.to_string(),
+                    ))
+                })
+            }),
+        )
+        .await?;
+    }
+    Ok(relay_tx)
+}
+
+/// Send the `relay_tx` to Ethereum, and wait for it to be
+/// confirmed, auditing each attempt.
+///
+/// Returns the hash of the relay transaction, along with its
+/// receipt, unless it was not confirmed in time.
+async fn submit_relay_tx<E>(
+    eth_client: &E,
+    io: &impl Io,
+    mut relay_tx: TypedTransaction,
+    submission: &RelaySubmission<'_>,
+    progress: Option<&ProgressSender>,
+) -> Result<(H256, Option<TransactionReceipt>), Error>
+where
+    E: Middleware,
+    E::Error: std::fmt::Display,
+{
+    let RelaySubmission {
+        batch_nonce,
+        request_timeout,
+        ..
+    } = *submission;
+    let record_attempt = |relay_tx: &TypedTransaction,
+                          tx_hash: Option<H256>,
+                          outcome: RelayOutcome,
+                          gas_used: Option<u64>| {
+        let Some(audit_log) = submission.audit_log else {
+            return;
+        };
+        let record = RelayAuditRecord {
+            timestamp: DateTimeUtc::now(),
+            batch_nonce: batch_nonce.to_string(),
+            transfers: submission.transfers.to_vec(),
+            gas_limit: relay_tx.gas().map(|gas| gas.to_string()),
+            gas_price: relay_tx.gas_price().map(|price| price.to_string()),
+            tx_hash,
+            outcome,
+            gas_used,
+        };
+        // NB: failing to audit a relay must not interrupt it
+        if let Err(err) = record.append(audit_log) {
+            edisplay_line!(io, "{err}");
+        }
+    };
+
+    report_progress(progress, ProgressEvent::Relaying);
+    let mut escalations = 0;
+    let mut pending_tx = loop {
+        let result = send_relay_transaction(
+            eth_client,
+            request_timeout,
+            submission.private_relay_url,
+            &relay_tx,
+        )
+        .await;
+        let err = match result {
+            Ok(pending_tx) => break pending_tx,
+            Err(err) => err,
+        };
+        record_attempt(
+            &relay_tx,
+            None,
+            RelayOutcome::Failed {
+                error: err.to_string(),
+            },
+            None,
+        );
+        let escalated_gas_price = submission
+            .gas_price_bump
+            .filter(|_| {
+                is_underpriced_error(&err)
+                    && escalations < MAX_GAS_PRICE_ESCALATIONS
+            })
+            .zip(relay_tx.gas_price())
+            .map(|(bump, gas_price)| escalate_gas_price(gas_price, bump))
+            .filter(|gas_price| {
+                submission
+                    .max_gas_price
+                    .map(|max_gas_price| *gas_price <= max_gas_price.into())
+                    .unwrap_or(true)
+            });
+        let Some(gas_price) = escalated_gas_price else {
+            return Err(err);
+        };
+        escalations += 1;
+        display_line!(
+            io,
+            "The relay transaction was rejected as underpriced. Resubmitting \
+             it with a gas price of {gas_price} wei (escalation \
+             {escalations}/{MAX_GAS_PRICE_ESCALATIONS})."
+        );
+        relay_tx.set_gas_price(gas_price);
+    };
+    if let Some(state_file) = submission.state_file {
+        RelayState {
+            tx_hash: pending_tx.tx_hash(),
+            batch_nonce,
+            transfers: submission.transfers.to_vec(),
+        }
+        .store(state_file)?;
+    }
+    let tx_hash = pending_tx.tx_hash();
+    let confirmation_deadline = submission
+        .confirmation_timeout
+        .map(|timeout| Instant::now() + timeout);
+    let transf_result = match submission.rebroadcast_after {
+        Some(grace_period) => {
+            wait_for_relay_with_rebroadcasts(
+                eth_client,
+                io,
+                request_timeout,
+                tx_hash,
+                RebroadcastingWait {
+                    relay_tx: &relay_tx,
+                    private_relay_url: submission.private_relay_url,
+                    confirmations: submission.confirmations,
+                    poll_interval: submission
+                        .poll_interval
+                        .unwrap_or(DEFAULT_CONFIRMATION_POLL_INTERVAL),
+                    grace_period,
+                    max_rebroadcasts: submission
+                        .max_rebroadcasts
+                        .unwrap_or(DEFAULT_MAX_REBROADCASTS),
+                    deadline: confirmation_deadline,
+                },
+            )
+            .await
+        }
+        None => {
+            if let Some(poll_interval) = submission.poll_interval {
+                // NB: shorter intervals reduce the latency of waiting
+                // for confirmations, but increase the number of requests
+                // issued to the Ethereum RPC endpoint
+                pending_tx = pending_tx.interval(poll_interval);
+            }
+            let confirmed = pending_tx
+                .confirmations(submission.confirmations as usize)
+                .map(|result| {
+                    result.map_err(|e| {
+                        Error::EthereumBridge(EthereumBridgeError::Rpc(
+                            e.to_string(),
+                        ))
+                    })
+                });
+            match submission.confirmation_timeout {
+                // NB: a relay that is not confirmed in time is
+                // reported as timed out
+                Some(timeout) => {
+                    time::timeout(timeout, confirmed).await.unwrap_or(Ok(None))
+                }
+                None => confirmed.await,
+            }
+        }
+    };
+    let (outcome, gas_used) = match &transf_result {
+        Ok(Some(receipt)) => (
+            RelayOutcome::from_receipt(receipt),
+            receipt
+                .gas_used
+                .and_then(|gas_used| u64::try_from(gas_used).ok()),
+        ),
+        Ok(None) => (RelayOutcome::Timeout, None),
+        Err(err) => (
+            RelayOutcome::Failed {
+                error: err.to_string(),
+            },
+            None,
+        ),
+    };
+    record_attempt(&relay_tx, Some(tx_hash), outcome, gas_used);
+    let transf_result = transf_result?;
+
+    if let Some(state_file) = submission.state_file {
+        RelayState::clear(state_file)?;
+    }
+
+    let Some(receipt) = transf_result else {
+        display_line!(
+            io,
+            "The relay transaction {tx_hash:#x} was not confirmed."
+        );
+        return Ok((tx_hash, None));
+    };
+    if RelayOutcome::from_receipt(&receipt) == RelayOutcome::Reverted {
+        return Err(diagnose_reverted_relay(
+            eth_client,
+            io,
+            request_timeout,
+            &relay_tx,
+            &receipt,
+        )
+        .await);
+    }
+    display_line!(
+        io,
+        "The relay transaction {:#x} was confirmed in block {}, using {} gas.",
+        receipt.transaction_hash,
+        receipt
+            .block_number
+            .map(|number| number.to_string())
+            .unwrap_or_else(|| "<unknown>".to_string()),
+        receipt
+            .gas_used
+            .map(|gas_used| gas_used.to_string())
+            .unwrap_or_else(|| "<unknown>".to_string()),
+    );
+    report_progress(progress, ProgressEvent::Confirmed { tx_hash });
+    Ok((tx_hash, Some(receipt)))
+}
+
+/// Everything required to relay a Bridge pool proof to Ethereum,
+/// without access to a Namada node.
+///
+/// Bundles are written by [`export_relay_bundle`], and relayed with
+/// [`relay_from_bundle`], e.g. from an air-gapped machine.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelayBundle {
+    /// Version of the encoding of the proof.
+    pub version: u8,
+    /// The hashes of the transfers in the proof.
+    #[serde(serialize_with = "serialize_hashes")]
+    pub transfers: Vec<KeccakHash>,
+    /// The address of the Bridge contract to relay the proof to.
+    pub bridge_contract: EthAddress,
+    /// The nonce of the Bridge pool batch of the proof, which the
+    /// Bridge contract is expected to be at.
+    pub batch_nonce: U256,
+    /// The id of the Ethereum chain to relay the proof to.
+    pub eth_chain_id: u64,
+    /// The ABI encoded arguments of the relay call.
+    #[serde(
+        serialize_with = "serialize_hex_bytes",
+        deserialize_with = "deserialize_hex_bytes"
+    )]
+    pub abi_encoded_args: Vec<u8>,
+}
+
+impl RelayBundle {
+    /// Read a [`RelayBundle`] from the given path.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let contents = std::fs::read(path).map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::RelayBundle(format!(
+                "{}: {e}",
+                path.display()
+            )))
+        })?;
+        serde_json::from_slice(&contents)
+            .map_err(|e| Error::Encode(EncodingError::Serde(e.to_string())))
+    }
+
+    /// Write this [`RelayBundle`] to the given path.
+    pub fn store(&self, path: &Path) -> Result<(), Error> {
+        let contents = serde_json::to_vec_pretty(self)
+            .map_err(|e| Error::Encode(EncodingError::Serde(e.to_string())))?;
+        std::fs::write(path, contents).map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::RelayBundle(format!(
+                "{}: {e}",
+                path.display()
+            )))
+        })
+    }
+}
+
+/// Construct a Bridge pool proof, and write it to a [`RelayBundle`]
+/// along with the address of the Bridge contract and the nonce of
+/// the proven batch, such that it can be relayed without access to
+/// a Namada node.
+pub async fn export_relay_bundle(
+    client: &(impl Client + Sync),
+    io: &(impl Io + MaybeSync),
+    mut args: args::ExportRelayBundle,
+) -> Result<RelayBundle, Error> {
+    dedup_transfers(io, &mut args.transfers);
+    ensure_transfers_specified(io, &args.transfers)?;
+    let GenVersionedBridgePoolProofRsp {
+        version,
+        proof:
+            GenBridgePoolProofRsp {
+                abi_encoded_args, ..
+            },
+    } = construct_bridge_pool_proof(
+        client,
+        io,
+        GenBridgePoolProofReq {
+            transfers: args.transfers.as_slice().into(),
+            relayer: Cow::Borrowed(&args.relayer),
+            with_appendix: false,
+        },
+        args.target_root,
+        args.fail_on_warning,
+    )
+    .await?;
+    let (_, _, bp_proof) = decode_relay_args(
+        io,
+        version,
+        &abi_encoded_args,
+        ProofRequest {
+            transfers: args.transfers.len(),
+            relayer: Some(&args.relayer),
+        },
+    )?;
+    let bridge_contract = RPC
+        .shell()
+        .eth_bridge()
+        .read_bridge_contract(client)
+        .await
+        .map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::RetrieveContract(
+                e.to_string(),
+            ))
+        })?
+        .address;
+    let bundle = RelayBundle {
+        version,
+        transfers: args.transfers,
+        bridge_contract,
+        batch_nonce: bp_proof.batch_nonce,
+        eth_chain_id: args.eth_chain_id,
+        abi_encoded_args,
+    };
+    bundle.store(&args.bundle_file)?;
+    display_line!(
+        io,
+        "Wrote the relay bundle of Bridge pool batch {} to {}",
+        bundle.batch_nonce,
+        args.bundle_file.display()
+    );
+    Ok(bundle)
+}
+
+/// Relay a [`RelayBundle`] to Ethereum, without access to a
+/// Namada node.
+///
+/// The Ethereum node must be connected to the chain of the bundle,
+/// and the Bridge contract must be at the nonce of its batch.
+/// Relays that are not confirmed in time are reported as errors.
+pub async fn relay_from_bundle<E>(
+    eth_client: Arc<E>,
+    io: &(impl Io + MaybeSync),
+    bundle: RelayBundle,
+    args: args::RelayFromBundle,
+) -> Result<(), Error>
+where
+    E: Middleware,
+    E::Error: std::fmt::Debug + std::fmt::Display,
+{
+    let confirmations = validate_relay_confirmations(io, args.confirmations);
+    let _signal_receiver = args.safe_mode.then(install_shutdown_signal);
+    let request_timeout = match args.request_timeout {
+        Some(timeout) => Some(timeout),
+        None => eth_request_timeout_from_env(io)?,
+    };
+    let rpc_err = |e: E::Error| {
+        Error::EthereumBridge(EthereumBridgeError::Rpc(e.to_string()))
+    };
+
+    if args.sync {
+        block_on_eth_sync(
+            &*eth_client,
+            io,
+            BlockOnEthSync {
+                deadline: Instant::now() + Duration::from_secs(60),
+                delta_sleep: Duration::from_secs(1),
+            },
+        )
+        .await?;
+    } else {
+        eth_sync_or_exit(&*eth_client, io).await?;
+    }
+
+    let chain_id = with_eth_request_timeout(
+        request_timeout,
+        "query the Ethereum chain id",
+        eth_client
+            .get_chainid()
+            .map(|result| result.map_err(rpc_err)),
+    )
+    .await?;
+    if chain_id != U256::from(bundle.eth_chain_id) {
+        return Err(Error::EthereumBridge(
+            EthereumBridgeError::EthChainIdMismatch {
+                expected: bundle.eth_chain_id,
+                got: chain_id.to_string(),
+            },
+        ));
+    }
+
+    let (validator_set, signatures, bp_proof) = decode_relay_args(
+        io,
+        bundle.version,
+        &bundle.abi_encoded_args,
+        ProofRequest {
+            transfers: bundle.transfers.len(),
+            relayer: None,
+        },
+    )?;
+    if bp_proof.batch_nonce != bundle.batch_nonce {
+        return Err(Error::EthereumBridge(EthereumBridgeError::RelayBundle(
+            format!(
+                "the proof is for Bridge pool batch {}, but the bundle \
+                 claims it is for batch {}",
+                bp_proof.batch_nonce, bundle.batch_nonce
+            ),
+        )));
+    }
+
+    if let Some(ceiling_gwei) = args.max_gas_price_gwei {
+        check_gas_price_ceiling(
+            &*eth_client,
+            io,
+            request_timeout,
+            ceiling_gwei,
+        )
+        .await?;
+    }
+
+    let bridge = Bridge::new(bundle.bridge_contract, Arc::clone(&eth_client));
+    let contract_nonce =
+        query_contract_bp_nonce(&bridge, request_timeout).await?;
+    check_bp_nonce(io, bundle.batch_nonce, contract_nonce)?;
+
+    let mut relay_op =
+        bridge.transfer_to_erc(validator_set, signatures, bp_proof);
+    set_relay_tx_params(
+        &mut relay_op.tx,
+        args.gas,
+        args.gas_price,
+        args.eth_addr,
+    );
+
+    let submission = RelaySubmission {
+        batch_nonce: bundle.batch_nonce,
+        transfers: &bundle.transfers,
+        confirmations,
+        request_timeout,
+        access_list: false,
+        gas_price_bump: None,
+        max_gas_price: None,
+        private_relay_url: None,
+        poll_interval: args.poll_interval,
+        rebroadcast_after: args.rebroadcast_after,
+        max_rebroadcasts: args.max_rebroadcasts,
+        confirmation_timeout: args.confirmation_timeout,
+        state_file: None,
+        audit_log: args.audit_log.as_deref(),
+    };
+    let relay_tx =
+        prepare_relay_tx(&*eth_client, io, relay_op.tx, &submission).await?;
+    match submit_relay_tx(&*eth_client, io, relay_tx, &submission, None).await?
+    {
+        (_, Some(_)) => Ok(()),
+        (tx_hash, None) => Err(Error::EthereumBridge(
+            EthereumBridgeError::RelayUnconfirmed(format!("{tx_hash:#x}")),
+        )),
+    }
+}
+
+/// The expected outcome of relaying the batch of transfers
+/// recommended by the Bridge pool recommender.
+#[derive(Debug, Clone)]
+pub struct RelayPreview {
+    /// Statistics about the run of the recommender.
+    pub stats: RecommendationStats,
+    /// The outcome of dry-running the relay of the recommended
+    /// batch, if a batch was recommended.
+    pub dry_run: Option<RelayDryRun>,
+}
+
+/// The outcome of dry-running a relay call against the Bridge contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RelayDryRun {
+    /// The relay call succeeded, and is estimated to consume
+    /// the given amount of gas.
+    Success {
+        /// The estimated gas of the relay call.
+        gas: U256,
+    },
+    /// The relay call reverted, for the given reason.
+    Revert(String),
+}
+
+/// Run the Bridge pool recommender, construct a proof of the
+/// recommended batch and dry-run its relay against the Bridge
+/// contract, reporting the expected outcome of relaying it.
+/// The gas estimated by the Ethereum node is checked against the
+/// estimate of the recommender.
+///
+/// No transaction is sent to Ethereum.
+pub async fn preview_relay<E>(
+    context: &impl Namada,
+    eth_client: Arc<E>,
+    args: args::PreviewRelay,
+) -> Result<RelayPreview, Error>
+where
+    E: Middleware,
+    E::Error: std::fmt::Debug + std::fmt::Display,
+{
+    let request_timeout = match args.request_timeout {
+        Some(timeout) => Some(timeout),
+        None => eth_request_timeout_from_env(context.io())?,
+    };
+    eth_sync_or_exit(&*eth_client, context.io()).await?;
+
+    let stats =
+        recommendations::recommend_batch(context, args.recommend, None).await?;
+    if stats.recommended_transfers.is_empty() {
+        return Ok(RelayPreview {
+            stats,
+            dry_run: None,
+        });
+    }
+
+    // NB: transfers past the security threshold fail the preview,
+    // rather than prompting the user to proceed
+    let GenVersionedBridgePoolProofRsp {
+        version,
+        proof:
+            GenBridgePoolProofRsp {
+                abi_encoded_args, ..
+            },
+    } = construct_bridge_pool_proof(
+        context.client(),
+        context.io(),
+        GenBridgePoolProofReq {
+            transfers: stats.recommended_transfers.as_slice().into(),
+            relayer: Cow::Borrowed(&args.relayer),
+            with_appendix: false,
+        },
+        None,
+        true,
+    )
+    .await?;
+    let bridge =
+        read_bridge_contract(context.client(), context.io(), &eth_client)
+            .await?;
+    let (validator_set, signatures, bp_proof) = decode_relay_args(
+        context.io(),
+        version,
+        &abi_encoded_args,
+        ProofRequest {
+            transfers: stats.recommended_count,
+            relayer: Some(&args.relayer),
+        },
+    )?;
+    let mut relay_op =
+        bridge.transfer_to_erc(validator_set, signatures, bp_proof);
+    if let Some(eth_addr) = args.eth_addr {
+        relay_op.tx.set_from(eth_addr.into());
+    }
+
+    // NOTE: these operations cost no gas on Ethereum
+    let call_result = with_eth_request_timeout(
+        request_timeout,
+        "dry-run the relay call",
+        relay_op.call().map(Ok),
+    )
+    .await?;
+    let dry_run = match call_result {
+        Ok(()) => {
+            let gas = with_eth_request_timeout(
+                request_timeout,
+                "estimate the gas of the relay call",
+                relay_op.estimate_gas().map(|result| {
+                    result.map_err(|e| {
+                        Error::EthereumBridge(
+                            EthereumBridgeError::ContractCall(e.to_string()),
+                        )
+                    })
+                }),
+            )
+            .await?;
+            display_line!(
+                context.io(),
+                "Relaying the {} recommended transfers would succeed, \
+                 consuming an estimated {gas} gas.",
+                stats.recommended_count,
+            );
+            if let Some(heuristic) = stats.estimated_gas {
+                check_gas_estimate(context.io(), heuristic, gas);
+            }
+            RelayDryRun::Success { gas }
+        }
+        Err(err) => {
+            let error = error_label();
+            display_line!(
+                context.io(),
+                "{error}: Relaying the recommended transfers would revert: \
+                 {err}"
+            );
+            RelayDryRun::Revert(err.to_string())
+        }
+    };
+    Ok(RelayPreview {
+        stats,
+        dry_run: Some(dry_run),
+    })
+}
+
+/// The divergence, in percent, between the gas estimated by the
+/// Bridge pool recommender and by an Ethereum node, past which a
+/// warning is emitted.
+pub const MAX_GAS_ESTIMATE_DIVERGENCE_PERCENT: u64 = 25;
+
+/// Compare the gas of a relay estimated by the heuristics of the
+/// Bridge pool recommender with the estimate of an Ethereum node,
+/// warning if they diverge by more than
+/// [`MAX_GAS_ESTIMATE_DIVERGENCE_PERCENT`].
+///
+/// Returns whether the estimates diverge.
+fn check_gas_estimate(io: &impl Io, heuristic: Uint, estimated: U256) -> bool {
+    let heuristic = U256(heuristic.0);
+    if estimated.is_zero() {
+        return false;
+    }
+    let divergence = if heuristic > estimated {
+        heuristic - estimated
+    } else {
+        estimated - heuristic
+    };
+    let divergence_percent =
+        divergence.saturating_mul(U256::from(100)) / estimated;
+    if divergence_percent <= U256::from(MAX_GAS_ESTIMATE_DIVERGENCE_PERCENT) {
+        return false;
+    }
+    let warning = warning_label();
+    display_line!(
+        io,
+        "{warning}: The recommender estimated {heuristic} gas for the relay, \
+         which diverges by {divergence_percent}% from the {estimated} gas \
+         estimated by the Ethereum node. The gas costs assumed by the \
+         recommender may need to be recalibrated."
+    );
+    true
+}
+
+/// Instantiate the Bridge contract, at the address stored in Namada.
+async fn read_bridge_contract<E>(
+    client: &(impl Client + Sync),
+    io: &impl Io,
+    eth_client: &Arc<E>,
+) -> Result<Bridge<E>, Error>
+where
+    E: Middleware,
+{
+    match RPC.shell().eth_bridge().read_bridge_contract(client).await {
+        Ok(address) => Ok(Bridge::new(address.address, Arc::clone(eth_client))),
+        Err(err_msg) => {
+            let error = error_label();
+            display_line!(
+                io,
+                "{error}: Unable to read the address of the Bridge contract \
+                 from Namada: {err_msg}"
+            );
+            Err(Error::EthereumBridge(
+                EthereumBridgeError::RetrieveContract(err_msg.to_string()),
+            ))
+        }
+    }
+}
+
+/// Details about the request of a Bridge pool proof, reported
+/// if the proof fails to decode.
+#[derive(Debug, Clone, Copy)]
+struct ProofRequest<'relayer> {
+    /// The number of transfers requested to be proven.
+    transfers: usize,
+    /// The relayer the proof was requested for, if known.
+    relayer: Option<&'relayer Address>,
+}
+
+impl std::fmt::Display for ProofRequest<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "requested for {} transfer(s)", self.transfers)?;
+        if let Some(relayer) = self.relayer {
+            write!(f, " by relayer {relayer}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Decode the ABI encoded arguments of a Bridge pool proof of
+/// the given `version` into the arguments of a relay call.
+fn decode_relay_args(
+    io: &impl Io,
+    version: u8,
+    abi_encoded_args: &[u8],
+    request: ProofRequest<'_>,
+) -> Result<TransferToErcArgs, Error> {
+    if version != BRIDGE_POOL_PROOF_VERSION {
+        edisplay_line!(
+            io,
+            "The generated proof has version {version}, but only version \
+             {BRIDGE_POOL_PROOF_VERSION} can be relayed to the Bridge contract"
+        );
+        return Err(Error::EthereumBridge(
+            EthereumBridgeError::UnsupportedProofVersion {
+                got: version,
+                expected: BRIDGE_POOL_PROOF_VERSION,
+            },
+        ));
+    }
+    AbiDecode::decode(abi_encoded_args).map_err(|error| {
+        Error::Encode(EncodingError::Decoding(echo_error!(
+            io,
+            "Unable to decode the generated proof of {} bytes, {request}: \
+             {error:?}",
+            abi_encoded_args.len(),
+        )))
+    })
+}
+
+/// The destination and the calldata of a relay transaction, to
+/// submit it through an external wallet, such as a multisig.
+struct RelayCalldata {
+    /// The address of the Bridge contract.
+    to: Option<ethers::types::Address>,
+    /// The ABI encoded call to the Bridge contract.
+    calldata: Vec<u8>,
+}
+
+impl RelayCalldata {
+    /// Extract the destination and the calldata of a relay transaction.
+    fn new(relay_tx: &TypedTransaction) -> Self {
+        Self {
+            to: relay_tx.to().and_then(|to| to.as_address()).copied(),
+            calldata: relay_tx
+                .data()
+                .map(|data| data.to_vec())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl std::fmt::Display for RelayCalldata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.to {
+            Some(to) => writeln!(f, "To: {to:?}")?,
+            None => writeln!(f, "To: <unset>")?,
+        }
+        let selector = self.calldata.get(..4).unwrap_or_default();
+        writeln!(f, "Function selector: 0x{}", HEXLOWER.encode(selector))?;
+        write!(f, "Calldata: 0x{}", HEXLOWER.encode(&self.calldata))
+    }
+}
+
+/// Query the nonce of the next Bridge pool batch expected by the
+/// Bridge contract.
+async fn query_contract_bp_nonce<E>(
+    bridge: &Bridge<E>,
+    request_timeout: Option<Duration>,
+) -> Result<U256, Error>
+where
+    E: Middleware,
+{
+    // NOTE: this operation costs no gas on Ethereum
+    with_eth_request_timeout(
+        request_timeout,
+        "query the Bridge pool nonce",
+        bridge.transfer_to_erc_20_nonce().call().map(|result| {
+            result.map_err(|e| {
+                Error::EthereumBridge(EthereumBridgeError::ContractCall(
+                    e.to_string(),
+                ))
+            })
+        }),
+    )
+    .await
+}
+
+/// Override the gas limit, the gas price and the sender of a relay
+/// transaction, if they were provided by the user.
+pub(super) fn set_relay_tx_params(
+    relay_tx: &mut TypedTransaction,
+    gas: Option<u64>,
+    gas_price: Option<u64>,
+    eth_addr: Option<EthAddress>,
+) {
+    if let Some(gas) = gas {
+        relay_tx.set_gas(gas);
+    }
+    if let Some(gas_price) = gas_price {
+        relay_tx.set_gas_price(gas_price);
+    }
+    if let Some(eth_addr) = eth_addr {
+        relay_tx.set_from(eth_addr.into());
+    }
+}
+
+/// Check that the Bridge contract is at the nonce of the Bridge
+/// pool batch being relayed.
+fn check_bp_nonce(
+    io: &impl Io,
+    batch_nonce: U256,
+    contract_nonce: U256,
+) -> Result<(), Error> {
+    match batch_nonce.cmp(&contract_nonce) {
+        Ordering::Equal => Ok(()),
+        Ordering::Less => {
+            let error = error_label();
+            display_line!(
+                io,
+                "{error}: The Bridge pool nonce in the smart contract is \
+                 {contract_nonce}, while the nonce in Namada is still {}. A \
+                 relay of the former one has already happened, but a proof \
+                 has yet to be crafted in Namada.",
+                batch_nonce
+            );
+            Err(Error::EthereumBridge(EthereumBridgeError::InvalidBpNonce))
+        }
+        Ordering::Greater => {
+            let error = error_label();
+            display_line!(
+                io,
+                "{error}: The Bridge pool nonce in the smart contract is \
+                 {contract_nonce}, while the nonce in Namada is still {}. \
+                 Somehow, Namada's nonce is ahead of the contract's nonce!",
+                batch_nonce
+            );
+            Err(Error::EthereumBridge(EthereumBridgeError::InvalidBpNonce))
+        }
+    }
+}
+
+/// Halt if the latest signed Bridge pool root is no longer signed
+/// over the nonce of the batch being relayed.
+///
+/// Once a root is signed over a later nonce, the relayed batch has
+/// been superseded, so relaying its proof would revert on Ethereum.
+async fn ensure_signed_root_nonce(
+    client: &(impl Client + Sync),
+    io: &impl Io,
+    batch_nonce: U256,
+) -> Result<(), Error> {
+    let signed_nonce = query_signed_root(client)
+        .await?
+        .map(|(bp_root, _)| U256(bp_root.data.1 .0));
+    if signed_nonce == Some(batch_nonce) {
+        return Ok(());
+    }
+    let signed_nonce = signed_nonce
+        .map(|nonce| nonce.to_string())
+        .unwrap_or_else(|| "none".into());
+    edisplay_line!(
+        io,
+        "The latest signed Bridge pool root has nonce {signed_nonce}, but \
+         the proof was generated for nonce {batch_nonce}. Regenerate the \
+         proof before relaying it."
+    );
+    Err(Error::EthereumBridge(EthereumBridgeError::StaleProof {
+        proof_nonce: batch_nonce.to_string(),
+        signed_nonce,
+    }))
+}
+
+/// Send a relay transaction to the Ethereum node, or to the given
+/// private relay.
+async fn send_relay_transaction<'client, E>(
+    eth_client: &'client E,
+    request_timeout: Option<Duration>,
+    private_relay_url: Option<&str>,
+    relay_tx: &TypedTransaction,
+) -> Result<PendingTransaction<'client, E::Provider>, Error>
+where
+    E: Middleware,
+    E::Error: std::fmt::Display,
+{
+    match private_relay_url {
+        Some(private_relay_url) => {
+            with_eth_request_timeout(
+                request_timeout,
+                "send the relay transaction to the private relay",
+                send_private_relay_transaction(
+                    eth_client,
+                    private_relay_url,
+                    relay_tx,
+                ),
+            )
+            .await
+        }
+        None => {
+            with_eth_request_timeout(
+                request_timeout,
+                "send the relay transaction",
+                eth_client.send_transaction(relay_tx.clone(), None).map(
+                    |result| {
+                        result.map_err(|e| {
+                            Error::EthereumBridge(
+                                EthereumBridgeError::ContractCall(
+                                    e.to_string(),
+                                ),
+                            )
+                        })
+                    },
+                ),
+            )
+            .await
+        }
+    }
+}
+
+/// The interval between the first polls of the Ethereum node, while
+/// waiting for a relay transaction to be confirmed, if none is given.
+pub const DEFAULT_CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_secs(7);
+
+/// The upper bound on the exponentially increasing interval between
+/// polls of the Ethereum node, while waiting for a relay transaction
+/// to be confirmed.
+pub const MAX_CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The maximum number of times a relay transaction is re-broadcast
+/// while waiting for it to be confirmed, if none is given.
+pub const DEFAULT_MAX_REBROADCASTS: u64 = 5;
+
+/// Parameters of [`wait_for_relay_with_rebroadcasts`].
+struct RebroadcastingWait<'tx> {
+    /// The relay transaction, with its nonce and gas price filled in.
+    relay_tx: &'tx TypedTransaction,
+    /// The private relay the relay transaction was sent to, if any.
+    private_relay_url: Option<&'tx str>,
+    /// The number of confirmations to wait for.
+    confirmations: u64,
+    /// The interval between the first polls of the Ethereum node.
+    poll_interval: Duration,
+    /// The amount of time the relay transaction may be neither
+    /// pending nor mined, before it is re-broadcast.
+    grace_period: Duration,
+    /// The maximum number of re-broadcasts of the relay transaction.
+    max_rebroadcasts: u64,
+    /// The instant after which the relay transaction is no longer
+    /// waited upon, if any.
+    deadline: Option<Instant>,
+}
+
+/// Wait for a relay transaction to be confirmed, re-broadcasting it
+/// if it falls out of the mempool of the Ethereum node.
+///
+/// The Ethereum node is polled according to
+/// [`confirmation_poll_policy`], whose backoff is reset after each
+/// re-broadcast. `Ok(None)` is returned if the relay transaction is
+/// not confirmed by the deadline of the `wait`. The wait is aborted
+/// with an error once the relay transaction falls out of the mempool
+/// after its last allowed re-broadcast, or if re-broadcasting it
+/// fails for a reason that retrying cannot fix.
+async fn wait_for_relay_with_rebroadcasts<E>(
+    eth_client: &E,
+    io: &impl Io,
+    request_timeout: Option<Duration>,
+    mut tx_hash: H256,
+    wait: RebroadcastingWait<'_>,
+) -> Result<Option<TransactionReceipt>, Error>
+where
+    E: Middleware,
+    E::Error: std::fmt::Display,
+{
+    let rpc_err = |e: E::Error| {
+        Error::EthereumBridge(EthereumBridgeError::Rpc(e.to_string()))
+    };
+    let poll_policy = confirmation_poll_policy(wait.poll_interval);
+    let mut polls = 0u32;
+    let mut missing_since: Option<Instant> = None;
+    let mut rebroadcasts = 0u64;
+    loop {
+        polls = polls.saturating_add(1);
+        let delay = poll_policy.jittered_delay(polls);
+        if let Some(deadline) = wait.deadline {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if delay >= remaining {
+                time::sleep(remaining).await;
+                return Ok(None);
+            }
+        }
+        time::sleep(delay).await;
+
+        let receipt = with_eth_request_timeout(
+            request_timeout,
+            "query the receipt of the relay transaction",
+            eth_client
+                .get_transaction_receipt(tx_hash)
+                .map(|result| result.map_err(rpc_err)),
+        )
+        .await?;
+        if let Some(receipt) = receipt {
+            missing_since = None;
+            let Some(included_at) = receipt.block_number else {
+                continue;
+            };
+            let latest_block = with_eth_request_timeout(
+                request_timeout,
+                "query the latest Ethereum block number",
+                eth_client
+                    .get_block_number()
+                    .map(|result| result.map_err(rpc_err)),
+            )
+            .await?;
+            if relay_is_confirmed(
+                included_at.as_u64(),
+                latest_block.as_u64(),
+                wait.confirmations,
+            ) {
+                return Ok(Some(receipt));
+            }
+            continue;
+        }
+
+        let pending = with_eth_request_timeout(
+            request_timeout,
+            "query the relay transaction",
+            eth_client
+                .get_transaction(tx_hash)
+                .map(|result| result.map_err(rpc_err)),
+        )
+        .await?
+        .is_some();
+        if pending {
+            missing_since = None;
+            continue;
+        }
+        let now = Instant::now();
+        let missing_at = *missing_since.get_or_insert(now);
+        if now.duration_since(missing_at) < wait.grace_period {
+            continue;
+        }
+        if rebroadcasts >= wait.max_rebroadcasts {
+            edisplay_line!(
+                io,
+                "The relay transaction {tx_hash:?} fell out of the mempool, \
+                 and was already re-broadcast {rebroadcasts} time(s). Giving \
+                 up on it."
+            );
+            return Err(Error::EthereumBridge(
+                EthereumBridgeError::RelayDropped(format!(
+                    "{tx_hash:?} was re-broadcast {rebroadcasts} time(s)"
+                )),
+            ));
+        }
+
+        rebroadcasts += 1;
+        tracing::info!(
+            ?tx_hash,
+            rebroadcasts,
+            "Re-broadcasting the relay transaction"
+        );
+        display_line!(
+            io,
+            "The relay transaction {tx_hash:?} fell out of the mempool. \
+             Re-broadcasting it (re-broadcast {rebroadcasts})."
+        );
+        match send_relay_transaction(
+            eth_client,
+            request_timeout,
+            wait.private_relay_url,
+            wait.relay_tx,
+        )
+        .await
+        {
+            Ok(pending_tx) => tx_hash = pending_tx.tx_hash(),
+            // NB: re-broadcasting cannot succeed if the nonce of the
+            // relay transaction was consumed, or if its sender cannot
+            // pay for it
+            Err(err) if is_permanent_send_error(&err) => {
+                edisplay_line!(
+                    io,
+                    "Failed to re-broadcast the relay transaction, giving up \
+                     on it: {err}"
+                );
+                return Err(err);
+            }
+            Err(err) => {
+                edisplay_line!(
+                    io,
+                    "Failed to re-broadcast the relay transaction: {err}"
+                );
+            }
+        }
+        missing_since = None;
+        polls = 0;
+    }
+}
+
+/// Diagnose a relay transaction that was mined, but reverted,
+/// returning the error to fail the relay with.
+///
+/// The reason string the Bridge contract reverted with is recovered
+/// by replaying the relay transaction, and explained to the user.
+async fn diagnose_reverted_relay<E>(
+    eth_client: &E,
+    io: &impl Io,
+    request_timeout: Option<Duration>,
+    relay_tx: &TypedTransaction,
+    receipt: &TransactionReceipt,
+) -> Error
+where
+    E: Middleware,
+{
+    let tx_hash = receipt.transaction_hash;
+    let reason =
+        relay_revert_reason(eth_client, request_timeout, relay_tx, receipt)
+            .await;
+    let error = error_label();
+    match &reason {
+        Some(reason) => {
+            display_line!(
+                io,
+                "{error}: The relay transaction {tx_hash:?} reverted with \
+                 reason: {reason}"
+            );
+            if let Some(explanation) = explain_relay_revert(reason) {
+                display_line!(io, "{explanation}");
+            }
+        }
+        None => {
+            display_line!(
+                io,
+                "{error}: The relay transaction {tx_hash:?} reverted, but its \
+                 revert reason could not be recovered."
+            );
+        }
+    }
+    Error::EthereumBridge(EthereumBridgeError::RelayReverted {
+        tx_hash: format!("{tx_hash:?}"),
+        reason: reason.unwrap_or_else(|| "unknown reason".into()),
+    })
+}
+
+/// Replay a reverted relay transaction with an `eth_call`, on top of
+/// the state preceding the block it was mined in, and decode the
+/// reason string the Bridge contract reverted with.
+///
+/// Returns [`None`] if no reason string could be recovered, e.g.
+/// because the replayed call no longer reverts.
+async fn relay_revert_reason<E>(
+    eth_client: &E,
+    request_timeout: Option<Duration>,
+    relay_tx: &TypedTransaction,
+    receipt: &TransactionReceipt,
+) -> Option<String>
+where
+    E: Middleware,
+{
+    let mined_at = receipt.block_number?;
+    let mut replayed_tx = relay_tx.clone();
+    replayed_tx.set_from(receipt.from);
+    let block = BlockId::Number(BlockNumber::Number(
+        mined_at.saturating_sub(1u64.into()),
+    ));
+    let replay_result = with_eth_request_timeout(
+        request_timeout,
+        "replay the reverted relay transaction",
+        eth_client.call(&replayed_tx, Some(block)).map(Ok),
+    )
+    .await
+    .ok()?;
+    let revert_data =
+        replay_result.err()?.as_error_response()?.as_revert_data()?;
+    String::decode_with_selector(&revert_data)
+}
+
+/// Explain the reason string the Bridge contract reverted a
+/// relay with, if it is a known one.
+fn explain_relay_revert(reason: &str) -> Option<&'static str> {
+    let reason = reason.to_lowercase();
+    if reason.contains("nonce") {
+        Some(
+            "The nonce of the relayed batch does not match the Bridge pool \
+             nonce of the Bridge contract. The batch may have been relayed \
+             by someone else, or the roots preceding it have yet to be \
+             relayed.",
+        )
+    } else if reason.contains("signature") || reason.contains("voting power") {
+        Some(
+            "The validator signatures over the relayed root are not valid \
+             for the validator set of the Bridge contract. The validator \
+             set may have been updated since the proof was generated, in \
+             which case the proof must be regenerated.",
+        )
+    } else if reason.contains("proof") || reason.contains("root") {
+        Some(
+            "The relayed transfers could not be proven against the signed \
+             Bridge pool root. Regenerate the proof against the latest \
+             signed root.",
+        )
+    } else {
+        None
+    }
+}
+
+/// The policy by which the Ethereum node is polled, while waiting
+/// for a relay transaction to be confirmed.
+///
+/// The interval between polls starts at `poll_interval`, doubling
+/// after each poll up to [`MAX_CONFIRMATION_POLL_INTERVAL`]. Poll
+/// intervals already larger than the bound are left untouched.
+fn confirmation_poll_policy(poll_interval: Duration) -> RetryPolicy {
+    RetryPolicy {
+        base: poll_interval,
+        max_delay: MAX_CONFIRMATION_POLL_INTERVAL,
+        ..Default::default()
+    }
+}
+
+/// Check if a relay transaction included in the block at height
+/// `included_at` has the given number of `confirmations`, once the
+/// Ethereum chain has reached the height `latest_block`.
+///
+/// The block including the transaction counts as its first
+/// confirmation.
+fn relay_is_confirmed(
+    included_at: u64,
+    latest_block: u64,
+    confirmations: u64,
+) -> bool {
+    latest_block.saturating_add(1) >= included_at.saturating_add(confirmations)
+}
+
+/// Comparison between the Ethereum gas estimated by the Bridge pool
+/// recommender for a batch of transfers, and the gas actually used
+/// to relay them.
+///
+/// Useful to calibrate the gas heuristics of the recommender.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct RelayGasReport {
+    /// The gas estimated by the recommender.
+    estimated: u64,
+    /// The gas used by the confirmed relay transaction.
+    actual: u64,
+}
+
+impl RelayGasReport {
+    /// Build a new [`RelayGasReport`].
+    const fn new(estimated: u64, actual: u64) -> Self {
+        Self { estimated, actual }
+    }
+
+    /// The difference between the actual and the estimated gas.
+    ///
+    /// Positive values mean the recommender underestimated the
+    /// cost of the relay.
+    fn delta(&self) -> i128 {
+        i128::from(self.actual) - i128::from(self.estimated)
+    }
+
+    /// The [`Self::delta`] as a percentage of the estimated gas.
+    fn delta_percentage(&self) -> Option<f64> {
+        (self.estimated != 0)
+            .then(|| self.delta() as f64 * 100.0 / self.estimated as f64)
+    }
+}
+
+impl std::fmt::Display for RelayGasReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Estimated gas: {}, actual gas: {}, delta: {:+}",
+            self.estimated,
+            self.actual,
+            self.delta()
+        )?;
+        if let Some(percentage) = self.delta_percentage() {
+            write!(f, " ({percentage:+.2}%)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Halt if the current Ethereum gas price exceeds the given
+/// ceiling, in gwei.
+///
+/// This allows relayers to refrain from relaying during gas
+/// price spikes.
+async fn check_gas_price_ceiling<E>(
+    eth_client: &E,
+    io: &impl Io,
+    request_timeout: Option<Duration>,
+    ceiling_gwei: u64,
+) -> Result<(), Error>
+where
+    E: Middleware,
+    E::Error: std::fmt::Display,
+{
+    let gas_price = with_eth_request_timeout(
+        request_timeout,
+        "query the gas price",
+        eth_client.get_gas_price().map(|result| {
+            result.map_err(|e| {
+                Error::EthereumBridge(EthereumBridgeError::Rpc(e.to_string()))
+            })
+        }),
+    )
+    .await?;
+    if gas_price_exceeds_ceiling(gas_price, ceiling_gwei) {
+        display_line!(
+            io,
+            "Not relaying, since the current Ethereum gas price of \
+             {gas_price} wei exceeds the ceiling of {ceiling_gwei} gwei."
+        );
+        return Err(Error::EthereumBridge(
+            EthereumBridgeError::GasPriceAboveCeiling {
+                gas_price: gas_price.to_string(),
+                ceiling_gwei,
+            },
+        ));
+    }
+    Ok(())
+}
+
+/// Check if a gas price, in wei, exceeds a ceiling, in gwei.
+fn gas_price_exceeds_ceiling(gas_price: U256, ceiling_gwei: u64) -> bool {
+    const WEI_PER_GWEI: u64 = 1_000_000_000;
+    gas_price > U256::from(ceiling_gwei) * U256::from(WEI_PER_GWEI)
+}
+
+/// Attach an EIP-2930 access list, generated by the Ethereum node
+/// with `eth_createAccessList`, to a relay transaction.
+///
+/// Declaring the storage slots of the Bridge contract accessed by
+/// the relay upfront may reduce its gas cost. The gas estimated with
+/// and without the access list is logged, to measure the savings,
+/// and the access list is only attached if it saves gas.
+async fn with_access_list<E>(
+    eth_client: &E,
+    io: &impl Io,
+    request_timeout: Option<Duration>,
+    relay_tx: TypedTransaction,
+) -> Result<TypedTransaction, Error>
+where
+    E: Middleware,
+    E::Error: std::fmt::Display,
+{
+    let AccessListWithGasUsed {
+        access_list,
+        gas_used,
+    } = with_eth_request_timeout(
+        request_timeout,
+        "create an access list for the relay transaction",
+        eth_client
+            .create_access_list(&relay_tx, None)
+            .map(|result| {
+                result.map_err(|e| {
+                    Error::EthereumBridge(EthereumBridgeError::Rpc(
+                        e.to_string(),
+                    ))
+                })
+            }),
+    )
+    .await?;
+    let gas_without_access_list = with_eth_request_timeout(
+        request_timeout,
+        "estimate the gas of the relay transaction",
+        eth_client.estimate_gas(&relay_tx, None).map(|result| {
+            result.map_err(|e| {
+                Error::EthereumBridge(EthereumBridgeError::Rpc(e.to_string()))
+            })
+        }),
+    )
+    .await?;
+
+    if gas_used >= gas_without_access_list {
+        tracing::info!(
+            %gas_without_access_list,
+            gas_with_access_list = %gas_used,
+            "Relaying without an access list, which would not save gas"
+        );
+        display_line!(
+            io,
+            "Relaying without an access list, since it would not reduce \
+             the estimated gas of {gas_without_access_list}."
+        );
+        return Ok(relay_tx);
+    }
+    let savings = gas_without_access_list - gas_used;
+    tracing::info!(
+        %gas_without_access_list,
+        gas_with_access_list = %gas_used,
+        %savings,
+        accessed_addresses = access_list.0.len(),
+        "Created an access list for the relay transaction"
+    );
+    display_line!(
+        io,
+        "Relaying with an access list of {} address(es). Estimated gas: \
+         {gas_used} (saving {savings} gas).",
+        access_list.0.len()
+    );
+
+    Ok(match relay_tx {
+        // legacy transactions cannot carry access lists
+        TypedTransaction::Legacy(tx) => TypedTransaction::Eip2930(
+            Eip2930TransactionRequest::new(tx, access_list),
+        ),
+        mut relay_tx => {
+            relay_tx.set_access_list(access_list);
+            relay_tx
+        }
+    })
+}
+
+/// Sign a relay transaction locally, and submit it to a private
+/// transaction relay with `eth_sendPrivateTransaction`.
+///
+/// Private relays do not broadcast transactions through the public
+/// mempool, which prevents profitable relays from being front-run.
+/// The returned [`PendingTransaction`] polls the Ethereum node for
+/// the inclusion of the relay transaction.
+async fn send_private_relay_transaction<'client, E>(
+    eth_client: &'client E,
+    private_relay_url: &str,
+    relay_tx: &TypedTransaction,
+) -> Result<PendingTransaction<'client, E::Provider>, Error>
+where
+    E: Middleware,
+    E::Error: std::fmt::Display,
+{
+    let from = relay_tx.from().copied().ok_or_else(|| {
+        Error::EthereumBridge(EthereumBridgeError::PrivateRelay(
+            "The sender of the relay transaction is unknown".into(),
+        ))
+    })?;
+    let signature =
+        eth_client
+            .sign_transaction(relay_tx, from)
+            .await
+            .map_err(|e| {
+                Error::EthereumBridge(EthereumBridgeError::PrivateRelay(
+                    format!("Failed to sign the relay transaction: {e}"),
+                ))
+            })?;
+    let raw_tx = relay_tx.rlp_signed(&signature);
+
+    let private_relay =
+        Provider::<Http>::try_from(private_relay_url).map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::PrivateRelay(format!(
+                "Invalid private relay URL {private_relay_url}: {e}"
+            )))
+        })?;
+    let tx_hash: H256 = private_relay
+        .request(
+            "eth_sendPrivateTransaction",
+            [serde_json::json!({ "tx": raw_tx })],
+        )
+        .await
+        .map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::PrivateRelay(
+                e.to_string(),
+            ))
+        })?;
+    Ok(PendingTransaction::new(tx_hash, eth_client.provider()))
+}
+
+/// State of a Bridge pool relay operation, persisted to disk
+/// after the relay transaction has been sent to Ethereum.
+#[derive(Serialize, Deserialize)]
+struct RelayState {
+    /// Hash of the Ethereum transaction relaying the proof.
+    tx_hash: H256,
+    /// Nonce of the relayed Bridge pool batch.
+    batch_nonce: U256,
+    /// The hashes of the relayed transfers.
+    ///
+    /// States persisted before the transfers were recorded are
+    /// never resumed, since they cannot be matched to a relay.
+    #[serde(default, serialize_with = "serialize_hashes")]
+    transfers: Vec<KeccakHash>,
+}
+
+impl RelayState {
+    /// Read a [`RelayState`] from the given path, if any
+    /// has been stored there.
+    fn load(path: &Path) -> Result<Option<Self>, Error> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let contents = std::fs::read(path).map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::RelayState(
+                e.to_string(),
+            ))
+        })?;
+        serde_json::from_slice(&contents)
+            .map(Some)
+            .map_err(|e| Error::Encode(EncodingError::Serde(e.to_string())))
+    }
+
+    /// Write this [`RelayState`] to the given path.
+    ///
+    /// The state is written to a temporary file, which is then
+    /// renamed to `path`, such that a crash never leaves a
+    /// truncated state behind.
+    fn store(&self, path: &Path) -> Result<(), Error> {
+        let contents = serde_json::to_vec(self)
+            .map_err(|e| Error::Encode(EncodingError::Serde(e.to_string())))?;
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+        let tmp_path = std::path::PathBuf::from(tmp_path);
+        std::fs::File::create(&tmp_path)
+            .and_then(|mut file| {
+                file.write_all(&contents)?;
+                file.sync_all()
+            })
+            .and_then(|()| std::fs::rename(&tmp_path, path))
+            .map_err(|e| {
+                Error::EthereumBridge(EthereumBridgeError::RelayState(
+                    e.to_string(),
+                ))
+            })
+    }
+
+    /// Check if this [`RelayState`] belongs to a relay of the
+    /// given `transfers`, regardless of their order.
+    fn relays(&self, transfers: &[KeccakHash]) -> bool {
+        let relayed: HashSet<_> = self.transfers.iter().collect();
+        let requested: HashSet<_> = transfers.iter().collect();
+        !relayed.is_empty() && relayed == requested
+    }
+
+    /// Remove the [`RelayState`] stored at the given path.
+    fn clear(path: &Path) -> Result<(), Error> {
+        if !path.exists() {
+            return Ok(());
+        }
+        std::fs::remove_file(path).map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::RelayState(
+                e.to_string(),
+            ))
+        })
+    }
+}
+
+/// The outcome of a Bridge pool relay attempt, as recorded in a
+/// relay audit log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RelayOutcome {
+    /// The relay transaction was confirmed.
+    Confirmed,
+    /// The relay transaction was mined, but reverted.
+    Reverted,
+    /// The relay transaction was dropped before being confirmed.
+    Timeout,
+    /// The relay transaction could not be sent, or its
+    /// confirmation could not be awaited.
+    Failed {
+        /// The error that caused the relay attempt to fail.
+        error: String,
+    },
+}
+
+impl RelayOutcome {
+    /// Determine the outcome of a mined relay transaction
+    /// from its receipt.
+    fn from_receipt(receipt: &TransactionReceipt) -> Self {
+        if receipt.status == Some(0u64.into()) {
+            Self::Reverted
+        } else {
+            Self::Confirmed
+        }
+    }
+}
+
+/// A record of a Bridge pool relay attempt, appended to a relay
+/// audit log as a line of json.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelayAuditRecord {
+    /// The time at which the attempt concluded.
+    pub timestamp: DateTimeUtc,
+    /// The nonce of the relayed Bridge pool batch.
+    pub batch_nonce: String,
+    /// The hashes of the relayed transfers.
+    #[serde(serialize_with = "serialize_hashes")]
+    pub transfers: Vec<KeccakHash>,
+    /// The gas limit of the relay transaction, if set.
+    pub gas_limit: Option<String>,
+    /// The gas price of the relay transaction, in wei, if set.
+    pub gas_price: Option<String>,
+    /// The hash of the relay transaction, if it was sent.
+    pub tx_hash: Option<H256>,
+    /// The outcome of the attempt.
+    pub outcome: RelayOutcome,
+    /// The gas used by the relay transaction, if it was mined.
+    pub gas_used: Option<u64>,
+}
+
+impl RelayAuditRecord {
+    /// Append this record to the audit log at `path`, as a
+    /// single line of json.
+    ///
+    /// The log is only ever appended to, and each record is
+    /// synced to disk before returning, such that previously
+    /// audited attempts survive crashes.
+    fn append(&self, path: &Path) -> Result<(), Error> {
+        let mut line = serde_json::to_vec(self)
+            .map_err(|e| Error::Encode(EncodingError::Serde(e.to_string())))?;
+        line.push(b'\n');
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| {
+                file.write_all(&line)?;
+                file.sync_data()
+            })
+            .map_err(|e| {
+                Error::EthereumBridge(EthereumBridgeError::RelayAuditLog(
+                    format!("{}: {e}", path.display()),
+                ))
+            })
+    }
+}
+
+/// Resume a relay operation that was interrupted before being
+/// confirmed on Ethereum, whose state was persisted to `state_file`.
+///
+/// Returns `true` if the interrupted relay has been successfully
+/// confirmed, in which case there is no need to relay again.
+///
+/// The relay is only resumed if it relayed the requested
+/// `transfers`, otherwise an error is returned, leaving the
+/// state file untouched.
+async fn resume_interrupted_relay<E>(
+    eth_client: &E,
+    io: &impl Io,
+    state_file: &Path,
+    transfers: &[KeccakHash],
+    confirmations: u64,
+) -> Result<bool, Error>
+where
+    E: Middleware,
+    E::Error: std::fmt::Display,
+{
+    let Some(state) = RelayState::load(state_file)? else {
+        return Ok(false);
+    };
+    if !state.relays(transfers) {
+        return Err(Error::EthereumBridge(EthereumBridgeError::RelayState(
+            echo_error!(
+                io,
+                "The relay state in {} belongs to a relay of different \
+                 transfers than the requested ones. Remove it, or relay \
+                 the same transfers, to proceed.",
+                state_file.display()
+            ),
+        )));
+    }
+    let RelayState {
+        tx_hash,
+        batch_nonce,
+        ..
+    } = state;
+    display_line!(
+        io,
+        "Resuming interrupted relay of Bridge pool batch {batch_nonce}, in \
+         Ethereum transaction {tx_hash:?}"
+    );
+
+    let sent_tx = eth_client.get_transaction(tx_hash).await.map_err(|e| {
+        Error::EthereumBridge(EthereumBridgeError::Rpc(e.to_string()))
+    })?;
+    if sent_tx.is_none() {
+        display_line!(
+            io,
+            "The interrupted relay is no longer known to the Ethereum node. \
+             Relaying again."
+        );
+        RelayState::clear(state_file)?;
+        return Ok(false);
+    }
+
+    let receipt = PendingTransaction::new(tx_hash, eth_client.provider())
+        .confirmations(confirmations as usize)
+        .await
+        .map_err(|e| {
+            Error::EthereumBridge(EthereumBridgeError::Rpc(e.to_string()))
+        })?;
+    RelayState::clear(state_file)?;
+
+    let succeeded = receipt
+        .as_ref()
+        .and_then(|receipt| receipt.status)
+        .map(|status| status.as_u64() == 1)
+        .unwrap_or(false);
+    if succeeded {
+        display_line!(io, "{receipt:?}");
+    } else {
+        display_line!(
+            io,
+            "The interrupted relay did not succeed: {receipt:?}. Relaying \
+             again."
+        );
+    }
+    Ok(succeeded)
+}
+
+/// The minimum number of Ethereum confirmations to wait for,
+/// before a relay is deemed confirmed.
+///
+/// The block including the relay transaction counts as its
+/// first confirmation.
+pub const MIN_RELAY_CONFIRMATIONS: u64 = 1;
+
+/// The number of Ethereum confirmations past which a warning is
+/// emitted, since Ethereum blocks are finalized well before then.
+///
+/// This is equivalent to two epochs of the Ethereum beacon chain.
+pub const WARN_RELAY_CONFIRMATIONS: u64 = 64;
+
+/// Validate the number of Ethereum confirmations to wait for
+/// in a relay.
+///
+/// Zero confirmations are raised to [`MIN_RELAY_CONFIRMATIONS`],
+/// and a warning is emitted if waiting for more confirmations
+/// than [`WARN_RELAY_CONFIRMATIONS`].
+pub(super) fn validate_relay_confirmations(
+    io: &impl Io,
+    confirmations: u64,
+) -> u64 {
+    if confirmations < MIN_RELAY_CONFIRMATIONS {
+        edisplay_line!(
+            io,
+            "Waiting for {MIN_RELAY_CONFIRMATIONS} confirmation(s) of the \
+             relay, instead of {confirmations}"
+        );
+        return MIN_RELAY_CONFIRMATIONS;
+    }
+    if confirmations > WARN_RELAY_CONFIRMATIONS {
+        edisplay_line!(
+            io,
+            "Warning: Waiting for {confirmations} confirmations of the relay. \
+             Ethereum blocks are usually finalized after \
+             {WARN_RELAY_CONFIRMATIONS} confirmations, so the relay may take \
+             much longer than necessary to complete."
+        );
+    }
+    confirmations
+}
+
+/// The default maximum size of the calldata of a relay transaction,
+/// in bytes.
+///
+/// Matches the maximum size of the transactions accepted into the
+/// mempools of most Ethereum nodes.
+pub const DEFAULT_MAX_RELAY_CALLDATA_SIZE: u64 = 128 * 1024;
+
+/// Split a batch of transfers, whose relay calldata has `calldata_size`
+/// bytes, into smaller batches, each expected to fit in `max_size`
+/// bytes of calldata.
+///
+/// The size of a proof is assumed to grow linearly with the number
+/// of relayed transfers. Since proofs also include the signatures of
+/// the validator set, which do not shrink with smaller batches, the
+/// resulting batches may still need to be split further.
+pub fn split_transfer_batch(
+    transfers: &[KeccakHash],
+    calldata_size: u64,
+    max_size: u64,
+) -> Vec<Vec<KeccakHash>> {
+    if transfers.is_empty() || max_size == 0 {
+        return vec![];
+    }
+    // round up, to make sure each batch fits in `max_size` bytes
+    let batches = ((calldata_size + max_size - 1) / max_size).max(1) as usize;
+    let batch_len = (transfers.len() + batches - 1) / batches;
+    transfers
+        .chunks(batch_len)
+        .map(|batch| batch.to_vec())
+        .collect()
+}
+
+/// Relay a backlog of Bridge pool roots, in order of their nonces.
+///
+/// Up to `max_pending_proofs` proofs of upcoming roots are constructed
+/// concurrently, while the relay of the current root confirms. Relays
+/// themselves are never parallelized, since the Bridge contract only
+/// accepts the root whose nonce follows the one it last saw. The nonce
+/// of each constructed proof must follow the nonce of the previously
+/// relayed proof, otherwise the pipeline is halted.
+pub async fn pipeline_relays<R, P, C, CF, S, SF>(
+    roots: impl IntoIterator<Item = R>,
+    max_pending_proofs: NonZeroUsize,
+    construct: C,
+    mut relay: S,
+) -> Result<(), Error>
+where
+    C: FnMut(R) -> CF,
+    CF: Future<Output = Result<(U256, P), Error>>,
+    S: FnMut(P) -> SF,
+    SF: Future<Output = Result<(), Error>>,
+{
+    let mut proofs = stream::iter(roots)
+        .map(construct)
+        .buffered(max_pending_proofs.get());
+    let mut next_proof = proofs.next().await.transpose()?;
+    let mut last_nonce: Option<U256> = None;
+    while let Some((batch_nonce, proof)) = next_proof {
+        if let Some(last_nonce) = last_nonce {
+            let expected = last_nonce.checked_add(U256::one());
+            if expected != Some(batch_nonce) {
+                return Err(Error::EthereumBridge(
+                    EthereumBridgeError::PipelineNonceGap {
+                        expected: expected
+                            .map(|nonce| nonce.to_string())
+                            .unwrap_or_else(|| "none".into()),
+                        got: batch_nonce.to_string(),
+                    },
+                ));
+            }
+        }
+        // NB: polling the stream of proofs drives the construction
+        // of the upcoming proofs, while the current relay confirms
+        let (relayed, upcoming) =
+            future::join(relay(proof), proofs.next()).await;
+        relayed?;
+        last_nonce = Some(batch_nonce);
+        next_proof = upcoming.transpose()?;
+    }
+    Ok(())
+}
+
+/// The maximum number of times the gas price of a relay transaction
+/// is escalated, after being rejected as underpriced.
+const MAX_GAS_PRICE_ESCALATIONS: usize = 5;
+
+/// Check if an error returned while sending a relay transaction
+/// was caused by its gas price being too low.
+fn is_underpriced_error(err: &Error) -> bool {
+    err.to_string().to_lowercase().contains("underpriced")
+}
+
+/// Check if an error returned while sending a relay transaction
+/// cannot be recovered from by sending it again.
+fn is_permanent_send_error(err: &Error) -> bool {
+    let err = err.to_string().to_lowercase();
+    err.contains("nonce too low") || err.contains("insufficient funds")
+}
+
+/// Bump the given gas price by `bump_percent` percent.
+fn escalate_gas_price(gas_price: U256, bump_percent: u64) -> U256 {
+    gas_price.saturating_add(
+        gas_price.saturating_mul(bump_percent.into()) / U256::from(100u64),
+    )
+}
+
+/// Read the maximum amount of time to wait on each request to the
+/// Ethereum node from [`ENV_VAR_ETH_REQUEST_TIMEOUT`].
+///
+/// Malformed durations are rejected, rather than silently lifting
+/// the timeout.
+fn eth_request_timeout_from_env(
+    io: &impl Io,
+) -> Result<Option<Duration>, Error> {
+    let Ok(timeout) = std::env::var(ENV_VAR_ETH_REQUEST_TIMEOUT) else {
+        return Ok(None);
+    };
+    parse_duration::parse(&timeout).map(Some).map_err(|err| {
+        Error::Other(echo_error!(
+            io,
+            "Invalid duration {timeout:?} in \
+             {ENV_VAR_ETH_REQUEST_TIMEOUT}: {err}"
+        ))
+    })
+}
+
+/// Await a request to the Ethereum node, bounded by an optional
+/// timeout.
+///
+/// The `operation` describes the request being awaited, and is
+/// reported back to the user if it times out.
+async fn with_eth_request_timeout<F, T>(
+    timeout: Option<Duration>,
+    operation: &str,
+    request: F,
+) -> Result<T, Error>
+where
+    F: Future<Output = Result<T, Error>>,
+{
+    let Some(timeout) = timeout else {
+        return request.await;
+    };
+    time::timeout(timeout, request).await.map_err(|_| {
+        Error::EthereumBridge(EthereumBridgeError::RequestTimeout(
+            operation.into(),
+        ))
+    })?
+}
+
+/// Await a query to the ledger node, bounded by `timeout`.
+///
+/// The `query` describes the request being awaited, and is
+/// reported back to the user if it times out.
+async fn with_query_timeout<F, T>(
+    io: &impl Io,
+    timeout: Duration,
+    query: &str,
+    request: F,
+) -> Result<T, Error>
+where
+    F: Future<Output = Result<T, Error>>,
+{
+    time::timeout(timeout, request).await.map_err(|_| {
+        edisplay_line!(io, "Timed out waiting for the ledger node to {query}");
+        Error::EthereumBridge(EthereumBridgeError::QueryTimeout(query.into()))
+    })?
+}
+
+/// Query the status of a set of transfers to Ethreum, indexed
+/// by their keccak hash.
+///
+/// Any unrecognized hashes (i.e. not pertaining to transfers
+/// in Namada's event log nor the Bridge pool) will be flagged
+/// as such. Unrecognized transfers could have been relayed to
+/// Ethereum, or could have expired from the Bridge pool. If
+/// these scenarios verify, it should be possible to retrieve
+/// the status of these transfers by querying CometBFT's block
+/// data.
+pub async fn query_eth_transfer_status<C, T>(
+    client: &C,
+    transfers: T,
+) -> Result<TransferToEthereumStatus, Error>
+where
+    C: Client + Sync,
+    T: Into<HashSet<KeccakHash>>,
+{
+    RPC.shell()
+        .eth_bridge()
+        .pending_eth_transfer_status(
+            client,
+            Some(transfers.into().serialize_to_vec()),
+            None,
+            false,
+        )
+        .await
+        .map_err(|e| Error::Query(QueryError::General(e.to_string())))
+        .map(|result| result.data)
+}
+
+mod recommendations {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use namada_core::types::ethereum_events::Uint as EthUint;
+    use namada_core::types::uint::{self, Uint, I256};
+    use namada_vote_ext::validator_set_update::{
+        EthAddrBook, VotingPowersMap, VotingPowersMapExt,
+    };
+
+    use super::*;
+    use crate::edisplay_line;
+    use crate::eth_bridge::storage::bridge_pool::get_nonce_key;
+    use crate::io::{Io, NullIo};
+
+    const fn unsigned_transfer_fee() -> Uint {
+        Uint::from_u64(37_500_u64)
+    }
+
+    const fn transfer_fee() -> I256 {
+        I256(unsigned_transfer_fee())
+    }
+
+    const fn signature_fee() -> Uint {
+        Uint::from_u64(24_500)
+    }
+
+    const fn valset_fee() -> Uint {
+        Uint::from_u64(2000)
+    }
+
+    /// The different states while trying to solve
+    /// for a recommended batch of transfers.
+    struct AlgorithState {
+        /// We are scanning transfers that increase
+        /// net profits to the relayer. However, we
+        /// are not in the feasible region.
+        profitable: bool,
+        /// We are scanning solutions that satisfy the
+        /// requirements of the input.
+        feasible_region: bool,
+    }
+
+    /// The algorithm exhibits two different remmondation strategies
+    /// depending on whether the user is will to accept a positive cost
+    /// for relaying.
+    #[derive(PartialEq)]
+    enum AlgorithmMode {
+        /// Only keep profitable transactions
+        Greedy,
+        /// Allow transactions which are not profitable
+        Generous,
+    }
+
+    /// The constraint that governed a decision of the recommender
+    /// over an eligible transfer.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    enum TraceReason {
+        /// The batch is within the gas and cost limits.
+        WithinLimits,
+        /// The transfer is profitable, but the batch is not yet within
+        /// the gas and cost limits.
+        NotYetWithinLimits,
+        /// The batch exceeds the gas limit.
+        ExceedsMaxGas,
+        /// The batch exceeds the cost limit.
+        ExceedsMaxCost,
+        /// Unprofitable transfers are not relayed in greedy mode.
+        Greedy,
+        /// Unprofitable transfers are relayed in generous mode.
+        Generous,
+    }
+
+    impl std::fmt::Display for TraceReason {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Self::WithinLimits => write!(f, "within the limits"),
+                Self::NotYetWithinLimits => {
+                    write!(f, "profitable, not yet within the limits")
+                }
+                Self::ExceedsMaxGas => write!(f, "exceeds the max gas"),
+                Self::ExceedsMaxCost => write!(f, "exceeds the max cost"),
+                Self::Greedy => write!(f, "unprofitable, greedy mode"),
+                Self::Generous => write!(f, "unprofitable, generous mode"),
+            }
+        }
+    }
+
+    /// A decision of the recommender over an eligible transfer,
+    /// printed when tracing its walk over the Bridge pool.
+    #[derive(Debug)]
+    struct TraceStep<'hash> {
+        /// Hash of the transfer.
+        hash: &'hash str,
+        /// Cost of relaying the transfer, in gwei.
+        cost: I256,
+        /// Gas of the batch including the transfer, in gwei.
+        total_gas: Uint,
+        /// Cost of the batch including the transfer, in gwei.
+        total_cost: I256,
+        /// Whether the transfer was added to the batch.
+        added: bool,
+        /// The constraint that governed the decision.
+        reason: TraceReason,
+    }
+
+    impl std::fmt::Display for TraceStep<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let Self {
+                hash,
+                cost,
+                total_gas,
+                total_cost,
+                added,
+                reason,
+            } = self;
+            let decision = if *added { "added" } else { "not added" };
+            write!(
+                f,
+                "{hash}: cost {cost}, total gas {total_gas}, total cost \
+                 {total_cost}, {decision} ({reason})"
+            )
+        }
+    }
+
+    /// Classify the limit exceeded by a batch with the given totals.
+    fn exceeded_limit(
+        total_gas: Uint,
+        total_cost: I256,
+        max_gas: Uint,
+        max_cost: I256,
+    ) -> Option<TraceReason> {
+        if total_gas > max_gas {
+            Some(TraceReason::ExceedsMaxGas)
+        } else if total_cost > max_cost {
+            Some(TraceReason::ExceedsMaxCost)
+        } else {
+            None
+        }
+    }
+
+    /// Transfer to Ethereum that is eligible to be recommended
+    /// for a relay operation, generating a profit.
+    ///
+    /// This means that the underlying Ethereum event has not
+    /// been "seen" yet, and that the user provided appropriate
+    /// conversion rates to gwei for the gas fee token in
+    /// the transfer.
+    #[derive(Debug, Eq, PartialEq)]
+    struct EligibleRecommendation {
+        /// Pending transfer to Ethereum.
+        pending_transfer: PendingTransfer,
+        /// Hash of the [`PendingTransfer`].
+        transfer_hash: String,
+        /// Cost of relaying the transfer, in gwei, with its fees
+        /// weighted by how much the relayer values their token.
+        cost: I256,
+        /// Cost of relaying the transfer, in gwei, with its fees
+        /// valued at their conversion rate alone.
+        unweighted_cost: I256,
+    }
+
+    /// Batch of recommended transfers to Ethereum that generate
+    /// a profit after a relay operation.
+    #[derive(Debug, Eq, PartialEq)]
+    struct RecommendedBatch {
+        /// Hashes of the recommended transfers to be relayed.
+        transfer_hashes: Vec<String>,
+        /// Estimate of the total fees, measured in gwei, that will be paid
+        /// on Ethereum.
+        ethereum_gas_fees: Uint,
+        /// Net profitt in gwei, based on the conversion rates provided
+        /// to the algorithm.
+        net_profit: I256,
+        /// Gas fees paid by the transfers considered for relaying,
+        /// paid in various token types.
+        bridge_pool_gas_fees: HashMap<String, Uint>,
+    }
+
+    /// Statistics about a run of the Bridge pool recommender.
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct RecommendationStats {
+        /// Number of transfers eligible to be recommended.
+        pub eligible_transfers: usize,
+        /// Number of signed transfers excluded from the recommendation,
+        /// since they are already in the process of being relayed.
+        pub in_progress_excluded: usize,
+        /// Number of recommended transfers.
+        pub recommended_count: usize,
+        /// The hashes of the recommended transfers.
+        pub recommended_transfers: Vec<KeccakHash>,
+        /// Number of recommended transfers whose relay has already
+        /// been backed by a quorum of the voting power.
+        pub recommended_at_quorum: usize,
+        /// Estimate of the Ethereum gas fees (in gwei) of relaying the
+        /// recommended batch, if any.
+        pub estimated_gas: Option<Uint>,
+        /// Estimate of the net profit (in gwei) of relaying the
+        /// recommended batch, if any.
+        pub estimated_net_profit: Option<I256>,
+    }
+
+    /// Relay status of a recommended transfer, as observed by the
+    /// validator set through the progress of its transfer to Ethereum.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum RelayReadiness {
+        /// No validator has backed the relay of the transfer yet.
+        Unbacked,
+        /// The relay of the transfer is still accumulating backing,
+        /// with the given fraction of the voting power.
+        Accumulating(FractionalVotingPower),
+        /// The relay of the transfer is backed by a quorum of the
+        /// voting power.
+        Quorum,
+    }
+
+    impl RelayReadiness {
+        /// Classify a transfer by the voting power backing its relay,
+        /// if any has been observed.
+        pub fn new(voting_power: Option<FractionalVotingPower>) -> Self {
+            match voting_power {
+                Some(power) if power > FractionalVotingPower::TWO_THIRDS => {
+                    Self::Quorum
+                }
+                Some(power) if power > FractionalVotingPower::NULL => {
+                    Self::Accumulating(power)
+                }
+                _ => Self::Unbacked,
+            }
+        }
+    }
+
+    impl std::fmt::Display for RelayReadiness {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Self::Unbacked => write!(f, "no backing yet"),
+                Self::Accumulating(power) => {
+                    write!(
+                        f,
+                        "accumulating backing ({power} of the voting power)"
+                    )
+                }
+                Self::Quorum => write!(f, "quorum reached"),
+            }
+        }
+    }
+
+    /// Query the gas cost, in gwei, of hashing the validator set and
+    /// checking a quorum of signatures over the latest signed Bridge
+    /// pool root. Every relayed batch bears this cost once.
+    async fn query_validator_gas<IO: Io>(
+        client: &(impl Client + Sync),
+        io: &IO,
+        rpc_timeout: Duration,
+    ) -> Result<Uint, Error> {
+        // get the signed bridge pool root so we can analyze the signatures
+        // the estimate the gas cost of verifying them.
+        let (bp_root, height) =
+            <(BridgePoolRootProof, BlockHeight)>::try_from_slice(
+                &with_query_timeout(
+                    io,
+                    rpc_timeout,
+                    "read the signed Bridge pool root",
+                    RPC.shell()
+                        .storage_value(
+                            client,
+                            None,
+                            None,
+                            false,
+                            &get_signed_root_key(),
+                        )
+                        .map(|result| {
+                            result.map_err(|err| {
+                                Error::Query(QueryError::General(echo_error!(
+                                    io,
+                                    "Failed to query Bridge pool proof: {err}"
+                                )))
+                            })
+                        }),
+                )
+                .await?
+                .data,
+            )
+            .map_err(|err| {
+                Error::Encode(EncodingError::Decoding(echo_error!(
+                    io,
+                    "Failed to decode Bridge pool proof: {err}"
+                )))
+            })?;
+
+        // get the latest bridge pool nonce
+        let latest_bp_nonce = EthUint::try_from_slice(
+            &with_query_timeout(
+                io,
+                rpc_timeout,
+                "read the Bridge pool nonce",
+                RPC.shell()
+                    .storage_value(client, None, None, false, &get_nonce_key())
+                    .map(|result| {
+                        result.map_err(|err| {
+                            Error::Query(QueryError::General(echo_error!(
+                                io,
+                                "Failed to query Bridge pool nonce: {err}"
+                            )))
+                        })
+                    }),
+            )
+            .await?
+            .data,
+        )
+        .map_err(|err| {
+            Error::Encode(EncodingError::Decoding(echo_error!(
+                io,
+                "Failed to decode Bridge pool nonce: {err}"
+            )))
+        })?;
+
+        if latest_bp_nonce != bp_root.data.1 {
+            edisplay_line!(
+                io,
+                "The signed Bridge pool nonce is not up to date, repeat this \
+                 query at a later time"
+            );
+            return Err(Error::EthereumBridge(
+                EthereumBridgeError::InvalidBpNonce,
+            ));
+        }
+
+        // Get the voting powers of each of validator who signed
+        // the above root.
+        let voting_powers = with_query_timeout(
+            io,
+            rpc_timeout,
+            "report the voting powers of the validators",
+            RPC.shell()
+                .eth_bridge()
+                .voting_powers_at_height(client, &height)
+                .map(|result| {
+                    result.map_err(|e| {
+                        Error::EthereumBridge(
+                            EthereumBridgeError::QueryVotingPowers(
+                                e.to_string(),
+                            ),
+                        )
+                    })
+                }),
+        )
+        .await?;
+        ensure_voting_powers(io, &voting_powers, height)?;
+        let valset_size = Uint::from_u64(voting_powers.len() as u64);
+
+        let (sig_checks, achieved_power) =
+            signature_checks(voting_powers, &bp_root.signatures);
+        if achieved_power <= FractionalVotingPower::TWO_THIRDS {
+            edisplay_line!(
+                io,
+                "Warning: The signed Bridge pool root is only backed by \
+                 {achieved_power} of the voting power"
+            );
+        }
+
+        Ok(signature_fee() * sig_checks + valset_fee() * valset_size)
+    }
+
+    /// Filter out the conversion rates of `conversion_table` set longer
+    /// than `max_age` ago, warning about each of them. The transfers
+    /// paying fees in the filtered out tokens cannot be recommended.
+    ///
+    /// Conversion rates without a timestamp are never deemed stale.
+    fn fresh_conversion_rates<IO: Io>(
+        io: &IO,
+        conversion_table: &HashMap<Address, args::BpConversionTableEntry>,
+        max_age: Option<Duration>,
+        now: DateTimeUtc,
+    ) -> HashMap<Address, args::BpConversionTableEntry> {
+        let Some(max_age) = max_age else {
+            return conversion_table.clone();
+        };
+        conversion_table
+            .iter()
+            .filter(|(_, entry)| {
+                let Some(as_of) = entry.as_of else {
+                    return true;
+                };
+                // NB: rates set in the future are deemed fresh
+                let Ok(age) = now.0.signed_duration_since(as_of.0).to_std()
+                else {
+                    return true;
+                };
+                if age <= max_age {
+                    return true;
+                }
+                edisplay_line!(
+                    io,
+                    "Warning: The conversion rate of {} was set {}s ago, \
+                     exceeding the maximum age of {}s. Transfers paying fees \
+                     in this token are not recommended.",
+                    entry.alias,
+                    age.as_secs(),
+                    max_age.as_secs(),
+                );
+                false
+            })
+            .map(|(token, entry)| (token.clone(), entry.clone()))
+            .collect()
+    }
+
+    /// Recommend the most economical batch of transfers to relay based
+    /// on a conversion rate estimates from NAM to ETH and gas usage
+    /// heuristics.
+    ///
+    /// Returns statistics about the run of the recommender, which
+    /// are also emitted as a `tracing` event.
+    pub async fn recommend_batch(
+        context: &impl Namada,
+        args: args::RecommendBatch,
+        progress: Option<&ProgressSender>,
+    ) -> Result<RecommendationStats, Error> {
+        ensure_bridge_active(context.client(), context.io()).await?;
+        let conversion_table = fresh_conversion_rates(
+            context.io(),
+            &args.conversion_table,
+            args.max_rate_age,
+            DateTimeUtc::now(),
+        );
+
+        report_progress(progress, ProgressEvent::FetchingPool);
+        // get transfers that can already been relayed but are awaiting a quorum
+        // of backing votes, and whose backing voting power is above the
+        // configured threshold. the backing of every transfer is kept
+        // around, to annotate the recommended batch with it.
+        let mut in_progress = BTreeSet::new();
+        let mut backing = HashMap::new();
+        let snapshot = with_query_timeout(
+            context.io(),
+            args.rpc_timeout,
+            "report the relay progress of transfers to Ethereum",
+            query_transfer_to_ethereum_snapshot(context.client()),
+        )
+        .await?;
+        for (pending, voting_power) in snapshot {
+            let hash = pending.keccak256().to_hex();
+            if voting_power > args.in_progress_threshold {
+                in_progress.insert(hash.clone());
+            }
+            backing.insert(hash, voting_power);
+        }
+
+        report_progress(progress, ProgressEvent::ComputingCosts);
+        // This is the gas cost for hashing the validator set and
+        // checking a quorum of signatures (in gwei).
+        let validator_gas = query_validator_gas(
+            context.client(),
+            context.io(),
+            args.rpc_timeout,
+        )
+        .await?;
+
+        // we don't recommend transfers that have already been relayed
+        let router = eth_bridge_router(None);
+        let signed_pool = with_query_timeout(
+            context.io(),
+            args.rpc_timeout,
+            "read the signed contents of the Bridge pool",
+            query_signed_bridge_pool_contents(
+                context.client(),
+                context.io(),
+                &router,
+                None,
+                args.max_transfers,
+            ),
+        )
+        .await?;
+        let in_progress_excluded = signed_pool
+            .keys()
+            .filter(|hash| in_progress.contains(*hash))
+            .count();
+        let eligible = generate_eligible(
+            context.io(),
+            &conversion_table,
+            &in_progress,
+            signed_pool,
+        )?;
+        let eligible_transfers = eligible.len();
+
+        let max_gas =
+            args.max_gas.map(Uint::from_u64).unwrap_or(uint::MAX_VALUE);
+        let max_cost = args.gas.map(I256::from).unwrap_or_default();
+
+        let generate = match args.objective {
+            args::Objective::MaxProfit => generate_recommendations,
+            args::Objective::MaxThroughput => {
+                generate_throughput_recommendations
+            }
+        };
+        let recommendation = generate(
+            context.io(),
+            eligible,
+            &conversion_table,
+            validator_gas,
+            max_gas,
+            max_cost,
+            args.trace,
+        )?;
+        let recommendation = enforce_min_batch_size(
+            context.io(),
+            recommendation,
+            args.min_batch_size,
+        );
+
+        let recommended_transfers = recommendation
+            .as_ref()
+            .map(|batch| {
+                batch
+                    .transfer_hashes
+                    .iter()
+                    .map(|hash| KeccakHash::try_from(hash.as_str()))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()
+            .map_err(|err| {
+                Error::Encode(EncodingError::Decoding(echo_error!(
+                    context.io(),
+                    "Failed to decode the hash of a recommended transfer: \
+                     {err}"
+                )))
+            })?
+            .unwrap_or_default();
+        let stats = RecommendationStats {
+            eligible_transfers,
+            in_progress_excluded,
+            recommended_count: recommended_transfers.len(),
+            recommended_transfers,
+            recommended_at_quorum: recommendation
+                .as_ref()
+                .map(|batch| {
+                    batch
+                        .transfer_hashes
+                        .iter()
+                        .filter(|hash| {
+                            RelayReadiness::new(backing.get(*hash).copied())
+                                == RelayReadiness::Quorum
+                        })
+                        .count()
+                })
+                .unwrap_or_default(),
+            estimated_gas: recommendation
+                .as_ref()
+                .map(|batch| batch.ethereum_gas_fees),
+            estimated_net_profit: recommendation
+                .as_ref()
+                .map(|batch| batch.net_profit),
+        };
+        tracing::info!(
+            eligible_transfers = stats.eligible_transfers,
+            in_progress_excluded = stats.in_progress_excluded,
+            recommended_count = stats.recommended_count,
+            recommended_at_quorum = stats.recommended_at_quorum,
+            estimated_gas = ?stats.estimated_gas,
+            estimated_net_profit = ?stats.estimated_net_profit,
+            "Ran the Bridge pool recommender"
+        );
+
+        match recommendation {
+            Some(RecommendedBatch { net_profit, .. })
+                if args.only_profitable && !net_profit.is_positive() =>
+            {
+                return Err(Error::EthereumBridge(
+                    EthereumBridgeError::NoProfitableBatch(echo_error!(
+                        context.io(),
+                        "The recommended batch yields a net loss of {}",
+                        format_gwei(-net_profit)
+                    )),
+                ));
+            }
+            Some(RecommendedBatch {
+                transfer_hashes,
+                ethereum_gas_fees,
+                net_profit,
+                bridge_pool_gas_fees,
+            }) => {
+                let gas_fees =
+                    I256::try_from(ethereum_gas_fees).map_err(|err| {
+                        Error::Encode(EncodingError::Conversion(echo_error!(
+                            context.io(),
+                            "Failed to convert value to I256: {err}"
+                        )))
+                    })?;
+                let fee_revenue = net_profit + gas_fees;
+                display_line!(context.io(), "Recommended batch:");
+                for hash in &transfer_hashes {
+                    let readiness =
+                        RelayReadiness::new(backing.get(hash).copied());
+                    display_line!(context.io(), "  {hash}: {readiness}");
+                }
+                if args.sort_by_hash {
+                    display_line!(context.io(), "Recommended batch by hash:");
+                    for hash in sorted_by_hash(&transfer_hashes) {
+                        display_line!(context.io(), "  {hash}");
+                    }
+                }
+                display_line!(
+                    context.io(),
+                    "Estimated Ethereum transaction gas: {}",
+                    format_gwei(gas_fees),
+                );
+                display_line!(
+                    context.io(),
+                    "Estimated net profit: {}",
+                    format_gwei(net_profit),
+                );
+                display_line!(
+                    context.io(),
+                    "Break-even gas price: {}",
+                    format_uint_gwei(break_even_gas_price(
+                        ethereum_gas_fees,
+                        fee_revenue
+                    ))
+                );
+                display_line!(
+                    context.io(),
+                    "Total fees: {bridge_pool_gas_fees:#?}"
+                );
+            }
+            None if args.only_profitable => {
+                return Err(Error::EthereumBridge(
+                    EthereumBridgeError::NoProfitableBatch(echo_error!(
+                        context.io(),
+                        "No batch of transfers satisfying the input \
+                         parameters yields a net profit"
+                    )),
+                ));
+            }
+            None => {
+                display_line!(
+                    context.io(),
+                    "Unable to find a recommendation satisfying the input \
+                     parameters."
+                );
+            }
+        }
+
+        Ok(stats)
+    }
+
+    /// Sort the hashes of a batch of transfers lexicographically,
+    /// to be displayed in an order independent of their profitability.
+    fn sorted_by_hash(transfer_hashes: &[String]) -> Vec<&str> {
+        let mut hashes: Vec<_> =
+            transfer_hashes.iter().map(String::as_str).collect();
+        hashes.sort_unstable();
+        hashes
+    }
+
+    /// The number of gwei in one ether.
+    const GWEI_PER_ETH: u64 = 1_000_000_000;
+
+    /// The smallest amount of gwei, in absolute value, whose
+    /// equivalent in ether is rendered by [`format_gwei`].
+    const MIN_FORMATTED_ETH_IN_GWEI: u64 = 1_000_000;
+
+    /// Render an amount of gwei with thousands separators, e.g.
+    /// `1,234,567,890 gwei (1.23456789 ETH)`.
+    ///
+    /// The equivalent amount of ether is only rendered for amounts
+    /// of at least 0.001 ETH, in absolute value.
+    pub fn format_gwei(value: I256) -> String {
+        let sign = if value.is_negative() { "-" } else { "" };
+        let gwei = value.abs();
+        let digits = gwei.to_string();
+        let mut grouped = String::with_capacity(digits.len() * 4 / 3);
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                grouped.push(',');
+            }
+            grouped.push(digit);
+        }
+        if gwei < Uint::from(MIN_FORMATTED_ETH_IN_GWEI) {
+            return format!("{sign}{grouped} gwei");
+        }
+        let gwei_per_eth = Uint::from(GWEI_PER_ETH);
+        let whole_eth = gwei / gwei_per_eth;
+        let fractional_eth = format!("{:09}", (gwei % gwei_per_eth).as_u64());
+        let fractional_eth = fractional_eth.trim_end_matches('0');
+        if fractional_eth.is_empty() {
+            format!("{sign}{grouped} gwei ({sign}{whole_eth} ETH)")
+        } else {
+            format!(
+                "{sign}{grouped} gwei ({sign}{whole_eth}.{fractional_eth} ETH)"
+            )
+        }
+    }
+
+    /// Render an unsigned amount of gwei with [`format_gwei`].
+    fn format_uint_gwei(value: Uint) -> String {
+        I256::try_from(value)
+            .map(format_gwei)
+            .unwrap_or_else(|_| format!("{value} gwei"))
+    }
+
+    /// Compute the maximum Ethereum gas price, in gwei per unit of
+    /// gas, at which relaying a batch that consumes `batch_gas` still
+    /// breaks even, given the fees (in gwei) it earns the relayer.
+    ///
+    /// The gas estimates of the recommender assume a gas price of
+    /// one gwei, such that `batch_gas` is also the cost of the relay
+    /// in gwei at that price.
+    pub fn break_even_gas_price(
+        batch_gas: Uint,
+        fee_revenue_gwei: I256,
+    ) -> Uint {
+        if !fee_revenue_gwei.is_positive() {
+            return Uint::zero();
+        }
+        fee_revenue_gwei
+            .abs()
+            .checked_div(batch_gas)
+            .unwrap_or(uint::MAX_VALUE)
+    }
+
+    /// Suggest the minimum fee, paid in `fee_token`, required for a
+    /// transfer to be profitable to relay, according to the gas model
+    /// of the recommender and the conversion rate of `fee_token` to gwei.
+    ///
+    /// Paying at least this fee ensures the relaying cost of the
+    /// transfer is non-positive.
+    pub fn suggest_min_fee(
+        fee_token: &Address,
+        conversion_table: &HashMap<Address, args::BpConversionTableEntry>,
+    ) -> Result<Amount, Error> {
+        let gwei_per_gas_token = gwei_per_token(fee_token, conversion_table)?;
+
+        // round up, to make sure the transfer fee is covered
+        let (min_fee, remainder) =
+            unsigned_transfer_fee().div_mod(gwei_per_gas_token);
+        let min_fee = if remainder.is_zero() {
+            min_fee
+        } else {
+            min_fee + uint::ONE
+        };
+
+        Amount::from_uint(min_fee, 0).map_err(|err| {
+            Error::Encode(EncodingError::Conversion(err.to_string()))
+        })
+    }
+
+    /// Express the sum of the given fees, paid in various tokens,
+    /// in gwei, according to the conversion rates in `conversion_table`.
+    pub fn total_fees_in_gwei(
+        total_fees: &HashMap<Address, Amount>,
+        conversion_table: &HashMap<Address, args::BpConversionTableEntry>,
+    ) -> Result<Uint, Error> {
+        total_fees
+            .iter()
+            .try_fold(Uint::zero(), |total, (token, amount)| {
+                let gwei_per_token = gwei_per_token(token, conversion_table)?;
+                Uint::from(*amount)
+                    .checked_mul(gwei_per_token)
+                    .and_then(|fees| total.checked_add(fees))
+                    .ok_or_else(|| {
+                        Error::EthereumBridge(EthereumBridgeError::RelayCost(
+                            "Overflowed calculating the total fees in gwei"
+                                .into(),
+                        ))
+                    })
+            })
+    }
+
+    /// Look up the amount of gwei a single unit of `token` is worth.
+    fn gwei_per_token(
+        token: &Address,
+        conversion_table: &HashMap<Address, args::BpConversionTableEntry>,
+    ) -> Result<Uint, Error> {
+        let conversion_rate = conversion_table
+            .get(token)
+            .map(|entry| entry.conversion_rate)
+            .ok_or_else(|| {
+                Error::EthereumBridge(EthereumBridgeError::RelayCost(format!(
+                    "No conversion rate to gwei was provided for {token}"
+                )))
+            })?;
+        if conversion_rate <= 0.0f64 || conversion_rate > 1e9 {
+            return Err(Error::EthereumBridge(EthereumBridgeError::RelayCost(
+                format!(
+                    "{token}: Invalid conversion rate {conversion_rate:.1}, \
+                     expected a value in the range ]0, 10^9]"
+                ),
+            )));
+        }
+        Ok(Uint::from_u64((1e9 / conversion_rate).floor() as u64))
+    }
+
+    /// Look up the amount of gwei a single unit of `token` is worth,
+    /// along with the same amount weighted by how much the relayer
+    /// values the fees paid in `token`.
+    fn weighted_gwei_per_token(
+        token: &Address,
+        conversion_table: &HashMap<Address, args::BpConversionTableEntry>,
+    ) -> Result<(Uint, Uint), Error> {
+        let gwei_per_token = gwei_per_token(token, conversion_table)?;
+        let entry = &conversion_table[token];
+        let weighted_gwei_per_token = match entry.fee_weight {
+            None => gwei_per_token,
+            Some(w) if w.is_finite() && w > 0.0f64 => {
+                Uint::from_u64((1e9 * w / entry.conversion_rate).floor() as u64)
+            }
+            Some(w) => {
+                return Err(Error::EthereumBridge(
+                    EthereumBridgeError::RelayCost(format!(
+                        "{token}: Invalid fee weight {w}, expected a \
+                         positive value"
+                    )),
+                ));
+            }
+        };
+        Ok((gwei_per_token, weighted_gwei_per_token))
+    }
+
+    /// Query the transfers in the Bridge pool whose fees can never cover
+    /// the marginal cost of relaying them, regardless of the batch they
+    /// are relayed in.
+    ///
+    /// Transfers whose fees are paid in tokens absent from
+    /// `conversion_table` are not reported, since their relaying
+    /// cost cannot be estimated.
+    pub async fn query_unrelayable_transfers<IO: Io>(
+        client: &(impl Client + Sync),
+        io: &IO,
+        conversion_table: &HashMap<Address, args::BpConversionTableEntry>,
+    ) -> Result<Vec<PendingTransfer>, Error> {
+        ensure_bridge_active(client, io).await?;
+        let pool = RPC
+            .shell()
+            .eth_bridge()
+            .read_ethereum_bridge_pool(client)
+            .await
+            .map_err(|e| {
+                Error::EthereumBridge(EthereumBridgeError::ReadBridgePool(
+                    e.to_string(),
+                ))
+            })?
+            .into_iter()
+            .map(|transfer| (transfer.keccak256().to_hex(), transfer))
+            .collect();
+        let eligible =
+            generate_eligible(io, conversion_table, &BTreeSet::new(), pool)?;
+        Ok(unrelayable(eligible))
+    }
+
+    /// Filter out the transfers whose fees do not cover their
+    /// marginal relaying cost.
+    fn unrelayable(
+        eligible: Vec<EligibleRecommendation>,
+    ) -> Vec<PendingTransfer> {
+        eligible
+            .into_iter()
+            .filter(|recommendation| {
+                recommendation.unweighted_cost.is_positive()
+            })
+            .map(|recommendation| recommendation.pending_transfer)
+            .collect()
+    }
+
+    /// The profitability of relaying a single transfer to Ethereum
+    /// on its own, according to the gas model of the recommender.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ProfitabilityReport {
+        /// The assessed transfer.
+        pub transfer: PendingTransfer,
+        /// The fees paid by the transfer, in gwei.
+        pub fees_gwei: Uint,
+        /// The gas cost of relaying the transfer in a batch of its
+        /// own, in gwei. This includes the cost of verifying the
+        /// signatures over the Bridge pool root.
+        pub cost_gwei: Uint,
+        /// The fees of the transfer minus its relaying cost, in gwei.
+        pub net_profit_gwei: I256,
+    }
+
+    impl ProfitabilityReport {
+        /// Check if relaying the transfer on its own yields a profit.
+        pub fn is_profitable(&self) -> bool {
+            self.net_profit_gwei.is_positive()
+        }
+    }
+
+    /// Assess the profitability of relaying the transfer with the
+    /// given `hash` in a batch of its own, which means it alone
+    /// bears the gas cost of verifying the validator signatures.
+    ///
+    /// Returns `None` if the transfer is not in the Bridge pool.
+    pub async fn is_profitable_standalone<IO: Io>(
+        client: &(impl Client + Sync),
+        io: &IO,
+        hash: &KeccakHash,
+        conversion_table: &HashMap<Address, args::BpConversionTableEntry>,
+        rpc_timeout: Duration,
+    ) -> Result<Option<ProfitabilityReport>, Error> {
+        ensure_bridge_active(client, io).await?;
+        let Some(transfer) = with_query_timeout(
+            io,
+            rpc_timeout,
+            "read the transfer from the Bridge pool",
+            query_bridge_pool_transfers(client, std::slice::from_ref(hash)),
+        )
+        .await?
+        .remove(hash)
+        .flatten() else {
+            return Ok(None);
+        };
+        let validator_gas =
+            query_validator_gas(client, io, rpc_timeout).await?;
+        standalone_profitability(transfer, validator_gas, conversion_table)
+            .map(Some)
+    }
+
+    /// Compute the [`ProfitabilityReport`] of a transfer relayed on its
+    /// own, given the gas cost of verifying the validator signatures.
+    fn standalone_profitability(
+        transfer: PendingTransfer,
+        validator_gas: Uint,
+        conversion_table: &HashMap<Address, args::BpConversionTableEntry>,
+    ) -> Result<ProfitabilityReport, Error> {
+        let overflow = || {
+            Error::EthereumBridge(EthereumBridgeError::RelayCost(
+                "Overflowed calculating the profitability of the transfer"
+                    .into(),
+            ))
+        };
+        let gwei_per_gas_token =
+            gwei_per_token(&transfer.gas_fee.token, conversion_table)?;
+        let fees_gwei = Uint::from(transfer.gas_fee.amount)
+            .checked_mul(gwei_per_gas_token)
+            .ok_or_else(overflow)?;
+        let cost_gwei = estimated_relay_gas(1, validator_gas);
+        let net_profit_gwei = I256::try_from(fees_gwei)
+            .ok()
+            .zip(I256::try_from(cost_gwei).ok())
+            .and_then(|(fees, cost)| fees.checked_sub(&cost))
+            .ok_or_else(overflow)?;
+        Ok(ProfitabilityReport {
+            transfer,
+            fees_gwei,
+            cost_gwei,
+            net_profit_gwei,
+        })
+    }
+
+    /// Estimate the Ethereum gas of relaying a batch of `transfers`,
+    /// whose checks of the signatures of the validator set consume
+    /// `validator_gas`.
+    pub fn estimated_relay_gas(transfers: usize, validator_gas: Uint) -> Uint {
+        unsigned_transfer_fee()
+            .saturating_mul(Uint::from_u64(transfers as u64))
+            .saturating_add(validator_gas)
+    }
+
+    /// The constraints a relayer places on the batches recommended
+    /// to it, which determine the competition a transfer faces to
+    /// be included in the next batch.
+    #[derive(Debug, Clone)]
+    pub struct RelayerConstraints {
+        /// The maximum amount of gas to spend.
+        pub max_gas: Option<u64>,
+        /// How much net gas the relayer is willing to pay.
+        pub gas: Option<u64>,
+        /// The objective optimized by the recommended batch.
+        pub objective: args::Objective,
+        /// The minimum number of transfers in a recommended batch.
+        pub min_batch_size: Option<usize>,
+        /// The maximum number of signed transfers read from the
+        /// Bridge pool.
+        pub max_transfers: usize,
+        /// Transfers backed by more than this fraction of the voting
+        /// power are deemed likely to have already been relayed, and
+        /// are not recommended.
+        pub in_progress_threshold: FractionalVotingPower,
+    }
+
+    impl Default for RelayerConstraints {
+        fn default() -> Self {
+            Self {
+                max_gas: None,
+                gas: None,
+                objective: args::Objective::default(),
+                min_batch_size: None,
+                max_transfers: DEFAULT_MAX_QUERIED_TRANSFERS,
+                in_progress_threshold: FractionalVotingPower::NULL,
+            }
+        }
+    }
+
+    impl From<&args::RecommendBatch> for RelayerConstraints {
+        fn from(args: &args::RecommendBatch) -> Self {
+            Self {
+                max_gas: args.max_gas,
+                gas: args.gas,
+                objective: args.objective,
+                min_batch_size: args.min_batch_size,
+                max_transfers: args.max_transfers,
+                in_progress_threshold: args.in_progress_threshold,
+            }
+        }
+    }
+
+    /// Compute the minimum fee, paid in the fee token of
+    /// `proposed_transfer`, at which it would be included in the
+    /// batch recommended to a relayer with the given `constraints`,
+    /// competing against the transfers currently in the Bridge pool.
+    ///
+    /// Unlike [`suggest_min_fee`], this accounts for the transfers
+    /// the proposed transfer would displace from the batch. The fee
+    /// in `proposed_transfer` is ignored.
+    pub async fn fee_to_make_next_batch<IO: Io>(
+        client: &(impl Client + Sync),
+        io: &IO,
+        proposed_transfer: PendingTransfer,
+        conversion_table: &HashMap<Address, args::BpConversionTableEntry>,
+        constraints: &RelayerConstraints,
+        rpc_timeout: Duration,
+    ) -> Result<Amount, Error> {
+        ensure_bridge_active(client, io).await?;
+        // transfers deemed to be in progress are never recommended,
+        // so they do not compete with the proposed transfer
+        let in_progress = with_query_timeout(
+            io,
+            rpc_timeout,
+            "report the relay progress of transfers to Ethereum",
+            query_transfer_to_ethereum_snapshot(client),
+        )
+        .await?
+        .into_iter()
+        .filter(|(_, voting_power)| {
+            *voting_power > constraints.in_progress_threshold
+        })
+        .map(|(pending, _)| pending.keccak256().to_hex())
+        .collect();
+        let validator_gas =
+            query_validator_gas(client, io, rpc_timeout).await?;
+        let router = eth_bridge_router(None);
+        let signed_pool = with_query_timeout(
+            io,
+            rpc_timeout,
+            "read the signed contents of the Bridge pool",
+            query_signed_bridge_pool_contents(
+                client,
+                io,
+                &router,
+                None,
+                constraints.max_transfers,
+            ),
+        )
+        .await?;
+        min_fee_to_make_batch(
+            proposed_transfer,
+            signed_pool,
+            &in_progress,
+            conversion_table,
+            validator_gas,
+            constraints,
+        )
+    }
+
+    /// Binary search the minimum fee at which `proposed_transfer`
+    /// is included in the batch recommended out of `pool`, ignoring
+    /// the transfers in `in_progress`.
+    ///
+    /// Raising the fee of a transfer only lowers its relaying cost,
+    /// so its inclusion in the recommended batch is assumed to be
+    /// monotonic in the fee it pays.
+    fn min_fee_to_make_batch(
+        proposed_transfer: PendingTransfer,
+        pool: HashMap<String, PendingTransfer>,
+        in_progress: &BTreeSet<String>,
+        conversion_table: &HashMap<Address, args::BpConversionTableEntry>,
+        validator_gas: Uint,
+        constraints: &RelayerConstraints,
+    ) -> Result<Amount, Error> {
+        let overflow = || {
+            Error::EthereumBridge(EthereumBridgeError::RelayCost(
+                "Overflowed calculating the fee to make the next batch".into(),
+            ))
+        };
+        let generate = match constraints.objective {
+            args::Objective::MaxProfit => generate_recommendations,
+            args::Objective::MaxThroughput => {
+                generate_throughput_recommendations
+            }
+        };
+        let max_gas = constraints
+            .max_gas
+            .map(Uint::from_u64)
+            .unwrap_or(uint::MAX_VALUE);
+        let max_cost = constraints.gas.map(I256::from).unwrap_or_default();
+        // the recommender weighs the fees of the transfers, so the
+        // bound on the fee is derived from their weighted worth
+        let (_, weighted_gwei_per_gas_token) = weighted_gwei_per_token(
+            &proposed_transfer.gas_fee.token,
+            conversion_table,
+        )?;
+
+        // the simulated runs of the recommender are silenced, to
+        // not flood the output with their diagnostics
+        let makes_batch = |fee: Uint| -> Result<bool, Error> {
+            let mut transfer = proposed_transfer.clone();
+            transfer.gas_fee.amount =
+                Amount::from_uint(fee, 0).map_err(|err| {
+                    Error::Encode(EncodingError::Conversion(err.to_string()))
+                })?;
+            let hash = transfer.keccak256().to_hex();
+            let mut pool = pool.clone();
+            pool.insert(hash.clone(), transfer);
+            let eligible = generate_eligible(
+                &NullIo,
+                conversion_table,
+                in_progress,
+                pool,
+            )?;
+            let batch = generate(
+                &NullIo,
+                eligible,
+                conversion_table,
+                validator_gas,
+                max_gas,
+                max_cost,
+                false,
+            )?;
+            Ok(enforce_min_batch_size(
+                &NullIo,
+                batch,
+                constraints.min_batch_size,
+            )
+            .map(|batch| batch.transfer_hashes.contains(&hash))
+            .unwrap_or(false))
+        };
+
+        // paying more than both the most generous competing transfer,
+        // and the cost of relaying the transfer on its own, should
+        // always make the batch
+        let competing_fees = generate_eligible(
+            &NullIo,
+            conversion_table,
+            in_progress,
+            pool.clone(),
+        )?
+        .first()
+        .map(|best| {
+            transfer_fee()
+                .checked_sub(&best.cost)
+                .filter(|fees| !fees.is_negative())
+                .map(|fees| fees.abs())
+                .ok_or_else(overflow)
+        })
+        .transpose()?
+        .unwrap_or_default();
+        let mut upper = competing_fees
+            .max(estimated_relay_gas(1, validator_gas))
+            .checked_div(weighted_gwei_per_gas_token.max(uint::ONE))
+            .and_then(|fee| fee.checked_add(uint::ONE))
+            .ok_or_else(overflow)?;
+        if !makes_batch(upper)? {
+            return Err(Error::EthereumBridge(
+                EthereumBridgeError::UnreachableBatch(
+                    "No batch satisfying the constraints of the relayer \
+                     includes the transfer"
+                        .into(),
+                ),
+            ));
+        }
+
+        let mut lower = Uint::zero();
+        while lower < upper {
+            let fee = lower + (upper - lower) / 2;
+            if makes_batch(fee)? {
+                upper = fee;
+            } else {
+                lower = fee + uint::ONE;
+            }
+        }
+        Amount::from_uint(upper, 0).map_err(|err| {
+            Error::Encode(EncodingError::Conversion(err.to_string()))
+        })
+    }
+
+    /// The top percentages of the priced transfers in the Bridge pool
+    /// for which [`query_fee_histogram`] reports the minimum fee.
+    const FEE_HISTOGRAM_TOP_PERCENTS: [u8; 4] = [10, 25, 50, 75];
+
+    /// The width, in characters, of the longest bar of a
+    /// [`FeeHistogram`] printed as text.
+    const FEE_HISTOGRAM_BAR_WIDTH: usize = 40;
+
+    /// The maximum number of buckets of a [`FeeHistogram`].
+    pub const MAX_FEE_HISTOGRAM_BUCKETS: u64 = 100;
+
+    /// A bucket of a [`FeeHistogram`].
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+    pub struct FeeHistogramBucket {
+        /// The lowest fee, in gwei, covered by this bucket.
+        pub min_fee_gwei: Uint,
+        /// The highest fee, in gwei, covered by this bucket.
+        pub max_fee_gwei: Uint,
+        /// The number of transfers paying a fee within this bucket.
+        pub transfers: usize,
+    }
+
+    /// The minimum fee a transfer must pay to rank among the top
+    /// percentage of the priced transfers in the Bridge pool.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+    pub struct FeePercentile {
+        /// The top percentage of transfers.
+        pub top_percent: u8,
+        /// The minimum fee, in gwei.
+        pub min_fee_gwei: Uint,
+        /// The minimum fee, in each token of the conversion table,
+        /// keyed by the alias of the token.
+        pub min_fees: BTreeMap<String, Uint>,
+    }
+
+    /// Histogram of the fees paid by the transfers in the Bridge pool,
+    /// expressed in gwei.
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+    pub struct FeeHistogram {
+        /// Buckets of equal width, in ascending order of fees.
+        pub buckets: Vec<FeeHistogramBucket>,
+        /// Minimum fees required to rank among the top transfers.
+        pub percentiles: Vec<FeePercentile>,
+        /// The fee, in gwei, past which a transfer covers the
+        /// marginal cost of relaying it.
+        pub break_even_fee_gwei: Uint,
+        /// The number of transfers paying fees in tokens absent
+        /// from the conversion table.
+        pub unpriced_transfers: usize,
+    }
+
+    /// Bucket the fees paid by the transfers in the Bridge pool,
+    /// according to the conversion rates in `conversion_table`, to
+    /// help senders decide which fees to pay.
+    ///
+    /// The histogram is printed as text, or as JSON if requested.
+    pub async fn query_fee_histogram<IO: Io>(
+        client: &(impl Client + Sync),
+        io: &IO,
+        args: args::BridgePoolFeeHistogram,
+    ) -> Result<FeeHistogram, Error> {
+        validate_histogram_buckets(io, args.buckets)?;
+        ensure_bridge_active(client, io).await?;
+        let pool = RPC
+            .shell()
+            .eth_bridge()
+            .read_ethereum_bridge_pool(client)
+            .await
+            .map_err(|e| {
+                Error::EthereumBridge(EthereumBridgeError::ReadBridgePool(
+                    e.to_string(),
+                ))
+            })?;
+
+        let mut unpriced_transfers = 0;
+        let mut fees = Vec::with_capacity(pool.len());
+        for transfer in &pool {
+            let Ok(gwei_per_gas_token) =
+                gwei_per_token(&transfer.gas_fee.token, &args.conversion_table)
+            else {
+                unpriced_transfers += 1;
+                continue;
+            };
+            let fee = Uint::from(transfer.gas_fee.amount)
+                .checked_mul(gwei_per_gas_token)
+                .ok_or_else(|| {
+                    Error::EthereumBridge(EthereumBridgeError::RelayCost(
+                        "Overflowed calculating the fee of a transfer in gwei"
+                            .into(),
+                    ))
+                })?;
+            fees.push(fee);
+        }
+        fees.sort();
+
+        let percentiles = FEE_HISTOGRAM_TOP_PERCENTS
+            .into_iter()
+            .filter_map(|top_percent| {
+                let min_fee_gwei = min_fee_in_top_percent(&fees, top_percent)?;
+                let min_fees = args
+                    .conversion_table
+                    .iter()
+                    .filter_map(|(token, entry)| {
+                        let gwei_per_gas_token =
+                            gwei_per_token(token, &args.conversion_table)
+                                .ok()?;
+                        // round up, to make sure the fee ranks high enough
+                        let (min_fee, remainder) =
+                            min_fee_gwei.div_mod(gwei_per_gas_token);
+                        let min_fee = if remainder.is_zero() {
+                            min_fee
+                        } else {
+                            min_fee + uint::ONE
+                        };
+                        Some((entry.alias.clone(), min_fee))
+                    })
+                    .collect();
+                Some(FeePercentile {
+                    top_percent,
+                    min_fee_gwei,
+                    min_fees,
+                })
+            })
+            .collect();
+        let histogram = FeeHistogram {
+            buckets: fee_histogram_buckets(&fees, args.buckets),
+            percentiles,
+            break_even_fee_gwei: unsigned_transfer_fee(),
+            unpriced_transfers,
+        };
+
+        if args.json {
+            write_query_output(io, None, &histogram)?;
+        } else {
+            display_fee_histogram(io, &histogram, fees.len());
+        }
+        Ok(histogram)
+    }
+
+    /// Print a [`FeeHistogram`] as text.
+    fn display_fee_histogram<IO: Io>(
+        io: &IO,
+        histogram: &FeeHistogram,
+        priced_transfers: usize,
+    ) {
+        if histogram.buckets.is_empty() {
+            display_line!(
+                io,
+                "No transfers paying fees in tokens of the conversion table \
+                 were found in the Bridge pool."
+            );
+        } else {
+            display_line!(
+                io,
+                "Fees paid by {priced_transfers} transfers in the Bridge \
+                 pool:"
+            );
+            let largest_bucket = histogram
+                .buckets
+                .iter()
+                .map(|bucket| bucket.transfers)
+                .max()
+                .unwrap_or_default();
+            for bucket in &histogram.buckets {
+                let bar_width = bucket.transfers * FEE_HISTOGRAM_BAR_WIDTH
+                    / largest_bucket.max(1);
+                let profitable =
+                    if bucket.min_fee_gwei >= histogram.break_even_fee_gwei {
+                        "*"
+                    } else {
+                        " "
+                    };
+                display_line!(
+                    io,
+                    "{profitable} [{}, {}] | {} {}",
+                    format_uint_gwei(bucket.min_fee_gwei),
+                    format_uint_gwei(bucket.max_fee_gwei),
+                    "#".repeat(bar_width),
+                    bucket.transfers,
+                );
+            }
+            display_line!(
+                io,
+                "Buckets marked with * pay at least {}, the marginal cost \
+                 of relaying a transfer.",
+                format_uint_gwei(histogram.break_even_fee_gwei),
+            );
+            display_line!(io, "Minimum fees to rank among the top transfers:");
+            for percentile in &histogram.percentiles {
+                let min_fees = percentile
+                    .min_fees
+                    .iter()
+                    .map(|(alias, fee)| format!("{fee} {alias}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                display_line!(
+                    io,
+                    "  top {}%: {} ({min_fees})",
+                    percentile.top_percent,
+                    format_uint_gwei(percentile.min_fee_gwei),
+                );
+            }
+        }
+        if histogram.unpriced_transfers > 0 {
+            display_line!(
+                io,
+                "{} transfers pay fees in tokens absent from the conversion \
+                 table, and were left out.",
+                histogram.unpriced_transfers,
+            );
+        }
+    }
+
+    /// Check that the requested number of buckets of a [`FeeHistogram`]
+    /// is within `1..=MAX_FEE_HISTOGRAM_BUCKETS`.
+    fn validate_histogram_buckets<IO: Io>(
+        io: &IO,
+        buckets: u64,
+    ) -> Result<(), Error> {
+        if (1..=MAX_FEE_HISTOGRAM_BUCKETS).contains(&buckets) {
+            return Ok(());
+        }
+        let error = error_label();
+        display_line!(
+            io,
+            "{error}: The fee histogram must have between 1 and \
+             {MAX_FEE_HISTOGRAM_BUCKETS} buckets, but {buckets} were \
+             requested."
+        );
+        Err(Error::EthereumBridge(
+            EthereumBridgeError::InvalidHistogramBuckets {
+                buckets,
+                max: MAX_FEE_HISTOGRAM_BUCKETS,
+            },
+        ))
+    }
+
+    /// Split the range of the given fees, sorted in ascending order,
+    /// into at most `buckets` buckets of equal width, and count the
+    /// fees within each bucket.
+    fn fee_histogram_buckets(
+        sorted_fees: &[Uint],
+        buckets: u64,
+    ) -> Vec<FeeHistogramBucket> {
+        let (Some(&min_fee), Some(&max_fee)) =
+            (sorted_fees.first(), sorted_fees.last())
+        else {
+            return vec![];
+        };
+        if buckets == 0 {
+            return vec![];
+        }
+        let width = (max_fee - min_fee) / Uint::from_u64(buckets) + uint::ONE;
+        let bucket_of =
+            |fee: Uint| ((fee - min_fee) / width).low_u64() as usize;
+
+        let mut histogram: Vec<_> = (0..=bucket_of(max_fee))
+            .map(|index| {
+                let min_fee_gwei = min_fee.saturating_add(
+                    width.saturating_mul(Uint::from_u64(index as u64)),
+                );
+                FeeHistogramBucket {
+                    min_fee_gwei,
+                    max_fee_gwei: min_fee_gwei
+                        .saturating_add(width - uint::ONE),
+                    transfers: 0,
+                }
+            })
+            .collect();
+        for &fee in sorted_fees {
+            histogram[bucket_of(fee)].transfers += 1;
+        }
+        histogram
+    }
+
+    /// Find the minimum fee paid by the top `top_percent` percent of
+    /// the given fees, sorted in ascending order.
+    fn min_fee_in_top_percent(
+        sorted_fees: &[Uint],
+        top_percent: u8,
+    ) -> Option<Uint> {
+        if sorted_fees.is_empty() {
+            return None;
+        }
+        // round up, such that at least one fee ranks among the top
+        let rank = (sorted_fees.len() * top_percent as usize + 99) / 100;
+        let rank = rank.clamp(1, sorted_fees.len());
+        Some(sorted_fees[sorted_fees.len() - rank])
+    }
+
+    /// Check that some validators have voting power at the given
+    /// height, without which no gas estimate of the signature
+    /// checks is meaningful.
+    fn ensure_voting_powers<IO: Io>(
+        io: &IO,
+        voting_powers: &VotingPowersMap,
+        height: BlockHeight,
+    ) -> Result<(), Error> {
+        if !voting_powers.is_empty() {
+            return Ok(());
+        }
+        edisplay_line!(
+            io,
+            "No validator voting powers found at height {height}, the \
+             Ethereum bridge may not have been active yet"
+        );
+        Err(Error::EthereumBridge(EthereumBridgeError::NoVotingPowers(
+            height.0,
+        )))
+    }
+
+    /// Given an ordered list of signatures, figure out the size of the first
+    /// subset constituting a 2 / 3 majority, along with the fraction of
+    /// voting power it achieves.
+    ///
+    /// The achieved voting power does not exceed 2 / 3 if the signatures
+    /// do not constitute a quorum.
+    ///
+    /// The function is generic to make unit testing easier (otherwise a dev
+    /// dependency needs to be added).
+    fn signature_checks<T>(
+        voting_powers: VotingPowersMap,
+        sigs: &HashMap<EthAddrBook, T>,
+    ) -> (Uint, FractionalVotingPower) {
+        let voting_powers = voting_powers.get_sorted();
+        let total_power = voting_powers.iter().map(|(_, &y)| y).sum::<Amount>();
+
+        // Find the total number of signature checks Ethereum will make
+        let mut power = FractionalVotingPower::NULL;
+        let checks = Uint::from_u64(
+            voting_powers
+                .iter()
+                .filter_map(|(a, &p)| sigs.get(a).map(|_| p))
+                .take_while(|p| {
+                    if power <= FractionalVotingPower::TWO_THIRDS {
+                        power += FractionalVotingPower::new(
+                            (*p).into(),
+                            total_power.into(),
+                        )
+                        // NB: this unwrap is infallible, since we calculate
+                        // the total voting power beforehand. the fraction's
+                        // value will never exceed 1.0
+                        .unwrap();
+                        true
+                    } else {
+                        false
+                    }
+                })
+                .count() as u64,
+        );
+        (checks, power)
+    }
+
+    /// Query the validators that failed to sign the latest signed
+    /// Bridge pool root, along with their voting power at the height
+    /// of the root, sorted in descending order by voting power.
+    ///
+    /// Helps operators identify validators that are failing to
+    /// produce Bridge pool signatures.
+    pub async fn missing_signers<C>(
+        client: &C,
+    ) -> Result<Vec<(EthAddrBook, Amount)>, Error>
+    where
+        C: Client + Sync,
+    {
+        let (bp_root, height) = query_signed_root(client)
+            .await?
+            .ok_or(Error::EthereumBridge(EthereumBridgeError::NoSignedRoot))?;
+        let voting_powers = RPC
+            .shell()
+            .eth_bridge()
+            .voting_powers_at_height(client, &height)
+            .await
+            .map_err(|e| {
+                Error::EthereumBridge(EthereumBridgeError::QueryVotingPowers(
+                    e.to_string(),
+                ))
+            })?;
+        Ok(unsigned_validators(&voting_powers, &bp_root.signatures))
+    }
+
+    /// Collect the validators in `voting_powers` without a signature
+    /// in `sigs`, sorted in descending order by voting power.
+    fn unsigned_validators<T>(
+        voting_powers: &VotingPowersMap,
+        sigs: &HashMap<EthAddrBook, T>,
+    ) -> Vec<(EthAddrBook, Amount)> {
+        voting_powers
+            .get_sorted()
+            .into_iter()
+            .filter(|(addr, _)| !sigs.contains_key(*addr))
+            .map(|(addr, &power)| (addr.clone(), power))
+            .collect()
+    }
+
+    /// Generate eligible recommendations.
+    fn generate_eligible<IO: Io>(
+        io: &IO,
+        conversion_table: &HashMap<Address, args::BpConversionTableEntry>,
+        in_progress: &BTreeSet<String>,
+        signed_pool: HashMap<String, PendingTransfer>,
+    ) -> Result<Vec<EligibleRecommendation>, Error> {
+        let mut eligible: Vec<_> = signed_pool
+            .into_iter()
+            .filter_map(|(pending_hash, pending)| {
+                if in_progress.contains(&pending_hash) {
+                    return None;
+                }
+
+                if !conversion_table.contains_key(&pending.gas_fee.token) {
+                    return None;
+                }
+                let (gwei_per_gas_token, weighted_gwei_per_gas_token) =
+                    match weighted_gwei_per_token(
+                        &pending.gas_fee.token,
+                        conversion_table,
+                    ) {
+                        Ok(rates) => rates,
+                        Err(err) => {
+                            edisplay_line!(
+                                io,
+                                "Ignoring transfer {pending_hash}: {err}"
+                            );
+                            return None;
+                        }
+                    };
+
+                // the cost of relaying the transfer, given the amount of
+                // gwei a single gas token is worth
+                let relay_cost = |gwei_per_gas_token: Uint| {
+                    Uint::from(pending.gas_fee.amount)
+                        .checked_mul(gwei_per_gas_token)
+                        .ok_or_else(|| {
+                            "Overflowed calculating earned gwei".to_string()
+                        })
+                        .and_then(|earned| {
+                            I256::try_from(earned).map_err(|e| e.to_string())
+                        })
+                        .and_then(|amt_of_earned_gwei| {
+                            transfer_fee()
+                                .checked_sub(&amt_of_earned_gwei)
+                                .ok_or_else(|| {
+                                    "Underflowed calculating relaying cost"
+                                        .to_string()
+                                })
+                        })
+                };
+                let unweighted_cost = relay_cost(gwei_per_gas_token);
+                let cost = relay_cost(weighted_gwei_per_gas_token);
+                Some(cost.and_then(|cost| {
+                    Ok(EligibleRecommendation {
+                        cost,
+                        unweighted_cost: unweighted_cost?,
+                        pending_transfer: pending,
+                        transfer_hash: pending_hash,
+                    })
+                }))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| {
+                Error::EthereumBridge(EthereumBridgeError::RelayCost(
+                    echo_error!(io, "Failed to calculate relaying cost: {err}"),
+                ))
+            })?;
+
+        // sort transfers in increasing amounts of profitability,
+        // breaking ties by hash, such that the order is deterministic
+        eligible.sort_by(|a, b| {
+            a.cost
+                .cmp(&b.cost)
+                .then_with(|| a.transfer_hash.cmp(&b.transfer_hash))
+        });
+
+        Ok(eligible)
+    }
+
+    /// Discard a recommended batch with fewer than `min_batch_size`
+    /// transfers, whose relay would not amortize the fixed cost of
+    /// checking the signatures of the validator set.
+    fn enforce_min_batch_size<IO: Io>(
+        io: &IO,
+        recommendation: Option<RecommendedBatch>,
+        min_batch_size: Option<usize>,
+    ) -> Option<RecommendedBatch> {
+        let (Some(batch), Some(min_batch_size)) =
+            (&recommendation, min_batch_size)
+        else {
+            return recommendation;
+        };
+        let batch_size = batch.transfer_hashes.len();
+        if batch_size >= min_batch_size {
+            return recommendation;
+        }
+        display_line!(
+            io,
+            "Only {batch_size} transfer(s) can be batched together, fewer \
+             than the minimum batch size of {min_batch_size}. Waiting for \
+             more transfers to accumulate in the Bridge pool is recommended."
+        );
+        None
+    }
+
+    /// Generates the actual recommendation from restrictions given by the
+    /// input parameters.
+    fn generate_recommendations<IO: Io>(
+        io: &IO,
+        contents: Vec<EligibleRecommendation>,
+        conversion_table: &HashMap<Address, args::BpConversionTableEntry>,
+        validator_gas: Uint,
+        max_gas: Uint,
+        max_cost: I256,
+        trace: bool,
+    ) -> Result<Option<RecommendedBatch>, Error> {
+        let mut state = AlgorithState {
+            profitable: true,
+            feasible_region: false,
+        };
+
+        let mode = if max_cost <= I256::zero() {
+            AlgorithmMode::Greedy
+        } else {
+            AlgorithmMode::Generous
+        };
+
+        let mut total_gas = validator_gas;
+        let mut total_cost = I256::try_from(validator_gas).map_err(|err| {
+            Error::Encode(EncodingError::Conversion(echo_error!(
+                io,
+                "Failed to convert value to I256: {err}"
+            )))
+        })?;
+        // NB: the profit of the batch is reported in gwei, regardless
+        // of the weights of the fee tokens
+        let mut total_unweighted_cost = total_cost;
+        let mut total_fees = HashMap::new();
+        let mut recommendation = vec![];
+        if trace {
+            display_line!(io, "Recommender decision trace:");
+        }
+        for EligibleRecommendation {
+            cost,
+            unweighted_cost,
+            transfer_hash: hash,
+            pending_transfer: transfer,
+        } in contents.into_iter()
+        {
+            let next_total_gas = total_gas + unsigned_transfer_fee();
+            let next_total_cost = total_cost + cost;
+            let exceeded = exceeded_limit(
+                next_total_gas,
+                next_total_cost,
+                max_gas,
+                max_cost,
+            );
+            let (added, reason) = if cost.is_negative() {
+                match exceeded {
+                    None => {
+                        state.feasible_region = true;
+                        (true, TraceReason::WithinLimits)
+                    }
+                    // once we leave the feasible region, we will never
+                    // re-enter it.
+                    Some(limit) if state.feasible_region => (false, limit),
+                    Some(_) => (true, TraceReason::NotYetWithinLimits),
+                }
+            } else if mode == AlgorithmMode::Generous {
+                state.profitable = false;
+                // once we leave the feasible region, we will never re-enter it.
+                match exceeded {
+                    Some(limit) if state.feasible_region => (false, limit),
+                    _ => (true, TraceReason::Generous),
+                }
+            } else {
+                (false, TraceReason::Greedy)
+            };
+            if trace {
+                let step = TraceStep {
+                    hash: &hash,
+                    cost,
+                    total_gas: next_total_gas,
+                    total_cost: next_total_cost,
+                    added,
+                    reason,
+                };
+                display_line!(io, "  {step}");
+            }
+            if !added {
+                break;
+            }
+            recommendation.push(hash);
+            total_cost = next_total_cost;
+            total_unweighted_cost = total_unweighted_cost + unweighted_cost;
+            total_gas = next_total_gas;
+            update_total_fees(&mut total_fees, transfer, conversion_table);
+        }
+
+        // NB: accepting a net loss does not extend to relaying
+        // batches that earn the relayer nothing at all
+        if mode == AlgorithmMode::Generous
+            && !recommendation.is_empty()
+            && total_fees.values().all(|fees| fees.is_zero())
+        {
+            edisplay_line!(
+                io,
+                "No worthwhile batch exists, since none of the transfers \
+                 satisfying the input parameters pay any fees."
+            );
+            return Ok(None);
+        }
+
+        Ok(if state.feasible_region && !recommendation.is_empty() {
+            Some(RecommendedBatch {
+                transfer_hashes: recommendation,
+                ethereum_gas_fees: total_gas,
+                net_profit: -total_unweighted_cost,
+                bridge_pool_gas_fees: total_fees,
+            })
+        } else {
+            edisplay_line!(
+                io,
+                "Unable to find a recommendation satisfying the input \
+                 parameters."
+            );
+            None
+        })
+    }
+
+    /// Generates a recommendation relaying as many transfers as
+    /// possible, regardless of their profitability, as long as the
+    /// total cost of the relay does not exceed `max_cost`.
+    ///
+    /// Since every transfer consumes the same amount of gas, the
+    /// largest batches are made up of the cheapest transfers to
+    /// relay, i.e. the longest feasible prefix of `contents`.
+    fn generate_throughput_recommendations<IO: Io>(
+        io: &IO,
+        contents: Vec<EligibleRecommendation>,
+        conversion_table: &HashMap<Address, args::BpConversionTableEntry>,
+        validator_gas: Uint,
+        max_gas: Uint,
+        max_cost: I256,
+        trace: bool,
+    ) -> Result<Option<RecommendedBatch>, Error> {
+        let mut total_gas = validator_gas;
+        let mut total_cost = I256::try_from(validator_gas).map_err(|err| {
+            Error::Encode(EncodingError::Conversion(echo_error!(
+                io,
+                "Failed to convert value to I256: {err}"
+            )))
+        })?;
+        // NB: the profit of the batch is reported in gwei, regardless
+        // of the weights of the fee tokens
+        let mut total_unweighted_cost = total_cost;
+        let mut batch_len = 0;
+        let mut batch_totals = None;
+        let mut steps = vec![];
+        for (
+            i,
+            EligibleRecommendation {
+                cost,
+                unweighted_cost,
+                transfer_hash,
+                ..
+            },
+        ) in contents.iter().enumerate()
+        {
+            total_gas = total_gas + unsigned_transfer_fee();
+            total_cost = total_cost + *cost;
+            total_unweighted_cost = total_unweighted_cost + *unweighted_cost;
+            let exceeded =
+                exceeded_limit(total_gas, total_cost, max_gas, max_cost);
+            if trace {
+                steps.push(TraceStep {
+                    hash: transfer_hash,
+                    cost: *cost,
+                    total_gas,
+                    total_cost,
+                    added: false,
+                    reason: exceeded.unwrap_or(TraceReason::WithinLimits),
+                });
+            }
+            match exceeded {
+                // gas only accrues, so no longer batch is feasible
+                Some(TraceReason::ExceedsMaxGas) => break,
+                Some(_) => {}
+                None => {
+                    batch_len = i + 1;
+                    batch_totals = Some((total_gas, total_unweighted_cost));
+                }
+            }
+        }
+        if trace {
+            // the batch is the longest prefix within the limits
+            display_line!(io, "Recommender decision trace:");
+            for (i, mut step) in steps.into_iter().enumerate() {
+                step.added = i < batch_len;
+                display_line!(io, "  {step}");
+            }
+        }
+
+        let mut total_fees = HashMap::new();
+        let mut recommendation = vec![];
+        for EligibleRecommendation {
+            transfer_hash,
+            pending_transfer,
+            ..
+        } in contents.into_iter().take(batch_len)
+        {
+            recommendation.push(transfer_hash);
+            update_total_fees(
+                &mut total_fees,
+                pending_transfer,
+                conversion_table,
+            );
+        }
+
+        Ok(match batch_totals {
+            Some((total_gas, total_unweighted_cost))
+                if total_fees.values().any(|fees| !fees.is_zero()) =>
+            {
+                Some(RecommendedBatch {
+                    transfer_hashes: recommendation,
+                    ethereum_gas_fees: total_gas,
+                    net_profit: -total_unweighted_cost,
+                    bridge_pool_gas_fees: total_fees,
+                })
+            }
+            _ => {
+                edisplay_line!(
+                    io,
+                    "Unable to find a recommendation satisfying the input \
+                     parameters."
+                );
+                None
+            }
+        })
+    }
+
+    fn update_total_fees(
+        total_fees: &mut HashMap<String, Uint>,
+        transfer: PendingTransfer,
+        conversion_table: &HashMap<Address, args::BpConversionTableEntry>,
+    ) {
+        let GasFee { token, amount, .. } = transfer.gas_fee;
+        let fees = total_fees
+            .entry(
+                conversion_table
+                    .get(&token)
+                    .map(|entry| entry.alias.clone())
+                    .unwrap_or_else(|| token.to_string()),
+            )
+            .or_insert(uint::ZERO);
+        *fees += Uint::from(amount);
+    }
+
+    #[cfg(test)]
+    pub(super) mod test_recommendations {
+        use assert_matches::assert_matches;
+        use namada_core::types::address::Address;
+
+        use super::*;
+        use crate::io::StdIo;
+
+        /// An established user address for testing & development
+        pub fn bertha_address() -> Address {
+            Address::decode("tnam1qyctxtpnkhwaygye0sftkq28zedf774xc5a2m7st")
+                .expect("The token address decoding shouldn't fail")
+        }
+
+        /// Generate a pending transfer with the specified gas
+        /// fee.
+        pub fn transfer(gas_amount: u64) -> PendingTransfer {
+            PendingTransfer {
+                transfer: TransferToEthereum {
+                    kind: TransferToEthereumKind::Erc20,
+                    asset: EthAddress([1; 20]),
+                    recipient: EthAddress([2; 20]),
+                    sender: bertha_address(),
+                    amount: Default::default(),
+                },
+                gas_fee: GasFee {
+                    token: namada_core::types::address::nam(),
+                    amount: gas_amount.into(),
+                    payer: bertha_address(),
+                },
+            }
+        }
+
+        /// Convert transfers into a format that the
+        /// [`generate_recommendations`] function understands.
+        fn process_transfers(
+            transfers: Vec<PendingTransfer>,
+        ) -> Vec<EligibleRecommendation> {
+            transfers
+                .into_iter()
+                .map(|t| {
+                    let cost = transfer_fee() - t.gas_fee.amount.change();
+                    EligibleRecommendation {
+                        cost,
+                        unweighted_cost: cost,
+                        transfer_hash: t.keccak256().to_hex(),
+                        pending_transfer: t,
+                    }
+                })
+                .collect()
+        }
+
+        fn address_book(i: u8) -> EthAddrBook {
+            EthAddrBook {
+                hot_key_addr: EthAddress([i; 20]),
+                cold_key_addr: EthAddress([i; 20]),
+            }
+        }
+
+        /// Data to pass to the [`test_generate_eligible_aux`] callback.
+        struct TestGenerateEligible<'a> {
+            pending: &'a PendingTransfer,
+            conversion_table:
+                &'a mut HashMap<Address, args::BpConversionTableEntry>,
+            in_progress: &'a mut BTreeSet<String>,
+            signed_pool: &'a mut HashMap<String, PendingTransfer>,
+            expected_eligible: &'a mut Vec<EligibleRecommendation>,
+        }
+
+        impl TestGenerateEligible<'_> {
+            /// Add ETH to a conversion table.
+            fn add_eth_to_conversion_table(&mut self) {
+                self.conversion_table.insert(
+                    namada_core::types::address::eth(),
+                    args::BpConversionTableEntry {
+                        alias: "ETH".into(),
+                        conversion_rate: 1e9, // 1 ETH = 1e9 GWEI
+                        as_of: None,
+                        fee_weight: None,
+                    },
+                );
+            }
+        }
+
+        /// Helper function to test [`generate_eligible`].
+        fn test_generate_eligible_aux<F>(
+            mut callback: F,
+        ) -> Vec<EligibleRecommendation>
+        where
+            F: FnMut(TestGenerateEligible<'_>),
+        {
+            let pending = PendingTransfer {
+                transfer: TransferToEthereum {
+                    kind: TransferToEthereumKind::Erc20,
+                    asset: EthAddress([1; 20]),
+                    recipient: EthAddress([2; 20]),
+                    sender: bertha_address(),
+                    amount: Default::default(),
+                },
+                gas_fee: GasFee {
+                    token: namada_core::types::address::eth(),
+                    amount: 1_000_000_000_u64.into(), // 1 GWEI
+                    payer: bertha_address(),
+                },
+            };
+            let mut table = HashMap::new();
+            let mut in_progress = BTreeSet::new();
+            let mut signed_pool = HashMap::new();
+            let mut expected = vec![];
+            callback(TestGenerateEligible {
+                pending: &pending,
+                conversion_table: &mut table,
+                in_progress: &mut in_progress,
+                signed_pool: &mut signed_pool,
+                expected_eligible: &mut expected,
+            });
+            let eligible =
+                generate_eligible(&StdIo, &table, &in_progress, signed_pool)
+                    .unwrap();
+            assert_eq!(eligible, expected);
+            eligible
+        }
+
+        /// Test the happy path of generating eligible recommendations
+        /// for Bridge pool relayed transfers.
+        #[test]
+        fn test_generate_eligible_happy_path() {
+            test_generate_eligible_aux(|mut ctx| {
+                ctx.add_eth_to_conversion_table();
+                ctx.signed_pool.insert(
+                    ctx.pending.keccak256().to_hex(),
+                    ctx.pending.clone(),
+                );
+                let cost = transfer_fee()
+                    - I256::try_from(ctx.pending.gas_fee.amount)
+                        .expect("Test failed");
+                ctx.expected_eligible.push(EligibleRecommendation {
+                    transfer_hash: ctx.pending.keccak256().to_hex(),
+                    cost,
+                    unweighted_cost: cost,
+                    pending_transfer: ctx.pending.clone(),
+                });
+            });
+        }
+
+        /// Test that a transfer is not recommended if it
+        /// is in the process of being relayed (has >0 voting
+        /// power behind it).
+        #[test]
+        fn test_generate_eligible_with_in_progress() {
+            test_generate_eligible_aux(|mut ctx| {
+                ctx.add_eth_to_conversion_table();
+                ctx.signed_pool.insert(
+                    ctx.pending.keccak256().to_hex(),
+                    ctx.pending.clone(),
+                );
+                ctx.in_progress.insert(ctx.pending.keccak256().to_hex());
+            });
+        }
+
+        /// Test that a transfer is not recommended if its gas
+        /// token is not found in the conversion table.
+        #[test]
+        fn test_generate_eligible_no_gas_token() {
+            test_generate_eligible_aux(|ctx| {
+                ctx.signed_pool.insert(
+                    ctx.pending.keccak256().to_hex(),
+                    ctx.pending.clone(),
+                );
+            });
+        }
+
+        /// Test that transfers with equal relaying costs are always
+        /// recommended in the same order, regardless of the order
+        /// in which they are read from the Bridge pool.
+        #[test]
+        fn test_equal_cost_transfers_deterministic() {
+            let transfers: Vec<_> = (1..=8u64)
+                .map(|i| {
+                    let mut transfer = transfer(100_000);
+                    transfer.transfer.amount = i.into();
+                    transfer
+                })
+                .collect();
+            let mut hashes: Vec<_> =
+                transfers.iter().map(|t| t.keccak256().to_hex()).collect();
+            hashes.sort();
+            let conversion_table = HashMap::from([(
+                namada_core::types::address::nam(),
+                args::BpConversionTableEntry {
+                    alias: "NAM".into(),
+                    conversion_rate: 1e9,
+                    as_of: None,
+                    fee_weight: None,
+                },
+            )]);
+
+            for _ in 0..16 {
+                // hash maps are seeded randomly, therefore each
+                // iteration yields transfers in a different order
+                let signed_pool: HashMap<_, _> = transfers
+                    .iter()
+                    .map(|t| (t.keccak256().to_hex(), t.clone()))
+                    .collect();
+                let eligible = generate_eligible(
+                    &StdIo,
+                    &conversion_table,
+                    &BTreeSet::new(),
+                    signed_pool,
+                )
+                .unwrap();
+                let recommendation = generate_recommendations(
+                    &StdIo,
+                    eligible,
+                    &conversion_table,
+                    Uint::from_u64(100_000),
+                    Uint::from_u64(100_000 + 3 * 37_500),
+                    I256::zero(),
+                    false,
+                )
+                .unwrap()
+                .expect("Test failed")
+                .transfer_hashes;
+                assert_eq!(recommendation, hashes[..3]);
+            }
+        }
+
+        /// Test that the suggested minimum fee yields a non-positive
+        /// relaying cost, and that any lower fee does not.
+        #[test]
+        fn test_suggest_min_fee() {
+            let token = namada_core::types::address::eth();
+            for (conversion_rate, expected_fee) in
+                [(1e9, 37_500_u64), (1e8, 3_750), (3e8, 12_500), (7e8, 37_500)]
+            {
+                let conversion_table = HashMap::from([(
+                    token.clone(),
+                    args::BpConversionTableEntry {
+                        alias: "ETH".into(),
+                        conversion_rate,
+                        as_of: None,
+                        fee_weight: None,
+                    },
+                )]);
+                let min_fee =
+                    suggest_min_fee(&token, &conversion_table).unwrap();
+                assert_eq!(min_fee, Amount::from(expected_fee));
+
+                let gwei_per_gas_token =
+                    I256::from((1e9 / conversion_rate).floor() as u64);
+                let cost = |fee: u64| {
+                    transfer_fee() - I256::from(fee) * gwei_per_gas_token
+                };
+                assert!(!cost(expected_fee).is_positive());
+                assert!(cost(expected_fee - 1).is_positive());
+            }
+        }
+
+        /// Test the arithmetic of the break-even gas price of a relay.
+        #[test]
+        fn test_break_even_gas_price() {
+            let batch_gas = Uint::from_u64(100_000);
+            // revenue covers the gas at exactly 1 gwei per gas
+            assert_eq!(
+                break_even_gas_price(batch_gas, I256::from(100_000)),
+                Uint::from_u64(1)
+            );
+            // round down, such that the relay does not incur a loss
+            assert_eq!(
+                break_even_gas_price(batch_gas, I256::from(2_599_999)),
+                Uint::from_u64(25)
+            );
+            // revenue does not cover any gas price
+            assert_eq!(
+                break_even_gas_price(batch_gas, I256::from(99_999)),
+                Uint::zero()
+            );
+            assert_eq!(
+                break_even_gas_price(batch_gas, I256::zero()),
+                Uint::zero()
+            );
+            assert_eq!(
+                break_even_gas_price(batch_gas, I256::from(-1_000_000)),
+                Uint::zero()
+            );
+            // no gas is consumed
+            assert_eq!(
+                break_even_gas_price(Uint::zero(), I256::from(1)),
+                uint::MAX_VALUE
+            );
+        }
+
+        /// Test the classification of transfers by the voting power
+        /// backing their relay.
+        #[test]
+        fn test_relay_readiness() {
+            assert_eq!(RelayReadiness::new(None), RelayReadiness::Unbacked);
+            assert_eq!(
+                RelayReadiness::new(Some(FractionalVotingPower::NULL)),
+                RelayReadiness::Unbacked
+            );
+            assert_eq!(
+                RelayReadiness::new(Some(FractionalVotingPower::ONE_THIRD)),
+                RelayReadiness::Accumulating(FractionalVotingPower::ONE_THIRD)
+            );
+            // a quorum requires strictly more than two thirds
+            assert_eq!(
+                RelayReadiness::new(Some(FractionalVotingPower::TWO_THIRDS)),
+                RelayReadiness::Accumulating(FractionalVotingPower::TWO_THIRDS)
+            );
+            assert_eq!(
+                RelayReadiness::new(Some(
+                    FractionalVotingPower::new_u64(3, 4).unwrap()
+                )),
+                RelayReadiness::Quorum
+            );
+            assert_eq!(
+                RelayReadiness::new(Some(FractionalVotingPower::WHOLE)),
+                RelayReadiness::Quorum
+            );
+        }
+
+        /// Test that no minimum fee is suggested for tokens with
+        /// missing or invalid conversion rates.
+        #[test]
+        fn test_suggest_min_fee_invalid_rates() {
+            let token = namada_core::types::address::eth();
+            assert!(suggest_min_fee(&token, &HashMap::new()).is_err());
+            for conversion_rate in [0.0, -1.0, 1e10] {
+                let conversion_table = HashMap::from([(
+                    token.clone(),
+                    args::BpConversionTableEntry {
+                        alias: "ETH".into(),
+                        conversion_rate,
+                        as_of: None,
+                        fee_weight: None,
+                    },
+                )]);
+                assert!(suggest_min_fee(&token, &conversion_table).is_err());
+            }
+        }
+
+        /// Test that stale conversion rates are filtered out, while
+        /// rates without a timestamp are kept.
+        #[test]
+        fn test_fresh_conversion_rates() {
+            let now = DateTimeUtc::now();
+            let ago =
+                |secs| now - namada_core::types::time::Duration::seconds(secs);
+            let entry = |alias: &str, as_of| args::BpConversionTableEntry {
+                alias: alias.into(),
+                conversion_rate: 1e9,
+                as_of,
+                fee_weight: None,
+            };
+            let eth = namada_core::types::address::eth();
+            let nam = namada_core::types::address::nam();
+            let btc = namada_core::types::address::btc();
+            let conversion_table = HashMap::from([
+                (eth.clone(), entry("ETH", Some(ago(300)))),
+                (nam.clone(), entry("NAM", Some(ago(7200)))),
+                (btc.clone(), entry("BTC", None)),
+            ]);
+
+            let fresh =
+                fresh_conversion_rates(&StdIo, &conversion_table, None, now);
+            assert_eq!(fresh.len(), 3);
+
+            let fresh = fresh_conversion_rates(
+                &StdIo,
+                &conversion_table,
+                Some(Duration::from_secs(3600)),
+                now,
+            );
+            assert_eq!(fresh.len(), 2);
+            assert!(fresh.contains_key(&eth));
+            assert!(fresh.contains_key(&btc));
+            assert!(!fresh.contains_key(&nam));
+        }
+
+        /// Test that a transfer relayed on its own bears the cost of
+        /// verifying the validator signatures.
+        #[test]
+        fn test_standalone_profitability() {
+            let conversion_table = HashMap::from([(
+                namada_core::types::address::nam(),
+                args::BpConversionTableEntry {
+                    alias: "NAM".into(),
+                    conversion_rate: 1e9,
+                    as_of: None,
+                    fee_weight: None,
+                },
+            )]);
+            let validator_gas = Uint::from_u64(100_000);
+
+            let report = standalone_profitability(
+                transfer(137_500),
+                validator_gas,
+                &conversion_table,
+            )
+            .unwrap();
+            assert_eq!(report.fees_gwei, Uint::from_u64(137_500));
+            assert_eq!(report.cost_gwei, Uint::from_u64(137_500));
+            assert_eq!(report.net_profit_gwei, I256::zero());
+            assert!(!report.is_profitable());
+
+            let report = standalone_profitability(
+                transfer(150_000),
+                validator_gas,
+                &conversion_table,
+            )
+            .unwrap();
+            assert_eq!(report.net_profit_gwei, I256::from(12_500));
+            assert!(report.is_profitable());
+
+            // the transfer on its own would be profitable without
+            // the validator gas overhead
+            let report = standalone_profitability(
+                transfer(40_000),
+                validator_gas,
+                &conversion_table,
+            )
+            .unwrap();
+            assert_eq!(report.net_profit_gwei, I256::from(-97_500));
+            assert!(!report.is_profitable());
+
+            assert!(standalone_profitability(
+                transfer(40_000),
+                validator_gas,
+                &HashMap::new(),
+            )
+            .is_err());
+        }
+
+        /// Test that the total fees of a proof are expressed in gwei,
+        /// across all fee tokens.
+        #[test]
+        fn test_total_fees_in_gwei() {
+            let eth = namada_core::types::address::eth();
+            let nam = namada_core::types::address::nam();
+            let conversion_table = HashMap::from([
+                (
+                    eth.clone(),
+                    args::BpConversionTableEntry {
+                        alias: "ETH".into(),
+                        conversion_rate: 1e9,
+                        as_of: None,
+                        fee_weight: None,
+                    },
+                ),
+                (
+                    nam.clone(),
+                    args::BpConversionTableEntry {
+                        alias: "NAM".into(),
+                        conversion_rate: 1e8,
+                        as_of: None,
+                        fee_weight: None,
+                    },
+                ),
+            ]);
+            let total_fees = HashMap::from([
+                (eth.clone(), Amount::from(5)),
+                (nam.clone(), Amount::from(3)),
+            ]);
+            assert_eq!(
+                total_fees_in_gwei(&total_fees, &conversion_table).unwrap(),
+                Uint::from_u64(5 + 3 * 10)
+            );
+
+            // fees paid in tokens without a conversion rate are rejected
+            let total_fees = HashMap::from([(
+                namada_core::types::address::btc(),
+                Amount::from(1),
+            )]);
+            assert!(total_fees_in_gwei(&total_fees, &conversion_table).is_err());
+        }
+
+        /// Test that only transfers whose fees do not cover the
+        /// marginal relaying cost are deemed unrelayable.
+        #[test]
+        fn test_unrelayable_transfers() {
+            let unsigned_fee = unsigned_transfer_fee().as_u64();
+            let profitable = vec![
+                transfer(unsigned_fee),
+                transfer(unsigned_fee + 1),
+                transfer(1_000_000),
+            ];
+            let stuck =
+                vec![transfer(0), transfer(1), transfer(unsigned_fee - 1)];
+            let eligible = process_transfers(
+                profitable.into_iter().chain(stuck.clone()).collect(),
+            );
+            assert_eq!(unrelayable(eligible), stuck);
+        }
+
+        #[test]
+        fn test_signature_count() {
+            let voting_powers = VotingPowersMap::from([
+                (address_book(1), Amount::from(5)),
+                (address_book(2), Amount::from(1)),
+                (address_book(3), Amount::from(1)),
+            ]);
+            let signatures = HashMap::from([
+                (address_book(1), 0),
+                (address_book(2), 0),
+                (address_book(3), 0),
+            ]);
+            let (checks, power) = signature_checks(voting_powers, &signatures);
+            assert_eq!(checks, uint::ONE);
+            assert_eq!(power, FractionalVotingPower::new_u64(5, 7).unwrap());
+        }
+
+        #[test]
+        fn test_signature_count_with_skips() {
+            let voting_powers = VotingPowersMap::from([
+                (address_book(1), Amount::from(5)),
+                (address_book(2), Amount::from(5)),
+                (address_book(3), Amount::from(1)),
+                (address_book(4), Amount::from(1)),
+            ]);
+            let signatures = HashMap::from([
+                (address_book(1), 0),
+                (address_book(3), 0),
+                (address_book(4), 0),
+            ]);
+            let (checks, power) = signature_checks(voting_powers, &signatures);
+            assert_eq!(checks, Uint::from_u64(3));
+            assert_eq!(power, FractionalVotingPower::new_u64(7, 12).unwrap());
+        }
+
+        /// Test that sorting the recommended hashes does not depend on
+        /// the order in which the transfers were recommended.
+        #[test]
+        fn test_sorted_by_hash() {
+            let hashes: Vec<_> = (1..=3)
+                .map(|gas_amount| transfer(gas_amount).keccak256().to_hex())
+                .collect();
+            let mut expected = hashes.clone();
+            expected.sort();
+            let reversed: Vec<_> = hashes.iter().rev().cloned().collect();
+            assert_eq!(sorted_by_hash(&hashes), expected);
+            assert_eq!(sorted_by_hash(&reversed), expected);
+        }
+
+        /// Test that no gas estimate is computed from an empty set of
+        /// voting powers.
+        #[test]
+        fn test_ensure_voting_powers() {
+            let height = BlockHeight(10);
+            assert_matches!(
+                ensure_voting_powers(&StdIo, &VotingPowersMap::new(), height),
+                Err(Error::EthereumBridge(
+                    EthereumBridgeError::NoVotingPowers(10)
+                ))
+            );
+            let voting_powers =
+                VotingPowersMap::from([(address_book(1), Amount::from(1))]);
+            assert!(
+                ensure_voting_powers(&StdIo, &voting_powers, height).is_ok()
+            );
+        }
+
+        /// Test that the achieved voting power is reported, even
+        /// if the signatures do not constitute a quorum.
+        #[test]
+        fn test_signature_count_without_quorum() {
+            let voting_powers = VotingPowersMap::from([
+                (address_book(1), Amount::from(1)),
+                (address_book(2), Amount::from(1)),
+                (address_book(3), Amount::from(1)),
+            ]);
+            let signatures =
+                HashMap::from([(address_book(1), 0), (address_book(2), 0)]);
+            let (checks, power) = signature_checks(voting_powers, &signatures);
+            assert_eq!(checks, Uint::from_u64(2));
+            assert_eq!(power, FractionalVotingPower::TWO_THIRDS);
+        }
+
+        /// Test that the validators missing from the signatures are
+        /// collected in descending order by voting power.
+        #[test]
+        fn test_unsigned_validators() {
+            let voting_powers = VotingPowersMap::from([
+                (address_book(1), Amount::from(5)),
+                (address_book(2), Amount::from(1)),
+                (address_book(3), Amount::from(3)),
+                (address_book(4), Amount::from(2)),
+            ]);
+            let signatures =
+                HashMap::from([(address_book(1), 0), (address_book(4), 0)]);
+            assert_eq!(
+                unsigned_validators(&voting_powers, &signatures),
+                vec![
+                    (address_book(3), Amount::from(3)),
+                    (address_book(2), Amount::from(1)),
+                ]
+            );
+
+            let signatures: HashMap<_, _> =
+                voting_powers.keys().map(|addr| (addr.clone(), 0)).collect();
+            assert!(unsigned_validators(&voting_powers, &signatures).is_empty());
+        }
+
+        /// Test that fees are counted within buckets of equal width.
+        #[test]
+        fn test_fee_histogram_buckets() {
+            let bucket = |min_fee_gwei: u64, max_fee_gwei: u64, transfers| {
+                FeeHistogramBucket {
+                    min_fee_gwei: Uint::from_u64(min_fee_gwei),
+                    max_fee_gwei: Uint::from_u64(max_fee_gwei),
+                    transfers,
+                }
+            };
+            let fees: Vec<_> = [10, 12, 15, 20, 29, 40]
+                .into_iter()
+                .map(Uint::from_u64)
+                .collect();
+            assert_eq!(
+                fee_histogram_buckets(&fees, 3),
+                vec![bucket(10, 20, 4), bucket(21, 31, 1), bucket(32, 42, 1)]
+            );
+
+            let fees = vec![Uint::from_u64(7); 3];
+            assert_eq!(fee_histogram_buckets(&fees, 5), vec![bucket(7, 7, 3)]);
+            assert!(fee_histogram_buckets(&fees, 0).is_empty());
+            assert!(fee_histogram_buckets(&[], 5).is_empty());
+        }
+
+        /// Test that only histograms with a bounded, non-zero number
+        /// of buckets may be requested.
+        #[test]
+        fn test_validate_histogram_buckets() {
+            assert!(validate_histogram_buckets(&StdIo, 1).is_ok());
+            assert!(validate_histogram_buckets(
+                &StdIo,
+                MAX_FEE_HISTOGRAM_BUCKETS
+            )
+            .is_ok());
+            for buckets in [0, MAX_FEE_HISTOGRAM_BUCKETS + 1, u64::MAX] {
+                assert_matches!(
+                    validate_histogram_buckets(&StdIo, buckets),
+                    Err(Error::EthereumBridge(
+                        EthereumBridgeError::InvalidHistogramBuckets { .. }
+                    ))
+                );
+            }
+        }
+
+        /// Test the minimum fee required to rank among the top fees.
+        #[test]
+        fn test_min_fee_in_top_percent() {
+            let fees: Vec<_> = (1..=10).map(Uint::from_u64).collect();
+            assert_eq!(
+                min_fee_in_top_percent(&fees, 10),
+                Some(Uint::from_u64(10))
+            );
+            assert_eq!(
+                min_fee_in_top_percent(&fees, 25),
+                Some(Uint::from_u64(8))
+            );
+            assert_eq!(
+                min_fee_in_top_percent(&fees, 50),
+                Some(Uint::from_u64(6))
+            );
+            assert_eq!(
+                min_fee_in_top_percent(&fees[..3], 10),
+                Some(Uint::from_u64(3))
+            );
+            assert_eq!(min_fee_in_top_percent(&[], 10), None);
+        }
+
+        #[test]
+        fn test_only_profitable() {
+            let profitable = vec![transfer(100_000); 17];
+            let hash = profitable[0].keccak256().to_hex();
+            let expected = vec![hash; 17];
+            let recommendation = generate_recommendations(
+                &StdIo,
+                process_transfers(profitable),
+                &Default::default(),
+                Uint::from_u64(800_000),
+                uint::MAX_VALUE,
+                I256::zero(),
+                false,
+            )
+            .unwrap()
+            .expect("Test failed")
+            .transfer_hashes;
+            assert_eq!(recommendation, expected);
+        }
+
+        /// Test that a profitable batch is not recommended, if it has
+        /// fewer transfers than the minimum batch size.
+        #[test]
+        fn test_min_batch_size() {
+            let recommend = || {
+                generate_recommendations(
+                    &StdIo,
+                    process_transfers(vec![transfer(1_000_000)]),
+                    &Default::default(),
+                    Uint::from_u64(800_000),
+                    uint::MAX_VALUE,
+                    I256::zero(),
+                    false,
+                )
+                .unwrap()
+            };
+            let batch = recommend().expect("Test failed");
+            assert_eq!(batch.transfer_hashes.len(), 1);
+            assert!(batch.net_profit.is_positive());
+            assert!(
+                enforce_min_batch_size(&StdIo, recommend(), Some(2)).is_none()
+            );
+            assert_eq!(
+                enforce_min_batch_size(&StdIo, recommend(), Some(1)),
+                Some(batch)
+            );
+        }
+
+        /// Test that the fee to make the next batch outbids the
+        /// competing transfers, when the batch has room for only one.
+        #[test]
+        fn test_min_fee_to_make_batch() {
+            let conversion_table = HashMap::from([(
+                namada_core::types::address::nam(),
+                args::BpConversionTableEntry {
+                    alias: "NAM".into(),
+                    conversion_rate: 1e9,
+                    as_of: None,
+                    fee_weight: None,
+                },
+            )]);
+            let validator_gas = Uint::from_u64(800_000);
+            let mut proposed = transfer(0);
+            proposed.transfer.recipient = EthAddress([3; 20]);
+
+            // with no competition, the transfer must cover the
+            // cost of its relay on its own
+            let fee = min_fee_to_make_batch(
+                proposed.clone(),
+                HashMap::new(),
+                &BTreeSet::new(),
+                &conversion_table,
+                validator_gas,
+                &RelayerConstraints::default(),
+            )
+            .expect("Test failed");
+            assert_eq!(fee, Amount::from_u64(837_500));
+
+            let competitor = transfer(1_000_000);
+            let pool =
+                HashMap::from([(competitor.keccak256().to_hex(), competitor)]);
+            let constraints = RelayerConstraints {
+                max_gas: Some(837_500),
+                ..Default::default()
+            };
+            let fee = min_fee_to_make_batch(
+                proposed.clone(),
+                pool.clone(),
+                &BTreeSet::new(),
+                &conversion_table,
+                validator_gas,
+                &constraints,
+            )
+            .expect("Test failed");
+            assert!(fee >= Amount::from_u64(1_000_000));
+            assert!(fee <= Amount::from_u64(1_000_001));
+
+            // a competitor whose relay is in progress is never
+            // recommended, so it need not be outbid
+            let in_progress = pool.keys().cloned().collect();
+            let fee = min_fee_to_make_batch(
+                proposed.clone(),
+                pool.clone(),
+                &in_progress,
+                &conversion_table,
+                validator_gas,
+                &constraints,
+            )
+            .expect("Test failed");
+            assert_eq!(fee, Amount::from_u64(837_500));
+
+            let constraints = RelayerConstraints {
+                max_gas: Some(800_000),
+                ..Default::default()
+            };
+            assert_matches!(
+                min_fee_to_make_batch(
+                    proposed,
+                    pool,
+                    &BTreeSet::new(),
+                    &conversion_table,
+                    validator_gas,
+                    &constraints,
+                ),
+                Err(Error::EthereumBridge(
+                    EthereumBridgeError::UnreachableBatch(_)
+                ))
+            );
+        }
+
+        /// Test that the fee to make the next batch is bounded by the
+        /// weighted worth of the fee token, when it is weighted below 1.
+        #[test]
+        fn test_min_fee_to_make_batch_weighted() {
+            let conversion_table = HashMap::from([(
+                namada_core::types::address::nam(),
+                args::BpConversionTableEntry {
+                    alias: "NAM".into(),
+                    conversion_rate: 1e8,
+                    as_of: None,
+                    fee_weight: Some(0.5),
+                },
+            )]);
+            let validator_gas = Uint::from_u64(800_000);
+
+            // a single token is worth 10 gwei, weighted down to 5
+            let fee = min_fee_to_make_batch(
+                transfer(0),
+                HashMap::new(),
+                &BTreeSet::new(),
+                &conversion_table,
+                validator_gas,
+                &RelayerConstraints::default(),
+            )
+            .expect("Test failed");
+            assert_eq!(fee, Amount::from_u64(167_500));
+        }
+
+        /// Test that tracing the decisions of the recommender does
+        /// not affect the recommended batch.
+        #[test]
+        fn test_recommender_trace() {
+            let step = TraceStep {
+                hash: "abcd",
+                cost: I256::from(-10),
+                total_gas: Uint::from_u64(837_500),
+                total_cost: I256::from(5),
+                added: true,
+                reason: TraceReason::NotYetWithinLimits,
+            };
+            assert_eq!(
+                step.to_string(),
+                "abcd: cost -10, total gas 837500, total cost 5, added \
+                 (profitable, not yet within the limits)"
+            );
+            assert_eq!(
+                exceeded_limit(
+                    Uint::from_u64(2),
+                    I256::from(2),
+                    Uint::from_u64(1),
+                    I256::from(1),
+                ),
+                Some(TraceReason::ExceedsMaxGas)
+            );
+
+            let transfers = vec![transfer(1_000_000), transfer(100_000)];
+            for generate in [
+                generate_recommendations::<StdIo>,
+                generate_throughput_recommendations::<StdIo>,
+            ] {
+                let recommend = |trace| {
+                    generate(
+                        &StdIo,
+                        process_transfers(transfers.clone()),
+                        &Default::default(),
+                        Uint::from_u64(800_000),
+                        uint::MAX_VALUE,
+                        I256::zero(),
+                        trace,
+                    )
+                    .unwrap()
+                };
+                assert_eq!(recommend(true), recommend(false));
+            }
+        }
+
+        #[test]
+        fn test_non_profitable_removed() {
+            let mut transfers = vec![transfer(100_000); 17];
+            let hash = transfers[0].keccak256().to_hex();
+            transfers.push(transfer(0));
+            let expected: Vec<_> = vec![hash; 17];
+            let recommendation = generate_recommendations(
+                &StdIo,
+                process_transfers(transfers),
+                &Default::default(),
+                Uint::from_u64(800_000),
+                uint::MAX_VALUE,
+                I256::zero(),
+                false,
+            )
+            .unwrap()
+            .expect("Test failed")
+            .transfer_hashes;
+            assert_eq!(recommendation, expected);
+        }
+
+        #[test]
+        fn test_max_gas() {
+            let transfers = vec![transfer(75_000); 4];
+            let hash = transfers[0].keccak256().to_hex();
+            let expected = vec![hash; 2];
+            let recommendation = generate_recommendations(
+                &StdIo,
+                process_transfers(transfers),
+                &Default::default(),
+                Uint::from_u64(50_000),
+                Uint::from_u64(150_000),
+                I256(uint::MAX_SIGNED_VALUE),
+                false,
+            )
+            .unwrap()
+            .expect("Test failed")
+            .transfer_hashes;
+            assert_eq!(recommendation, expected);
+        }
+
+        #[test]
+        fn test_net_loss() {
+            let mut transfers = vec![transfer(75_000); 4];
+            transfers.extend([transfer(17_500), transfer(17_500)]);
+            let expected: Vec<_> = transfers
+                .iter()
+                .map(|t| t.keccak256().to_hex())
+                .take(5)
+                .collect();
+            let recommendation = generate_recommendations(
+                &StdIo,
+                process_transfers(transfers),
+                &Default::default(),
+                Uint::from_u64(150_000),
+                uint::MAX_VALUE,
+                I256::from(20_000),
+                false,
+            )
+            .unwrap()
+            .expect("Test failed")
+            .transfer_hashes;
+            assert_eq!(recommendation, expected);
+        }
+
+        #[test]
+        fn test_net_loss_max_gas() {
+            let mut transfers = vec![transfer(75_000); 4];
+            let hash = transfers[0].keccak256().to_hex();
+            let expected = vec![hash; 4];
+            transfers.extend([transfer(17_500), transfer(17_500)]);
+            let recommendation = generate_recommendations(
+                &StdIo,
+                process_transfers(transfers),
+                &Default::default(),
+                Uint::from_u64(150_000),
+                Uint::from_u64(330_000),
+                I256::from(20_000),
+                false,
+            )
+            .unwrap()
+            .expect("Test failed")
+            .transfer_hashes;
+            assert_eq!(recommendation, expected);
+        }
+
+        #[test]
+        fn test_wholly_infeasible() {
+            let transfers = vec![transfer(75_000); 4];
+            let recommendation = generate_recommendations(
+                &StdIo,
+                process_transfers(transfers),
+                &Default::default(),
+                Uint::from_u64(300_000),
+                uint::MAX_VALUE,
+                I256::from(20_000),
+                false,
+            )
+            .unwrap();
+            assert!(recommendation.is_none())
+        }
+
+        /// Test that maximizing the throughput of a relay includes
+        /// unprofitable transfers subsidized by profitable ones, which
+        /// maximizing the profit of the relay leaves out.
+        #[test]
+        fn test_objectives_select_different_batches() {
+            let mut transfers = vec![transfer(75_000); 4];
+            transfers.extend([transfer(17_500), transfer(17_500)]);
+            let hashes: Vec<_> =
+                transfers.iter().map(|t| t.keccak256().to_hex()).collect();
+            let recommend = |objective: args::Objective| {
+                let generate = match objective {
+                    args::Objective::MaxProfit => generate_recommendations,
+                    args::Objective::MaxThroughput => {
+                        generate_throughput_recommendations
+                    }
+                };
+                generate(
+                    &StdIo,
+                    process_transfers(transfers.clone()),
+                    &Default::default(),
+                    Uint::from_u64(100_000),
+                    uint::MAX_VALUE,
+                    I256::zero(),
+                    false,
+                )
+                .unwrap()
+                .expect("Test failed")
+            };
+
+            let max_profit = recommend(args::Objective::MaxProfit);
+            assert_eq!(max_profit.transfer_hashes, hashes[..4]);
+            assert_eq!(max_profit.net_profit, I256::from(50_000));
+
+            let max_throughput = recommend(args::Objective::MaxThroughput);
+            assert_eq!(max_throughput.transfer_hashes, hashes);
+            assert_eq!(max_throughput.net_profit, I256::from(10_000));
+        }
+
+        /// Test that amounts of gwei are rendered with thousands
+        /// separators, along with their equivalent in ether.
+        #[test]
+        fn test_format_gwei() {
+            assert_eq!(format_gwei(I256::from(0)), "0 gwei");
+            assert_eq!(format_gwei(I256::from(999)), "999 gwei");
+            assert_eq!(format_gwei(I256::from(123_456)), "123,456 gwei");
+            assert_eq!(
+                format_gwei(I256::from(1_234_567_890)),
+                "1,234,567,890 gwei (1.23456789 ETH)"
+            );
+            assert_eq!(
+                format_gwei(-I256::from(2_000_000_000)),
+                "-2,000,000,000 gwei (-2 ETH)"
+            );
+            assert_eq!(
+                format_gwei(I256::from(5_000_000)),
+                "5,000,000 gwei (0.005 ETH)"
+            );
+        }
+
+        /// Test that batches earning no fees are never recommended,
+        /// even if a positive cost is accepted.
+        #[test]
+        fn test_generous_zero_fees() {
+            let transfers = vec![transfer(0); 4];
+            let recommendation = generate_recommendations(
+                &StdIo,
+                process_transfers(transfers),
+                &Default::default(),
+                Uint::from_u64(150_000),
+                uint::MAX_VALUE,
+                I256(uint::MAX_SIGNED_VALUE),
+                false,
+            )
+            .unwrap();
+            assert!(recommendation.is_none())
+        }
+
+        /// Test that the fees paid in distinct tokens are valued by
+        /// the recommender according to their weights.
+        #[test]
+        fn test_weighted_fee_tokens() {
+            let apfel = namada_core::types::address::apfel();
+            let schnitzel = namada_core::types::address::schnitzel();
+            let paid_in = |token: &Address, amount: u64| {
+                let mut pending = transfer(amount);
+                pending.gas_fee.token = token.clone();
+                pending
+            };
+            // the fees paid in apfel are worth more, unless fees paid
+            // in schnitzel are valued twice as much by the relayer
+            let in_apfel = paid_in(&apfel, 100_000);
+            let in_schnitzel = paid_in(&schnitzel, 150_000);
+            let recommend = |schnitzel_weight| {
+                let entry = |alias: &str, conversion_rate, fee_weight| {
+                    args::BpConversionTableEntry {
+                        alias: alias.into(),
+                        conversion_rate,
+                        as_of: None,
+                        fee_weight,
+                    }
+                };
+                let conversion_table = HashMap::from([
+                    (apfel.clone(), entry("APF", 5e8, None)),
+                    (schnitzel.clone(), entry("SCH", 1e9, schnitzel_weight)),
+                ]);
+                let signed_pool: HashMap<_, _> = [&in_apfel, &in_schnitzel]
+                    .into_iter()
+                    .map(|pending| {
+                        (pending.keccak256().to_hex(), pending.clone())
+                    })
+                    .collect();
+                let eligible = generate_eligible(
+                    &StdIo,
+                    &conversion_table,
+                    &BTreeSet::new(),
+                    signed_pool,
+                )
+                .unwrap();
+                // only a single transfer fits within the gas budget
+                generate_recommendations(
+                    &StdIo,
+                    eligible,
+                    &conversion_table,
+                    Uint::from_u64(100_000),
+                    Uint::from_u64(137_500),
+                    I256::zero(),
+                    false,
+                )
+                .unwrap()
+                .expect("Test failed")
+                .transfer_hashes
+            };
+            assert_eq!(recommend(None), vec![in_apfel.keccak256().to_hex()]);
+            assert_eq!(
+                recommend(Some(2.0)),
+                vec![in_schnitzel.keccak256().to_hex()]
+            );
+            // invalid weights exclude the transfers paid in the token
+            assert_eq!(
+                recommend(Some(-1.0)),
+                vec![in_apfel.keccak256().to_hex()]
+            );
+        }
+
+        /// Test that fees weighted by a unit weight are valued the
+        /// same as unweighted fees, and that the profit of a batch is
+        /// reported in gwei, regardless of the weights of its fees.
+        #[test]
+        fn test_weighted_and_unweighted_fees_agree() {
+            let apfel = namada_core::types::address::apfel();
+            let mut pending = transfer(100_000);
+            pending.gas_fee.token = apfel.clone();
+            let recommend = |fee_weight| {
+                let conversion_table = HashMap::from([(
+                    apfel.clone(),
+                    args::BpConversionTableEntry {
+                        alias: "APF".into(),
+                        conversion_rate: 5e8,
+                        as_of: None,
+                        fee_weight,
+                    },
+                )]);
+                let signed_pool = HashMap::from([(
+                    pending.keccak256().to_hex(),
+                    pending.clone(),
+                )]);
+                let eligible = generate_eligible(
+                    &StdIo,
+                    &conversion_table,
+                    &BTreeSet::new(),
+                    signed_pool,
+                )
+                .unwrap();
+                let costs: Vec<_> = eligible
+                    .iter()
+                    .map(|eligible| (eligible.cost, eligible.unweighted_cost))
+                    .collect();
+                let batch = generate_recommendations(
+                    &StdIo,
+                    eligible,
+                    &conversion_table,
+                    Uint::from_u64(100_000),
+                    uint::MAX_VALUE,
+                    I256::zero(),
+                    false,
+                )
+                .unwrap()
+                .expect("Test failed");
+                (costs, batch.net_profit)
+            };
+
+            // a single apfel is worth 2 gwei
+            let unweighted_cost = transfer_fee() - I256::from(200_000);
+            let net_profit = -unweighted_cost - I256::from(100_000);
+            let unweighted = recommend(None);
+            assert_eq!(
+                unweighted,
+                (vec![(unweighted_cost, unweighted_cost)], net_profit)
+            );
+            assert_eq!(recommend(Some(1.0)), unweighted);
+
+            // weights change how fees are valued, but not the profit
+            let weighted_cost = transfer_fee() - I256::from(400_000);
+            assert_eq!(
+                recommend(Some(2.0)),
+                (vec![(weighted_cost, unweighted_cost)], net_profit)
+            );
+        }
+
+        /// Test the profit margin obtained from relaying two
+        /// Bridge pool transfers with two distinct token types,
+        /// whose relation is 1:2 in value.
+        #[test]
+        fn test_conversion_table_profit_margin() {
+            // apfel is worth twice as much as schnitzel
+            const APF_RATE: f64 = 5e8;
+            const SCH_RATE: f64 = 1e9;
+            const APFEL: &str = "APF";
+            const SCHNITZEL: &str = "SCH";
+
+            let conversion_table = {
+                let mut t = HashMap::new();
+                t.insert(
+                    namada_core::types::address::apfel(),
+                    args::BpConversionTableEntry {
+                        alias: APFEL.into(),
+                        conversion_rate: APF_RATE,
+                        as_of: None,
+                        fee_weight: None,
+                    },
+                );
+                t.insert(
+                    namada_core::types::address::schnitzel(),
+                    args::BpConversionTableEntry {
+                        alias: SCHNITZEL.into(),
+                        conversion_rate: SCH_RATE,
+                        as_of: None,
+                        fee_weight: None,
+                    },
+                );
+                t
+            };
+
+            let eligible = test_generate_eligible_aux(|ctx| {
+                ctx.conversion_table.clone_from(&conversion_table);
+                // tune the pending transfer provided by the ctx
+                let transfer_paid_in_apfel = {
+                    let mut pending = ctx.pending.clone();
+                    pending.transfer.amount = 1.into();
+                    pending.gas_fee.token =
+                        namada_core::types::address::apfel();
+                    pending
+                };
+                let transfer_paid_in_schnitzel = {
+                    let mut pending = ctx.pending.clone();
+                    pending.transfer.amount = 2.into();
+                    pending.gas_fee.token =
+                        namada_core::types::address::schnitzel();
+                    pending
+                };
+                // add the transfers to the pool, and expect them to
+                // be eligible transfers
+                for (pending, rate) in [
+                    (transfer_paid_in_apfel, APF_RATE),
+                    (transfer_paid_in_schnitzel, SCH_RATE),
+                ] {
+                    ctx.signed_pool
+                        .insert(pending.keccak256().to_hex(), pending.clone());
+                    let cost = transfer_fee()
+                        - I256::from((1e9 / rate).floor() as u64)
+                            * I256::try_from(pending.gas_fee.amount)
+                                .expect("Test failed");
+                    ctx.expected_eligible.push(EligibleRecommendation {
+                        transfer_hash: pending.keccak256().to_hex(),
+                        cost,
+                        unweighted_cost: cost,
+                        pending_transfer: pending,
+                    });
+                }
+            });
+
+            const VALIDATOR_GAS_FEE: Uint = Uint::from_u64(100_000);
+
+            let recommended_batch = generate_recommendations(
+                &StdIo,
+                eligible,
+                &conversion_table,
+                // gas spent by validator signature checks
+                VALIDATOR_GAS_FEE,
+                // unlimited amount of gas
+                uint::MAX_VALUE,
+                // only profitable
                 I256::zero(),
+                false,
+            )
+            .unwrap()
+            .expect("Test failed");
+
+            assert_eq!(
+                recommended_batch.net_profit,
+                I256::from(1_000_000_000_u64) + I256::from(2_000_000_000_u64)
+                    - I256(VALIDATOR_GAS_FEE)
+                    - transfer_fee() * I256::from(2_u64)
+            );
+        }
+    }
+}
+
+pub use recommendations::{
+    break_even_gas_price, estimated_relay_gas, fee_to_make_next_batch,
+    format_gwei, is_profitable_standalone, missing_signers,
+    query_fee_histogram, query_unrelayable_transfers, recommend_batch,
+    suggest_min_fee, total_fees_in_gwei, FeeHistogram, FeeHistogramBucket,
+    FeePercentile, ProfitabilityReport, RecommendationStats, RelayReadiness,
+    RelayerConstraints,
+};
+
+#[cfg(test)]
+mod test_bridge_pool {
+    use std::sync::Mutex;
+
+    use assert_matches::assert_matches;
+
+    use namada_core::types::address::nam;
+    use namada_core::types::address::testing::established_address_1;
+    use namada_core::types::chain::ChainId;
+    use namada_core::types::hash::{Hash, KeccakHasher};
+    use namada_core::types::key::testing::gen_keypair;
+    use namada_core::types::key::{RefTo, SigScheme};
+    use namada_storage::StorageWrite;
+
+    use super::recommendations::test_recommendations::{
+        bertha_address, transfer,
+    };
+    use super::*;
+    use crate::eth_bridge::testing::MockEthereum;
+    use crate::io::StdIo;
+    use crate::queries::testing::TestClient;
+
+    /// Test that describing a Bridge pool transaction decodes
+    /// the pending transfer and the header of the transaction.
+    #[test]
+    fn test_describe_bridge_pool_tx() {
+        let transfer = transfer(1);
+        let mut tx = Tx::new(ChainId::default(), None);
+        tx.add_code_from_hash(
+            Hash([3; 32]),
+            Some("tx_bridge_pool.wasm".into()),
+        )
+        .add_data(transfer.clone());
+
+        let description = describe_bridge_pool_tx(&tx).unwrap();
+        for expected in [
+            transfer.keccak256().to_hex(),
+            transfer.transfer.asset.to_string(),
+            transfer.transfer.recipient.to_string(),
+            transfer.transfer.sender.to_string(),
+            format!("{} (tx_bridge_pool.wasm)", Hash([3; 32])),
+            ChainId::default().to_string(),
+        ] {
+            assert!(description.contains(&expected), "{description}");
+        }
+
+        // txs without data cannot be described
+        let mut tx = Tx::new(ChainId::default(), None);
+        tx.add_code_from_hash(Hash([3; 32]), None);
+        assert!(describe_bridge_pool_tx(&tx).is_err());
+    }
+
+    /// Test that only NUT transfers are described as redemptions
+    /// of NUTs.
+    #[test]
+    fn test_describe_nut_redemption() {
+        let mut transfer = transfer(1);
+        assert!(describe_nut_redemption(&transfer).is_none());
+
+        transfer.transfer.kind = TransferToEthereumKind::Nut;
+        let redemption = describe_nut_redemption(&transfer).unwrap();
+        assert!(redemption.contains(&transfer.transfer.asset.to_string()));
+        assert!(redemption.contains(&transfer.transfer.recipient.to_string()));
+    }
+
+    /// Test that we recover the Ethereum address of the signer
+    /// of a Bridge pool root.
+    #[test]
+    fn test_recover_eth_signer() {
+        let sk = gen_keypair::<secp256k1::SigScheme>();
+        let signer = EthAddress::from(&sk.ref_to());
+        let message = SignableEthMessage::as_signable(&keccak_hash(
+            [[1u8; 32], [2u8; 32]].concat(),
+        ));
+        let sig = secp256k1::SigScheme::sign_with_hasher::<KeccakHasher>(
+            &sk, message,
+        );
+        assert_eq!(recover_eth_signer(&message, &sig), Some(signer));
+
+        // the signature does not match a different message
+        let other = SignableEthMessage::as_signable(&keccak_hash([3u8; 32]));
+        assert_ne!(recover_eth_signer(&other, &sig), Some(signer));
+    }
+
+    /// Test that compressed proofs are decompressed back into
+    /// the original ABI encoded arguments.
+    #[test]
+    fn test_proof_encoding_roundtrip() {
+        let abi_encoded_args = [[7u8; 32], [0u8; 32], [7u8; 32]].concat();
+        for encoding in [ProofEncoding::Raw, ProofEncoding::Gzip] {
+            let encoded = encoding.encode(abi_encoded_args.clone()).unwrap();
+            let decoded = encoding.decode(encoded).unwrap();
+            assert_eq!(decoded, abi_encoded_args);
+        }
+        let compressed = ProofEncoding::Gzip
+            .encode(abi_encoded_args.clone())
+            .unwrap();
+        assert!(compressed.len() < abi_encoded_args.len());
+        assert_eq!(
+            serde_json::to_string(&ProofEncoding::Gzip).unwrap(),
+            "\"gzip\""
+        );
+    }
+
+    /// Test that stored proofs are read back with their ABI
+    /// encoded arguments decompressed, and that they must cover
+    /// exactly the transfers requested to be relayed.
+    #[test]
+    fn test_stored_bridge_pool_proof() {
+        let abi_encoded_args = [[7u8; 32], [0u8; 32]].concat();
+        let hashes = vec![KeccakHash([1; 32]), KeccakHash([2; 32])];
+        let proof = BridgePoolProofResponse {
+            version: BRIDGE_POOL_PROOF_VERSION,
+            hashes: hashes.clone(),
+            relayer_address: established_address_1(),
+            total_fees: HashMap::new(),
+            total_fees_denominated: HashMap::new(),
+            total_fees_gwei: None,
+            transfers: None,
+            abi_encoded_args_encoding: ProofEncoding::Gzip,
+            abi_encoded_args: ProofEncoding::Gzip
+                .encode(abi_encoded_args.clone())
+                .unwrap(),
+        };
+        let json = serde_json::to_value(&proof).unwrap();
+        assert!(json["abi_encoded_args"].as_str().unwrap().starts_with("0x"));
+        let dir = tempfile::tempdir().unwrap();
+        let proof_file = dir.path().join("proof.json");
+        std::fs::write(&proof_file, json.to_string()).unwrap();
+        let stored = StoredBridgePoolProof::load(&proof_file).unwrap();
+        assert_eq!(stored.version, BRIDGE_POOL_PROOF_VERSION);
+        assert_eq!(stored.abi_encoded_args, abi_encoded_args);
+
+        // proofs written as a list of numbers can still be read
+        let legacy = serde_json::json!({
+            "version": BRIDGE_POOL_PROOF_VERSION,
+            "hashes": json["hashes"],
+            "abi_encoded_args": abi_encoded_args,
+        });
+        std::fs::write(&proof_file, legacy.to_string()).unwrap();
+        let legacy = StoredBridgePoolProof::load(&proof_file).unwrap();
+        assert_eq!(legacy.abi_encoded_args, abi_encoded_args);
+
+        // the transfers of the proof are relayed if none are requested
+        let mut transfers = vec![];
+        stored.check_transfers(&mut transfers).unwrap();
+        assert_eq!(transfers, hashes);
+        let mut transfers = vec![hashes[1].clone(), hashes[0].clone()];
+        stored.check_transfers(&mut transfers).unwrap();
+        let mut transfers = vec![hashes[0].clone(), KeccakHash([3; 32])];
+        assert_matches!(
+            stored.check_transfers(&mut transfers),
+            Err(Error::EthereumBridge(
+                EthereumBridgeError::StoredProofMismatch(_)
+            ))
+        );
+    }
+
+    /// Test that the relay progress monitor computes the rate at
+    /// which transfers gain voting power, and flags stalled ones.
+    #[test]
+    fn test_relay_progress_monitor() {
+        let transfer = transfer(1);
+        let mut monitor = RelayProgressMonitor::new(Duration::from_secs(10));
+        let start = Instant::now();
+
+        // transfers observed for the first time have no rate
+        let report = monitor.observe(
+            start,
+            [(transfer.clone(), FractionalVotingPower::ONE_THIRD)],
+        );
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].rate, None);
+        assert!(!report[0].stalled);
+
+        // gaining voting power yields a positive rate
+        let report = monitor.observe(
+            start + Duration::from_secs(10),
+            [(transfer.clone(), FractionalVotingPower::HALF)],
+        );
+        let rate = report[0].rate.unwrap();
+        assert!((rate - (0.5 - 1.0 / 3.0) / 10.0).abs() < 1e-9);
+        assert!(!report[0].stalled);
+
+        // transfers are stalled once they stop gaining voting power
+        let report = monitor.observe(
+            start + Duration::from_secs(15),
+            [(transfer.clone(), FractionalVotingPower::HALF)],
+        );
+        assert_eq!(report[0].rate, Some(0.0));
+        assert!(!report[0].stalled);
+        let report = monitor.observe(
+            start + Duration::from_secs(20),
+            [(transfer.clone(), FractionalVotingPower::HALF)],
+        );
+        assert!(report[0].stalled);
+
+        // transfers no longer being relayed are forgotten
+        assert!(
+            monitor
+                .observe(start + Duration::from_secs(25), [])
+                .is_empty()
+        );
+        let report = monitor.observe(
+            start + Duration::from_secs(30),
+            [(transfer, FractionalVotingPower::HALF)],
+        );
+        assert_eq!(report[0].rate, None);
+        assert!(!report[0].stalled);
+    }
+
+    /// Test the json shape of the relay progress of a transfer.
+    #[test]
+    fn test_relay_progress_response_json() {
+        let transfer = transfer(1);
+        let transfer_hash = transfer.keccak256().to_hex();
+        assert!(transfer_hash.starts_with("0x"));
+        assert_eq!(
+            transfer_hash,
+            format!("0x{}", HEXLOWER.encode(&transfer.keccak256().0))
+        );
+
+        let response =
+            RelayProgressResponse::new(&transfer, FractionalVotingPower::HALF);
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            serde_json::json!({
+                "transfer_hash": transfer_hash,
+                "voting_power": "0.5",
+                "quorum_reached": false,
+            })
+        );
+
+        let response =
+            RelayProgressResponse::new(&transfer, FractionalVotingPower::WHOLE);
+        assert_eq!(
+            serde_json::to_value(&response).unwrap(),
+            serde_json::json!({
+                "transfer_hash": transfer_hash,
+                "voting_power": "1",
+                "quorum_reached": true,
+            })
+        );
+
+        let response = RelayProgressResponse::new(
+            &transfer,
+            FractionalVotingPower::ONE_THIRD,
+        );
+        assert_eq!(response.voting_power, "0.333333333333");
+    }
+
+    /// Test that relayed batches are read back from the output
+    /// of the proof construction command, and that the fees they
+    /// earned are aggregated per relayer and token.
+    #[test]
+    fn test_relayer_fee_ledger() {
+        let relayer = established_address_1();
+        let proof = BridgePoolProofResponse {
+            version: BRIDGE_POOL_PROOF_VERSION,
+            hashes: vec![KeccakHash([1; 32]), KeccakHash([2; 32])],
+            relayer_address: relayer.clone(),
+            total_fees: HashMap::from([(nam(), Amount::from(10))]),
+            total_fees_denominated: HashMap::new(),
+            total_fees_gwei: None,
+            transfers: None,
+            abi_encoded_args_encoding: ProofEncoding::Raw,
+            abi_encoded_args: vec![],
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let proof_file = dir.path().join("proof.json");
+        std::fs::write(&proof_file, serde_json::to_vec(&proof).unwrap())
+            .unwrap();
+        let batch = RelayedBatch::load(&proof_file).unwrap();
+        assert_eq!(batch.hashes, proof.hashes);
+        assert_eq!(batch.relayer_address, relayer);
+        assert_eq!(batch.total_fees, proof.total_fees);
+        assert_eq!(batch.recorded_at, None);
+
+        let mut ledger = RelayerFeeLedger::default();
+        let recorded_at: DateTimeUtc = "2024-01-01T00:00:00Z".parse().unwrap();
+        assert!(ledger.record(RelayedBatch {
+            recorded_at: Some(recorded_at),
+            ..batch.clone()
+        }));
+        // the same transfers are not recorded twice
+        let mut reordered = batch.clone();
+        reordered.hashes.reverse();
+        assert!(!ledger.record(reordered));
+        let later: DateTimeUtc = "2024-02-01T00:00:00Z".parse().unwrap();
+        assert!(ledger.record(RelayedBatch {
+            hashes: vec![KeccakHash([3; 32])],
+            total_fees: HashMap::from([(nam(), Amount::from(5))]),
+            recorded_at: Some(later),
+            ..batch
+        }));
+
+        assert_eq!(
+            ledger.fees_per_token(None).unwrap(),
+            HashMap::from([(nam(), Amount::from(15))])
+        );
+        assert_eq!(
+            ledger.fees_per_relayer(Some(later)).unwrap(),
+            HashMap::from([(
+                relayer,
+                HashMap::from([(nam(), Amount::from(5))])
+            )])
+        );
+
+        // ledgers survive a roundtrip through their file
+        let ledger_file = dir.path().join("ledger.json");
+        assert_eq!(
+            RelayerFeeLedger::load(&ledger_file).unwrap(),
+            RelayerFeeLedger::default()
+        );
+        write_query_output(&crate::io::StdIo, Some(&ledger_file), &ledger)
+            .unwrap();
+        assert_eq!(RelayerFeeLedger::load(&ledger_file).unwrap(), ledger);
+    }
+
+    /// Build a client of a ledger node storing the address of the
+    /// Bridge contract.
+    fn bridge_contract_client() -> TestClient<RPC> {
+        use namada_ethereum_bridge::storage::bridge_contract_key;
+        use namada_ethereum_bridge::storage::parameters::UpgradeableContract;
+
+        let mut client = TestClient::new(RPC);
+        client
+            .wl_storage
+            .write(
+                &bridge_contract_key(),
+                UpgradeableContract {
+                    address: EthAddress([3; 20]),
+                    version: Default::default(),
+                },
             )
-            .unwrap()
-            .expect("Test failed")
-            .transfer_hashes;
-            assert_eq!(recommendation, expected);
+            .expect("Test failed");
+        client
+    }
+
+    /// Write a proof of a batch of transfers with the given nonce to
+    /// `proof_file`, as output by [`construct_proof`].
+    fn write_stored_proof(proof_file: &Path, batch_nonce: u64) {
+        use namada_core::types::ethereum_structs::{
+            RelayProof, Signature, ValidatorSetArgs,
+        };
+
+        let abi_encoded_args = ethers::abi::AbiEncode::encode((
+            ValidatorSetArgs::default(),
+            Vec::<Signature>::new(),
+            RelayProof {
+                batch_nonce: batch_nonce.into(),
+                ..Default::default()
+            },
+        ));
+        let proof = BridgePoolProofResponse {
+            version: BRIDGE_POOL_PROOF_VERSION,
+            hashes: vec![KeccakHash([1; 32])],
+            relayer_address: established_address_1(),
+            total_fees: HashMap::from([(nam(), Amount::from(10))]),
+            total_fees_denominated: HashMap::new(),
+            total_fees_gwei: None,
+            transfers: None,
+            abi_encoded_args_encoding: ProofEncoding::Raw,
+            abi_encoded_args,
+        };
+        std::fs::write(proof_file, serde_json::to_vec(&proof).unwrap())
+            .unwrap();
+    }
+
+    /// Test that batches are only deemed relayed once the Bridge
+    /// contract has moved past their nonce.
+    #[tokio::test]
+    async fn test_ensure_batch_relayed() {
+        let mut client = bridge_contract_client();
+        client.wl_storage.commit_block().expect("Test failed");
+        let dir = tempfile::tempdir().unwrap();
+        let proof_file = dir.path().join("proof.json");
+        write_stored_proof(&proof_file, 5);
+
+        for (contract_nonce, relayed) in [(5u64, false), (6, true)] {
+            let (eth_client, _) = MockEthereum::default()
+                .respond_call_u256(contract_nonce)
+                .connect();
+            let result =
+                ensure_batch_relayed(&client, &StdIo, &eth_client, &proof_file)
+                    .await;
+            if relayed {
+                assert!(result.is_ok());
+            } else {
+                assert_matches!(
+                    result,
+                    Err(Error::EthereumBridge(EthereumBridgeError::FeeLedger(
+                        _
+                    )))
+                );
+            }
         }
+    }
 
-        #[test]
-        fn test_non_profitable_removed() {
-            let mut transfers = vec![transfer(100_000); 17];
-            let hash = transfers[0].keccak256().to_string();
-            transfers.push(transfer(0));
-            let expected: Vec<_> = vec![hash; 17];
-            let recommendation = generate_recommendations(
+    /// Test the relay of a stored proof end to end, against the
+    /// nonces the Bridge contract may be at.
+    #[tokio::test]
+    async fn test_relay_bridge_pool_proof() {
+        let mut client = bridge_contract_client();
+        client
+            .wl_storage
+            .write(
+                &get_signed_root_key(),
+                (
+                    BridgePoolRootProof {
+                        signatures: Default::default(),
+                        data: (KeccakHash([1; 32]), 5.into()),
+                    },
+                    BlockHeight(1),
+                ),
+            )
+            .expect("Test failed");
+        client.wl_storage.commit_block().expect("Test failed");
+        let dir = tempfile::tempdir().unwrap();
+        let proof_file = dir.path().join("proof.json");
+        write_stored_proof(&proof_file, 5);
+
+        let args = args::RelayBridgePoolProof {
+            ledger_address: "tcp://127.0.0.1:26657".parse().unwrap(),
+            transfers: vec![],
+            relayer: established_address_1(),
+            confirmations: 1,
+            eth_rpc_endpoint: (),
+            gas: Some(100_000),
+            gas_price: Some(20),
+            gas_price_bump: None,
+            max_gas_price: None,
+            max_gas_price_gwei: None,
+            eth_addr: None,
+            sync: false,
+            safe_mode: false,
+            poll_interval: None,
+            rebroadcast_after: None,
+            max_rebroadcasts: None,
+            confirmation_timeout: None,
+            request_timeout: None,
+            state_file: None,
+            proof_file: Some(proof_file),
+            target_root: None,
+            fail_on_warning: false,
+            estimated_gas: None,
+            private_relay_url: None,
+            max_calldata_size: None,
+            access_list: false,
+            audit_log: None,
+            print_calldata: false,
+        };
+        // NB: the node is synchronized, and at a non-zero height
+        let synced_node = |contract_nonce: u64| {
+            MockEthereum::default()
+                .respond(false)
+                .respond(ethers::types::U64::from(100))
+                .respond_call_u256(contract_nonce)
+        };
+
+        // the contract is either past or behind the nonce of the batch
+        for contract_nonce in [6u64, 4] {
+            let (eth_client, _) = synced_node(contract_nonce).connect();
+            let result = relay_bridge_pool_proof(
+                eth_client,
+                &client,
                 &StdIo,
-                process_transfers(transfers),
-                &Default::default(),
-                Uint::from_u64(800_000),
-                uint::MAX_VALUE,
-                I256::zero(),
+                args.clone(),
+                None,
+                None,
             )
-            .unwrap()
-            .expect("Test failed")
-            .transfer_hashes;
-            assert_eq!(recommendation, expected);
+            .await;
+            assert_matches!(
+                result,
+                Err(Error::EthereumBridge(EthereumBridgeError::InvalidBpNonce))
+            );
+        }
+
+        // the contract is at the nonce of the batch, and the relay
+        // gets confirmed
+        let tx_hash = H256::repeat_byte(7);
+        let sent_tx = ethers::types::Transaction {
+            hash: tx_hash,
+            block_number: Some(10.into()),
+            ..Default::default()
+        };
+        let receipt = TransactionReceipt {
+            transaction_hash: tx_hash,
+            block_number: Some(10.into()),
+            status: Some(1u64.into()),
+            gas_used: Some(90_000.into()),
+            ..Default::default()
+        };
+        let (eth_client, _) = synced_node(5)
+            .respond(tx_hash)
+            .respond(&sent_tx)
+            .respond(&receipt)
+            .connect();
+        let result = relay_bridge_pool_proof(
+            eth_client, &client, &StdIo, args, None, None,
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+
+    /// Test that oversized batches of transfers are split into
+    /// batches expected to fit in the maximum calldata size.
+    #[test]
+    fn test_split_transfer_batch() {
+        let transfers: Vec<_> = (0..5u8).map(|i| KeccakHash([i; 32])).collect();
+
+        // batches within the limit are not split
+        assert_eq!(
+            split_transfer_batch(&transfers, 1_000, 1_000),
+            vec![transfers.clone()]
+        );
+
+        let batches = split_transfer_batch(&transfers, 2_500, 1_000);
+        assert_eq!(
+            batches,
+            vec![
+                transfers[..2].to_vec(),
+                transfers[2..4].to_vec(),
+                transfers[4..].to_vec(),
+            ]
+        );
+
+        // cannot split below a single transfer per batch
+        let batches = split_transfer_batch(&transfers, 10_000, 1_000);
+        assert_eq!(batches.len(), transfers.len());
+
+        assert!(split_transfer_batch(&[], 10_000, 1_000).is_empty());
+    }
+
+    /// Test the comparison of gas prices, in wei, against a
+    /// ceiling, in gwei.
+    #[test]
+    fn test_gas_price_exceeds_ceiling() {
+        let gwei = U256::from(1_000_000_000u64);
+        assert!(!gas_price_exceeds_ceiling(gwei * 30, 30));
+        assert!(gas_price_exceeds_ceiling(gwei * 30 + 1, 30));
+        assert!(!gas_price_exceeds_ceiling(gwei * 29, 30));
+        assert!(gas_price_exceeds_ceiling(U256::one(), 0));
+        // large ceilings do not overflow
+        assert!(!gas_price_exceeds_ceiling(
+            U256::from(u64::MAX) * gwei,
+            u64::MAX
+        ));
+    }
+
+    /// Test that the growth of the Bridge pool is estimated from
+    /// the transfers added within the window of the monitor.
+    #[test]
+    fn test_bridge_pool_growth_monitor() {
+        let start = Instant::now();
+        let mut monitor =
+            BridgePoolGrowthMonitor::new(Duration::from_secs(120));
+
+        // transfers already in the pool are not counted as additions
+        let growth = monitor.observe(start, [transfer(1), transfer(2)]);
+        assert_eq!(growth.pool_size, 2);
+        assert_eq!(growth.transfers_per_minute, None);
+        assert!(growth.fees_per_minute.is_empty());
+        assert!(growth.evicted.is_empty());
+
+        // two transfers were added over a minute, while one left
+        let growth = monitor.observe(
+            start + Duration::from_secs(60),
+            [transfer(2), transfer(3), transfer(5)],
+        );
+        assert_eq!(growth.pool_size, 3);
+        assert_eq!(growth.transfers_per_minute, Some(2.0));
+        assert_eq!(growth.fees_per_minute[&nam()], Amount::from(8));
+        assert_eq!(growth.evicted, HashSet::from([transfer(1).keccak256()]));
+
+        // one more transfer was added over the next minute
+        let growth = monitor.observe(
+            start + Duration::from_secs(120),
+            [transfer(2), transfer(3), transfer(5), transfer(8)],
+        );
+        assert_eq!(growth.transfers_per_minute, Some(1.5));
+        assert_eq!(growth.fees_per_minute[&nam()], Amount::from(8));
+        assert!(growth.evicted.is_empty());
+
+        // the baseline falls out of the window, which then starts
+        // at the observation made after the first minute
+        let growth = monitor.observe(
+            start + Duration::from_secs(180),
+            [transfer(2), transfer(3), transfer(5), transfer(8)],
+        );
+        assert_eq!(growth.span, Duration::from_secs(120));
+        assert_eq!(growth.transfers_per_minute, Some(0.5));
+        assert_eq!(growth.fees_per_minute[&nam()], Amount::from(4));
+    }
+
+    /// Test that evicted transfers are classified by their status.
+    #[test]
+    fn test_eviction_stats() {
+        let hash = |byte: u8| KeccakHash([byte; 32]);
+        let evicted = HashSet::from([hash(1), hash(2), hash(3), hash(4)]);
+        let status = TransferToEthereumStatus {
+            relayed: HashSet::from([hash(1), hash(2)]),
+            expired: HashSet::from([hash(3)]),
+            ..Default::default()
+        };
+        assert_eq!(
+            EvictionStats::new(&evicted, &status),
+            EvictionStats {
+                relayed: 2,
+                expired: 1,
+                unrecognized: 1,
+            }
+        );
+    }
+
+    /// Test that the proofs of upcoming roots are constructed while
+    /// the current root is being relayed, and that relays happen in
+    /// order of their nonces.
+    #[tokio::test]
+    async fn test_pipeline_relays() {
+        let events = Mutex::new(vec![]);
+        let log = |event: String| events.lock().unwrap().push(event);
+        pipeline_relays(
+            3u64..6,
+            NonZeroUsize::new(2).unwrap(),
+            |nonce| {
+                log(format!("construct {nonce}"));
+                future::ready(Ok((U256::from(nonce), nonce)))
+            },
+            |nonce| {
+                log(format!("relay {nonce}"));
+                future::ready(Ok(()))
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                "construct 3",
+                "construct 4",
+                "relay 3",
+                "construct 5",
+                "relay 4",
+                "relay 5",
+            ]
+        );
+
+        // gaps in the nonces of the relayed roots halt the pipeline
+        let relayed = Mutex::new(vec![]);
+        let result = pipeline_relays(
+            [3u64, 5],
+            NonZeroUsize::new(1).unwrap(),
+            |nonce| future::ready(Ok((U256::from(nonce), nonce))),
+            |nonce| {
+                relayed.lock().unwrap().push(nonce);
+                future::ready(Ok(()))
+            },
+        )
+        .await;
+        assert_matches!(
+            result,
+            Err(Error::EthereumBridge(
+                EthereumBridgeError::PipelineNonceGap { expected, got }
+            )) if expected == "4" && got == "5"
+        );
+        assert_eq!(*relayed.lock().unwrap(), vec![3]);
+    }
+
+    /// Test that relay attempts are appended to the audit log,
+    /// one line of json each.
+    #[test]
+    fn test_relay_audit_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let audit_log = dir.path().join("audit.jsonl");
+        let record = |outcome| RelayAuditRecord {
+            timestamp: DateTimeUtc::now(),
+            batch_nonce: "7".into(),
+            transfers: vec![KeccakHash([1; 32])],
+            gas_limit: Some("100000".into()),
+            gas_price: None,
+            tx_hash: Some(H256::repeat_byte(2)),
+            outcome,
+            gas_used: None,
+        };
+        let failed = record(RelayOutcome::Failed {
+            error: "underpriced".into(),
+        });
+        let confirmed = RelayAuditRecord {
+            gas_used: Some(90_000),
+            ..record(RelayOutcome::Confirmed)
+        };
+        failed.append(&audit_log).unwrap();
+        confirmed.append(&audit_log).unwrap();
+
+        let contents = std::fs::read_to_string(&audit_log).unwrap();
+        let records: Vec<RelayAuditRecord> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records, vec![failed, confirmed]);
+        assert!(contents.contains(r#""outcome":"confirmed""#));
+        assert!(contents.contains(&KeccakHash([1; 32]).to_hex()));
+    }
+
+    /// Test that queries of Bridge pools holding too many
+    /// transfers fail.
+    #[test]
+    fn test_ensure_transfer_count() {
+        assert!(ensure_transfer_count(&StdIo, 0, 0).is_ok());
+        assert!(ensure_transfer_count(&StdIo, 10, 10).is_ok());
+        assert_matches!(
+            ensure_transfer_count(&StdIo, 11, 10),
+            Err(Error::EthereumBridge(
+                EthereumBridgeError::TooManyTransfers { count: 11, max: 10 }
+            ))
+        );
+    }
+
+    /// Test that Bridge pools holding too many transfers are
+    /// rejected from the length of the response, and that
+    /// smaller pools are decoded.
+    #[tokio::test]
+    async fn test_read_transfers_checked() {
+        let mut client = TestClient::new(RPC);
+        for gas_amount in [1, 2] {
+            let pending = transfer(gas_amount);
+            client
+                .wl_storage
+                .write(&get_pending_key(&pending), &pending)
+                .expect("Test failed");
+        }
+        client.wl_storage.commit_block().expect("Test failed");
+        client.wl_storage.storage.block.height += 1;
+        let path = eth_bridge_router(None).read_ethereum_bridge_pool_path();
+
+        assert_matches!(
+            read_transfers_checked(
+                &client,
+                &StdIo,
+                path.clone(),
+                1,
+                EthereumBridgeError::ReadBridgePool,
+            )
+            .await,
+            Err(Error::EthereumBridge(
+                EthereumBridgeError::TooManyTransfers { count: 2, max: 1 }
+            ))
+        );
+        let transfers = read_transfers_checked(
+            &client,
+            &StdIo,
+            path,
+            2,
+            EthereumBridgeError::ReadBridgePool,
+        )
+        .await
+        .expect("Test failed");
+        assert_eq!(transfers.len(), 2);
+    }
+
+    /// Test that transfers are reported to be covered by the
+    /// first signed Bridge pool root at or after the height at
+    /// which they were added, rather than by the latest one.
+    #[tokio::test]
+    async fn test_query_first_covering_root() {
+        let mut client = TestClient::new(RPC);
+        let root = |height: u64| {
+            (
+                BridgePoolRootProof {
+                    signatures: Default::default(),
+                    data: (KeccakHash([1; 32]), 0.into()),
+                },
+                BlockHeight(height),
+            )
+        };
+        // the root of block 1 is signed at block 2, and the root
+        // of block 3 at block 4
+        for height in 1..=4u64 {
+            client.wl_storage.storage.block.height = BlockHeight(height);
+            if height % 2 == 0 {
+                client
+                    .wl_storage
+                    .write(&get_signed_root_key(), root(height - 1))
+                    .expect("Test failed");
+            }
+            client.wl_storage.commit_block().expect("Test failed");
+        }
+
+        let mut covering_roots = HashMap::new();
+        for (added_height, covering_root) in
+            [(1, Some(1)), (2, Some(3)), (3, Some(3)), (4, None)]
+        {
+            let root_height = query_first_covering_root(
+                &client,
+                &mut covering_roots,
+                BlockHeight(added_height),
+            )
+            .await
+            .expect("Test failed");
+            assert_eq!(root_height, covering_root.map(BlockHeight));
+        }
+        assert_eq!(covering_roots.len(), 3);
+    }
+
+    /// Test that known revert reasons of the Bridge contract
+    /// are explained.
+    #[test]
+    fn test_explain_relay_revert() {
+        let nonce = explain_relay_revert("Invalid nonce.").unwrap();
+        assert!(nonce.contains("nonce of the relayed batch"));
+        let signature =
+            explain_relay_revert("Bad signature for validator set").unwrap();
+        assert!(signature.contains("validator signatures"));
+        let proof = explain_relay_revert("Invalid transfers proof.").unwrap();
+        assert!(proof.contains("signed Bridge pool root"));
+        assert_eq!(explain_relay_revert("Out of gas"), None);
+    }
+
+    /// Test that relay bundles can be read back after being
+    /// written to disk.
+    #[test]
+    fn test_relay_bundle_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let bundle_file = dir.path().join("bundle.json");
+        let bundle = RelayBundle {
+            version: BRIDGE_POOL_PROOF_VERSION,
+            transfers: vec![KeccakHash([1; 32]), KeccakHash([2; 32])],
+            bridge_contract: EthAddress([3; 20]),
+            batch_nonce: 7.into(),
+            eth_chain_id: 1,
+            abi_encoded_args: vec![4, 5, 6],
+        };
+        bundle.store(&bundle_file).unwrap();
+        assert_eq!(RelayBundle::load(&bundle_file).unwrap(), bundle);
+
+        let contents = std::fs::read_to_string(&bundle_file).unwrap();
+        assert!(contents.contains(&KeccakHash([1; 32]).to_hex()));
+        assert!(contents.contains("\"0x040506\""));
+        assert_matches!(
+            RelayBundle::load(&dir.path().join("missing.json")),
+            Err(Error::EthereumBridge(EthereumBridgeError::RelayBundle(_)))
+        );
+    }
+
+    /// Test that the interval between confirmation polls grows
+    /// exponentially, up to its upper bound.
+    #[test]
+    fn test_confirmation_poll_policy() {
+        let policy = confirmation_poll_policy(Duration::from_secs(7));
+        let intervals: Vec<_> =
+            (1..=6).map(|poll| policy.delay(poll).as_secs()).collect();
+        assert_eq!(intervals, vec![7, 14, 28, 56, 60, 60]);
+        // larger intervals than the bound are kept
+        let interval = Duration::from_secs(120);
+        let policy = confirmation_poll_policy(interval);
+        assert_eq!(policy.delay(3), interval);
+    }
+
+    /// Test that relays are confirmed once enough blocks have been
+    /// mined on top of the block including them.
+    #[test]
+    fn test_relay_is_confirmed() {
+        // the including block is the first confirmation
+        assert!(relay_is_confirmed(100, 100, 1));
+        assert!(!relay_is_confirmed(100, 101, 3));
+        assert!(relay_is_confirmed(100, 102, 3));
+        assert!(relay_is_confirmed(100, 110, 3));
+    }
+
+    /// Test that zero relay confirmations are raised to the minimum,
+    /// while other values are kept.
+    #[test]
+    fn test_validate_relay_confirmations() {
+        let io = crate::io::StdIo;
+        assert_eq!(
+            validate_relay_confirmations(&io, 0),
+            MIN_RELAY_CONFIRMATIONS
+        );
+        assert_eq!(validate_relay_confirmations(&io, 3), 3);
+        assert_eq!(
+            validate_relay_confirmations(&io, WARN_RELAY_CONFIRMATIONS + 1),
+            WARN_RELAY_CONFIRMATIONS + 1
+        );
+    }
+
+    /// Test the comparison between the estimated and actual gas
+    /// of a relay.
+    #[test]
+    fn test_relay_gas_report() {
+        let report = RelayGasReport::new(100_000, 110_000);
+        assert_eq!(report.delta(), 10_000);
+        assert_eq!(report.delta_percentage(), Some(10.0));
+        assert_eq!(
+            report.to_string(),
+            "Estimated gas: 100000, actual gas: 110000, delta: +10000 \
+             (+10.00%)"
+        );
+
+        let report = RelayGasReport::new(100_000, 75_000);
+        assert_eq!(report.delta(), -25_000);
+        assert_eq!(report.delta_percentage(), Some(-25.0));
+
+        let report = RelayGasReport::new(0, 75_000);
+        assert_eq!(report.delta_percentage(), None);
+        assert_eq!(
+            report.to_string(),
+            "Estimated gas: 0, actual gas: 75000, delta: +75000"
+        );
+    }
+
+    /// Test that Bridge pool transfer specs are parsed from JSON
+    /// arrays, reporting malformed specs without discarding the
+    /// others.
+    #[test]
+    fn test_parse_transfer_specs() {
+        let specs = parse_transfer_specs(
+            r#"[
+                {"asset": "0x01", "recipient": "0x02", "sender": "addr",
+                 "amount": "10"},
+                {"asset": "0x01", "amount": "10"},
+                {"asset": "0x03", "recipient": "0x04", "sender": "addr",
+                 "amount": "5", "fee_amount": "1"}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(specs.len(), 3);
+
+        let spec: BridgePoolTransferSpec =
+            serde_json::from_value(specs[0].clone()).unwrap();
+        assert_eq!(spec.asset, "0x01");
+        assert_eq!(spec.amount, "10");
+        assert_eq!(spec.fee_amount, None);
+        // specs missing required fields cannot be deserialized
+        assert!(serde_json::from_value::<BridgePoolTransferSpec>(
+            specs[1].clone()
+        )
+        .is_err());
+        let spec: BridgePoolTransferSpec =
+            serde_json::from_value(specs[2].clone()).unwrap();
+        assert_eq!(spec.recipient, "0x04");
+        assert_eq!(spec.fee_amount.as_deref(), Some("1"));
+
+        // only arrays of specs are accepted
+        assert!(parse_transfer_specs("").is_err());
+        assert!(parse_transfer_specs(r#"{"asset": "0x01"}"#).is_err());
+    }
+
+    /// Test that zero amounts are validated without looking up the
+    /// denomination of their token, while amounts overflowing once
+    /// scaled to the denomination of their token are rejected.
+    #[tokio::test]
+    async fn test_validate_transfer_amounts() {
+        use crate::masp::fs::FsShieldedUtils;
+        use crate::masp::ShieldedContext;
+        use crate::wallet::fs::FsWalletUtils;
+        use crate::NamadaImpl;
+
+        let mut client = TestClient::new(RPC);
+        namada_token::write_denom(&mut client.wl_storage, &nam(), 6u8.into())
+            .expect("Test failed");
+        client.wl_storage.commit_block().expect("Test failed");
+        let dir = tempfile::tempdir().unwrap();
+        let context = NamadaImpl::native_new(
+            client,
+            FsWalletUtils::new(dir.path().to_path_buf()),
+            ShieldedContext::<FsShieldedUtils>::default(),
+            StdIo,
+            nam(),
+        );
+        let amount = |amount: Amount| {
+            args::InputAmount::Unvalidated(DenominatedAmount::new(
+                amount,
+                0u8.into(),
+            ))
+        };
+
+        // the token has no denomination in storage
+        let token = established_address_1();
+        let (amount_zero, fee_zero) = validate_transfer_amounts(
+            &context,
+            false,
+            (&token, amount(Amount::zero())),
+            (&token, amount(Amount::zero())),
+        )
+        .await
+        .expect("Test failed");
+        assert!(amount_zero.is_zero());
+        assert!(fee_zero.is_zero());
+
+        let (validated, _) = validate_transfer_amounts(
+            &context,
+            false,
+            (&nam(), amount(Amount::from(1))),
+            (&nam(), amount(Amount::zero())),
+        )
+        .await
+        .expect("Test failed");
+        assert_eq!(
+            validated,
+            DenominatedAmount::new(Amount::from(1_000_000), 6u8.into())
+        );
+
+        let result = validate_transfer_amounts(
+            &context,
+            false,
+            (&nam(), amount(Amount::max())),
+            (&nam(), amount(Amount::from(1))),
+        )
+        .await;
+        assert_matches!(
+            result,
+            Err(Error::EthereumBridge(
+                EthereumBridgeError::InvalidTransferAmount { .. }
+            ))
+        );
+        let result = validate_transfer_amounts(
+            &context,
+            false,
+            (&nam(), amount(Amount::from(1))),
+            (&nam(), amount(Amount::max())),
+        )
+        .await;
+        assert_matches!(
+            result,
+            Err(Error::EthereumBridge(
+                EthereumBridgeError::InvalidFeeAmount { .. }
+            ))
+        );
+    }
+
+    /// Test that ensuring a transfer is in the Bridge pool does not
+    /// submit it again, once an identical transfer is pending.
+    #[tokio::test]
+    async fn test_ensure_in_bridge_pool_already_pending() {
+        use crate::masp::fs::FsShieldedUtils;
+        use crate::masp::ShieldedContext;
+        use crate::wallet::fs::FsWalletUtils;
+        use crate::NamadaImpl;
+
+        let mut client = TestClient::new(RPC);
+        namada_token::write_denom(&mut client.wl_storage, &nam(), 6u8.into())
+            .expect("Test failed");
+        let transfer = transfer(0);
+        client
+            .wl_storage
+            .write(&get_pending_key(&transfer), &transfer)
+            .expect("Test failed");
+        client.wl_storage.commit_block().expect("Test failed");
+        let dir = tempfile::tempdir().unwrap();
+        let context = NamadaImpl::native_new(
+            client,
+            FsWalletUtils::new(dir.path().to_path_buf()),
+            ShieldedContext::<FsShieldedUtils>::default(),
+            StdIo,
+            nam(),
+        );
+        let args = context.new_add_erc20_transfer(
+            bertha_address(),
+            transfer.transfer.recipient,
+            transfer.transfer.asset,
+            args::InputAmount::Unvalidated(DenominatedAmount::new(
+                Amount::zero(),
+                0u8.into(),
+            )),
+        );
+
+        // submitting the same transfer twice is a no-op
+        for _ in 0..2 {
+            let outcome = ensure_in_bridge_pool(&context, args.clone())
+                .await
+                .expect("Test failed");
+            assert_matches!(
+                outcome,
+                EnsureInBridgePool::AlreadyPending(hash)
+                    if hash == transfer.keccak256()
+            );
         }
+    }
 
-        #[test]
-        fn test_max_gas() {
-            let transfers = vec![transfer(75_000); 4];
-            let hash = transfers[0].keccak256().to_string();
-            let expected = vec![hash; 2];
-            let recommendation = generate_recommendations(
-                &StdIo,
-                process_transfers(transfers),
-                &Default::default(),
-                Uint::from_u64(50_000),
-                Uint::from_u64(150_000),
-                I256(uint::MAX_SIGNED_VALUE),
-            )
-            .unwrap()
-            .expect("Test failed")
-            .transfer_hashes;
-            assert_eq!(recommendation, expected);
-        }
+    /// Test that the Ethereum bridge queries are routed to the
+    /// default path, unless it is overridden.
+    #[test]
+    fn test_eth_bridge_router() {
+        assert_eq!(
+            eth_bridge_router(None).read_ethereum_bridge_pool_path(),
+            "/shell/eth_bridge/pool/contents"
+        );
+        assert_eq!(
+            eth_bridge_router(Some("shell/eth_bridge"))
+                .read_ethereum_bridge_pool_path(),
+            eth_bridge_router(None).read_ethereum_bridge_pool_path(),
+        );
+        assert_eq!(
+            eth_bridge_router(Some("/v2/eth_bridge/"))
+                .read_signed_ethereum_bridge_pool_path(),
+            "/v2/eth_bridge/pool/signed_contents"
+        );
+    }
 
-        #[test]
-        fn test_net_loss() {
-            let mut transfers = vec![transfer(75_000); 4];
-            transfers.extend([transfer(17_500), transfer(17_500)]);
-            let expected: Vec<_> = transfers
-                .iter()
-                .map(|t| t.keccak256().to_string())
-                .take(5)
-                .collect();
-            let recommendation = generate_recommendations(
-                &StdIo,
-                process_transfers(transfers),
-                &Default::default(),
-                Uint::from_u64(150_000),
-                uint::MAX_VALUE,
-                I256::from(20_000),
-            )
-            .unwrap()
-            .expect("Test failed")
-            .transfer_hashes;
-            assert_eq!(recommendation, expected);
-        }
+    /// Test that the json payloads of Bridge pool queries are written
+    /// to files in nested directories, replacing any previous payload.
+    #[test]
+    fn test_write_query_output() {
+        let io = crate::io::StdIo;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("snapshots").join("bridge_pool.json");
 
-        #[test]
-        fn test_net_loss_max_gas() {
-            let mut transfers = vec![transfer(75_000); 4];
-            let hash = transfers[0].keccak256().to_string();
-            let expected = vec![hash; 4];
-            transfers.extend([transfer(17_500), transfer(17_500)]);
-            let recommendation = generate_recommendations(
-                &StdIo,
-                process_transfers(transfers),
-                &Default::default(),
-                Uint::from_u64(150_000),
-                Uint::from_u64(330_000),
-                I256::from(20_000),
-            )
-            .unwrap()
-            .expect("Test failed")
-            .transfer_hashes;
-            assert_eq!(recommendation, expected);
+        write_query_output(&io, Some(&path), &vec![1u64, 2, 3]).unwrap();
+        let written: Vec<u64> =
+            serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(written, vec![1, 2, 3]);
+
+        write_query_output(&io, Some(&path), &Vec::<u64>::new()).unwrap();
+        let written: Vec<u64> =
+            serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert!(written.is_empty());
+
+        // no temporary files are left behind
+        let entries = std::fs::read_dir(path.parent().unwrap()).unwrap();
+        assert_eq!(entries.count(), 1);
+    }
+
+    /// Test that the style of warning and error labels can be parsed,
+    /// and that plain labels are left unstyled.
+    #[test]
+    fn test_output_style() {
+        assert_eq!("blink".parse(), Ok(OutputStyle::Blink));
+        assert_eq!("bold".parse(), Ok(OutputStyle::Bold));
+        assert_eq!("plain".parse(), Ok(OutputStyle::Plain));
+        assert!("fancy".parse::<OutputStyle>().is_err());
+
+        let red = owo_colors::AnsiColors::Red;
+        assert_eq!(OutputStyle::Plain.label("Error", red), "Error");
+        let bold = OutputStyle::Bold.label("Error", red);
+        let blink = OutputStyle::Blink.label("Error", red);
+        assert!(bold.contains("Error") && bold != "Error");
+        assert_ne!(bold, blink);
+    }
+
+    /// Test that the Bridge pool nonce is queried from the Bridge
+    /// contract, and that relays are only attempted for the batch
+    /// the contract expects next.
+    #[tokio::test]
+    async fn test_query_and_check_bp_nonce() {
+        let io = StdIo;
+        for (batch_nonce, contract_nonce, valid) in
+            [(5u64, 5u64, true), (4, 5, false), (6, 5, false)]
+        {
+            let (eth_client, _) = MockEthereum::default()
+                .respond_call_u256(contract_nonce)
+                .connect();
+            let bridge = Bridge::new(EthAddress([3; 20]), eth_client);
+            let nonce = query_contract_bp_nonce(&bridge, None).await.unwrap();
+            assert_eq!(nonce, U256::from(contract_nonce));
+
+            let result = check_bp_nonce(&io, batch_nonce.into(), nonce);
+            if valid {
+                assert!(result.is_ok());
+            } else {
+                assert_matches!(
+                    result,
+                    Err(Error::EthereumBridge(
+                        EthereumBridgeError::InvalidBpNonce
+                    ))
+                );
+            }
         }
+    }
 
-        #[test]
-        fn test_wholly_infeasible() {
-            let transfers = vec![transfer(75_000); 4];
-            let recommendation = generate_recommendations(
-                &StdIo,
-                process_transfers(transfers),
-                &Default::default(),
-                Uint::from_u64(300_000),
-                uint::MAX_VALUE,
-                I256::from(20_000),
+    /// Test that the gas parameters and the sender of a relay
+    /// transaction are only overridden if provided by the user.
+    #[test]
+    fn test_set_relay_tx_params() {
+        let mut relay_tx = TypedTransaction::Legacy(Default::default());
+        set_relay_tx_params(&mut relay_tx, None, None, None);
+        assert_eq!(relay_tx, TypedTransaction::Legacy(Default::default()));
+
+        set_relay_tx_params(
+            &mut relay_tx,
+            Some(100_000),
+            Some(20),
+            Some(EthAddress([4; 20])),
+        );
+        assert_eq!(relay_tx.gas(), Some(&U256::from(100_000)));
+        assert_eq!(relay_tx.gas_price(), Some(U256::from(20)));
+        assert_eq!(relay_tx.from(), Some(&EthAddress([4; 20]).into()));
+    }
+
+    /// Test that relays are called off if the gas price of Ethereum
+    /// exceeds the ceiling set by the user.
+    #[tokio::test]
+    async fn test_check_gas_price_ceiling() {
+        let io = StdIo;
+        let gwei = U256::from(1_000_000_000u64);
+
+        let (eth_client, _) =
+            MockEthereum::default().respond(gwei * 30).connect();
+        assert!(check_gas_price_ceiling(&*eth_client, &io, None, 30)
+            .await
+            .is_ok());
+
+        let (eth_client, _) =
+            MockEthereum::default().respond(gwei * 31).connect();
+        assert_matches!(
+            check_gas_price_ceiling(&*eth_client, &io, None, 30).await,
+            Err(Error::EthereumBridge(
+                EthereumBridgeError::GasPriceAboveCeiling { .. }
+            ))
+        );
+    }
+
+    /// Test that interrupted relays are only deemed complete once
+    /// their transaction is confirmed successfully, and that the
+    /// state of the relay is cleared afterwards.
+    #[tokio::test]
+    async fn test_resume_interrupted_relay() {
+        let io = StdIo;
+        let dir = tempfile::tempdir().unwrap();
+        let state_file = dir.path().join("relay.json");
+        let tx_hash = H256::repeat_byte(1);
+        let transfers = vec![KeccakHash([1; 32]), KeccakHash([2; 32])];
+        let relay_state = RelayState {
+            tx_hash,
+            batch_nonce: 5.into(),
+            transfers: transfers.clone(),
+        };
+
+        // nothing to resume
+        let (eth_client, _) = MockEthereum::default().connect();
+        assert!(!resume_interrupted_relay(
+            &*eth_client,
+            &io,
+            &state_file,
+            &transfers,
+            1,
+        )
+        .await
+        .unwrap());
+
+        // the state belongs to a relay of other transfers
+        relay_state.store(&state_file).unwrap();
+        let (eth_client, _) = MockEthereum::default().connect();
+        assert_matches!(
+            resume_interrupted_relay(
+                &*eth_client,
+                &io,
+                &state_file,
+                &transfers[..1],
+                1,
             )
-            .unwrap();
-            assert!(recommendation.is_none())
+            .await,
+            Err(Error::EthereumBridge(EthereumBridgeError::RelayState(_)))
+        );
+        assert!(state_file.exists());
+
+        // the relay transaction was dropped by the Ethereum node
+        let (eth_client, _) = MockEthereum::default()
+            .respond(serde_json::Value::Null)
+            .connect();
+        assert!(!resume_interrupted_relay(
+            &*eth_client,
+            &io,
+            &state_file,
+            &transfers,
+            1,
+        )
+        .await
+        .unwrap());
+        assert!(!state_file.exists());
+
+        let sent_tx = ethers::types::Transaction {
+            hash: tx_hash,
+            block_number: Some(10.into()),
+            ..Default::default()
+        };
+        for (status, relay_confirmed) in [(1u64, true), (0, false)] {
+            relay_state.store(&state_file).unwrap();
+            let receipt = TransactionReceipt {
+                transaction_hash: tx_hash,
+                block_number: Some(10.into()),
+                status: Some(status.into()),
+                ..Default::default()
+            };
+            let (eth_client, _) = MockEthereum::default()
+                .respond(&sent_tx)
+                .respond(&sent_tx)
+                .respond(&receipt)
+                .connect();
+            assert_eq!(
+                resume_interrupted_relay(
+                    &*eth_client,
+                    &io,
+                    &state_file,
+                    &transfers,
+                    1,
+                )
+                .await
+                .unwrap(),
+                relay_confirmed
+            );
+            assert!(!state_file.exists());
         }
+    }
 
-        /// Test the profit margin obtained from relaying two
-        /// Bridge pool transfers with two distinct token types,
-        /// whose relation is 1:2 in value.
-        #[test]
-        fn test_conversion_table_profit_margin() {
-            // apfel is worth twice as much as schnitzel
-            const APF_RATE: f64 = 5e8;
-            const SCH_RATE: f64 = 1e9;
-            const APFEL: &str = "APF";
-            const SCHNITZEL: &str = "SCH";
+    /// Test that access lists are only attached to relay
+    /// transactions if they reduce the estimated gas.
+    #[tokio::test]
+    async fn test_with_access_list() {
+        use ethers::types::transaction::eip2930::{AccessList, AccessListItem};
 
-            let conversion_table = {
-                let mut t = HashMap::new();
-                t.insert(
-                    namada_core::types::address::apfel(),
-                    args::BpConversionTableEntry {
-                        alias: APFEL.into(),
-                        conversion_rate: APF_RATE,
-                    },
-                );
-                t.insert(
-                    namada_core::types::address::schnitzel(),
-                    args::BpConversionTableEntry {
-                        alias: SCHNITZEL.into(),
-                        conversion_rate: SCH_RATE,
-                    },
+        let relay_tx =
+            TypedTransaction::Legacy(ethers::types::TransactionRequest::new());
+        let access_list = AccessList(vec![AccessListItem {
+            address: EthAddress([3; 20]).into(),
+            storage_keys: vec![H256::repeat_byte(1)],
+        }]);
+        for (gas_with, gas_without, attached) in
+            [(90_000u64, 100_000u64, true), (100_000, 100_000, false)]
+        {
+            let (eth_client, _) = MockEthereum::default()
+                .respond(AccessListWithGasUsed {
+                    access_list: access_list.clone(),
+                    gas_used: gas_with.into(),
+                })
+                .respond(U256::from(gas_without))
+                .connect();
+            let tx =
+                with_access_list(&*eth_client, &StdIo, None, relay_tx.clone())
+                    .await
+                    .unwrap();
+            if attached {
+                assert_matches!(
+                    tx,
+                    TypedTransaction::Eip2930(ref tx)
+                        if tx.access_list == access_list
                 );
-                t
-            };
+            } else {
+                assert_eq!(tx, relay_tx);
+            }
+        }
+    }
 
-            let eligible = test_generate_eligible_aux(|ctx| {
-                ctx.conversion_table.clone_from(&conversion_table);
-                // tune the pending transfer provided by the ctx
-                let transfer_paid_in_apfel = {
-                    let mut pending = ctx.pending.clone();
-                    pending.transfer.amount = 1.into();
-                    pending.gas_fee.token =
-                        namada_core::types::address::apfel();
-                    pending
-                };
-                let transfer_paid_in_schnitzel = {
-                    let mut pending = ctx.pending.clone();
-                    pending.transfer.amount = 2.into();
-                    pending.gas_fee.token =
-                        namada_core::types::address::schnitzel();
-                    pending
-                };
-                // add the transfers to the pool, and expect them to
-                // be eligible transfers
-                for (pending, rate) in [
-                    (transfer_paid_in_apfel, APF_RATE),
-                    (transfer_paid_in_schnitzel, SCH_RATE),
-                ] {
-                    ctx.signed_pool.insert(
-                        pending.keccak256().to_string(),
-                        pending.clone(),
-                    );
-                    ctx.expected_eligible.push(EligibleRecommendation {
-                        transfer_hash: pending.keccak256().to_string(),
-                        cost: transfer_fee()
-                            - I256::from((1e9 / rate).floor() as u64)
-                                * I256::try_from(pending.gas_fee.amount)
-                                    .expect("Test failed"),
-                        pending_transfer: pending,
-                    });
-                }
-            });
+    /// Test that waiting for a re-broadcast relay gives up once its
+    /// deadline passes, or once it is dropped after its last allowed
+    /// re-broadcast.
+    #[tokio::test]
+    async fn test_wait_for_relay_with_rebroadcasts() {
+        let relay_tx =
+            TypedTransaction::Legacy(ethers::types::TransactionRequest::new());
+        let wait = |max_rebroadcasts, deadline| RebroadcastingWait {
+            relay_tx: &relay_tx,
+            private_relay_url: None,
+            confirmations: 1,
+            poll_interval: Duration::from_millis(1),
+            grace_period: Duration::ZERO,
+            max_rebroadcasts,
+            deadline,
+        };
 
-            const VALIDATOR_GAS_FEE: Uint = Uint::from_u64(100_000);
+        // the deadline passed before the relay was confirmed
+        let (eth_client, _) = MockEthereum::default().connect();
+        let result = wait_for_relay_with_rebroadcasts(
+            &*eth_client,
+            &StdIo,
+            None,
+            H256::repeat_byte(1),
+            wait(DEFAULT_MAX_REBROADCASTS, Some(Instant::now())),
+        )
+        .await;
+        assert_matches!(result, Ok(None));
 
-            let recommended_batch = generate_recommendations(
-                &StdIo,
-                eligible,
-                &conversion_table,
-                // gas spent by validator signature checks
-                VALIDATOR_GAS_FEE,
-                // unlimited amount of gas
-                uint::MAX_VALUE,
-                // only profitable
-                I256::zero(),
+        // the relay was dropped, and may not be re-broadcast
+        let (eth_client, _) = MockEthereum::default()
+            .respond(serde_json::Value::Null)
+            .respond(serde_json::Value::Null)
+            .connect();
+        let result = wait_for_relay_with_rebroadcasts(
+            &*eth_client,
+            &StdIo,
+            None,
+            H256::repeat_byte(1),
+            wait(0, None),
+        )
+        .await;
+        assert_matches!(
+            result,
+            Err(Error::EthereumBridge(EthereumBridgeError::RelayDropped(_)))
+        );
+    }
+
+    /// Test that only the errors which re-sending a relay transaction
+    /// cannot fix are deemed permanent.
+    #[test]
+    fn test_is_permanent_send_error() {
+        let rpc_err = |err: &str| {
+            Error::EthereumBridge(EthereumBridgeError::Rpc(err.into()))
+        };
+        assert!(is_permanent_send_error(&rpc_err("nonce too low")));
+        assert!(is_permanent_send_error(&rpc_err(
+            "Insufficient funds for gas * price + value"
+        )));
+        assert!(!is_permanent_send_error(&rpc_err(
+            "replacement transaction underpriced"
+        )));
+        assert!(!is_permanent_send_error(&rpc_err("connection reset")));
+    }
+
+    /// Test that the calldata of a relay is reported along with its
+    /// destination and function selector.
+    #[test]
+    fn test_relay_calldata() {
+        let bridge = EthAddress([5; 20]);
+        let relay_tx = TypedTransaction::Legacy(
+            ethers::types::TransactionRequest::new()
+                .to(ethers::types::Address::from(bridge))
+                .data(vec![0xde, 0xad, 0xbe, 0xef, 0x01, 0x02]),
+        );
+        assert_eq!(
+            RelayCalldata::new(&relay_tx).to_string(),
+            format!(
+                "To: 0x{}\nFunction selector: 0xdeadbeef\nCalldata: \
+                 0xdeadbeef0102",
+                "05".repeat(20)
             )
-            .unwrap()
-            .expect("Test failed");
+        );
+    }
 
+    /// Test that the consistency of the Bridge contract with Namada
+    /// agrees with the nonces accepted by [`check_bp_nonce`].
+    #[test]
+    fn test_bridge_consistency() {
+        assert_eq!(
+            bridge_consistency(None, 0.into()),
+            BridgeConsistency::NoSignedRoot
+        );
+        assert_eq!(
+            bridge_consistency(Some(5.into()), 5.into()),
+            BridgeConsistency::RelayPossible
+        );
+        assert!(check_bp_nonce(&StdIo, 5.into(), 5.into()).is_ok());
+        assert_eq!(
+            bridge_consistency(Some(5.into()), 6.into()),
+            BridgeConsistency::AwaitingObservation
+        );
+        assert!(check_bp_nonce(&StdIo, 5.into(), 6.into()).is_err());
+        for contract_nonce in [0u64, 4, 7] {
             assert_eq!(
-                recommended_batch.net_profit,
-                I256::from(1_000_000_000_u64) + I256::from(2_000_000_000_u64)
-                    - I256(VALIDATOR_GAS_FEE)
-                    - transfer_fee() * I256::from(2_u64)
+                bridge_consistency(Some(5.into()), contract_nonce.into()),
+                BridgeConsistency::Anomaly
             );
         }
     }
-}
 
-pub use recommendations::recommend_batch;
+    /// Test that diverging gas estimates of a relay are detected.
+    #[test]
+    fn test_check_gas_estimate() {
+        let estimated = U256::from(100_000);
+        assert!(!check_gas_estimate(
+            &StdIo,
+            Uint::from_u64(100_000),
+            estimated
+        ));
+        assert!(!check_gas_estimate(
+            &StdIo,
+            Uint::from_u64(125_000),
+            estimated
+        ));
+        assert!(!check_gas_estimate(
+            &StdIo,
+            Uint::from_u64(75_000),
+            estimated
+        ));
+        assert!(check_gas_estimate(
+            &StdIo,
+            Uint::from_u64(126_000),
+            estimated
+        ));
+        assert!(check_gas_estimate(
+            &StdIo,
+            Uint::from_u64(50_000),
+            estimated
+        ));
+        assert!(!check_gas_estimate(
+            &StdIo,
+            Uint::from_u64(50_000),
+            U256::zero()
+        ));
+    }
+
+    /// Test that the failure to decode a malformed proof reports
+    /// the details of its request.
+    #[test]
+    fn test_decode_malformed_proof() {
+        let relayer = established_address_1();
+        let request = ProofRequest {
+            transfers: 2,
+            relayer: Some(&relayer),
+        };
+        let result = decode_relay_args(
+            &StdIo,
+            BRIDGE_POOL_PROOF_VERSION,
+            &[0xde, 0xad],
+            request,
+        );
+        let expected = format!(
+            "Unable to decode the generated proof of 2 bytes, requested for \
+             2 transfer(s) by relayer {relayer}: "
+        );
+        assert_matches!(
+            result,
+            Err(Error::Encode(EncodingError::Decoding(msg)))
+                if msg.starts_with(&expected)
+        );
+    }
+
+    /// Test that progress is only reported to listening frontends,
+    /// without waiting on them.
+    #[tokio::test]
+    async fn test_report_progress() {
+        report_progress(None, ProgressEvent::FetchingPool);
+
+        let (sender, mut receiver) = tokio::sync::mpsc::channel(2);
+        report_progress(Some(&sender), ProgressEvent::FetchingPool);
+        report_progress(
+            Some(&sender),
+            ProgressEvent::ProofGenerated { bytes: 32 },
+        );
+        // events reported to a full channel are dropped
+        report_progress(Some(&sender), ProgressEvent::Relaying);
+        assert_eq!(receiver.recv().await, Some(ProgressEvent::FetchingPool));
+        assert_eq!(
+            receiver.recv().await,
+            Some(ProgressEvent::ProofGenerated { bytes: 32 })
+        );
+        assert!(receiver.try_recv().is_err());
+
+        // reporting progress to a closed channel is not an error
+        drop(receiver);
+        report_progress(Some(&sender), ProgressEvent::Relaying);
+    }
+
+    /// Test that duplicate transfer hashes are removed, preserving
+    /// the order of the remaining hashes.
+    #[test]
+    fn test_dedup_transfers() {
+        let hash = |byte: u8| KeccakHash([byte; 32]);
+        let mut transfers = vec![hash(2), hash(1), hash(2), hash(3), hash(1)];
+        dedup_transfers(&StdIo, &mut transfers);
+        assert_eq!(transfers, vec![hash(2), hash(1), hash(3)]);
+
+        let mut transfers = vec![hash(1)];
+        dedup_transfers(&StdIo, &mut transfers);
+        assert_eq!(transfers, vec![hash(1)]);
+    }
+
+    /// Test that the economics of each transfer in a proof are
+    /// broken down.
+    #[test]
+    fn test_transfer_economics() {
+        let transfer = transfer(1_500_000);
+        let denom = Denomination(6);
+        let economics = TransferEconomics::new(&transfer, denom, None).unwrap();
+        assert_eq!(
+            economics,
+            TransferEconomics {
+                hash: transfer.keccak256().to_hex(),
+                marginal_gas: estimated_relay_gas(2, Uint::zero())
+                    - estimated_relay_gas(1, Uint::zero()),
+                fee_token: nam(),
+                fee: DenominatedAmount::new(1_500_000.into(), denom),
+                fee_gwei: None,
+            }
+        );
+
+        let conversion_table = HashMap::from([(
+            nam(),
+            args::BpConversionTableEntry {
+                alias: "NAM".into(),
+                conversion_rate: 5e8,
+                as_of: None,
+                fee_weight: None,
+            },
+        )]);
+        let economics =
+            TransferEconomics::new(&transfer, denom, Some(&conversion_table))
+                .unwrap();
+        assert_eq!(economics.fee_gwei, Some(Uint::from_u64(3_000_000)));
+    }
+
+    /// Test that the transfer added to the Bridge pool is decoded
+    /// from a Bridge pool transaction.
+    #[test]
+    fn test_bridge_pool_tx_transfer() {
+        let transfer = transfer(1);
+        let mut tx = Tx::new(ChainId::default(), None);
+        assert_matches!(bridge_pool_tx_transfer(&tx), Err(Error::Other(_)));
+        tx.add_data(transfer.clone());
+        assert_eq!(
+            bridge_pool_tx_transfer(&tx).unwrap().keccak256(),
+            transfer.keccak256()
+        );
+    }
+
+    /// Test that aborting an operation interrupts it, and that it
+    /// otherwise runs to completion.
+    #[tokio::test]
+    async fn test_abortable() {
+        let result = abortable(&StdIo, future::ready(Ok(1)), None).await;
+        assert_matches!(result, Ok(1));
+
+        let (handle, registration) = future::AbortHandle::new_pair();
+        let result =
+            abortable(&StdIo, future::ready(Ok(1)), Some(registration)).await;
+        assert_matches!(result, Ok(1));
+        assert!(!handle.is_aborted());
+
+        let (handle, registration) = future::AbortHandle::new_pair();
+        handle.abort();
+        let result = abortable(
+            &StdIo,
+            future::pending::<Result<(), Error>>(),
+            Some(registration),
+        )
+        .await;
+        assert_matches!(
+            result,
+            Err(Error::EthereumBridge(EthereumBridgeError::RelayAborted))
+        );
+    }
+}
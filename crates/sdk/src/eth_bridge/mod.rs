@@ -1,6 +1,8 @@
 //! Ethereum bridge utilities shared between `wasm` and the `cli`.
 
 pub mod bridge_pool;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod validator_set;
 
 use std::ops::ControlFlow;
@@ -0,0 +1,54 @@
+//! In-memory Ethereum middleware, to unit test code that issues
+//! requests to an Ethereum node.
+
+use std::sync::Arc;
+
+use ethers::abi::{self, Token};
+use ethers::providers::{MockProvider, Provider};
+use ethers::types::{Bytes, U256};
+use serde::Serialize;
+
+use crate::control_flow::time::Duration;
+
+/// The interval between polls of the [`MockEthereum`] node, e.g. when
+/// waiting for the confirmations of a transaction.
+const MOCK_POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// An in-memory Ethereum node, which answers the JSON-RPC requests
+/// sent to it with a pre-defined list of responses.
+#[derive(Debug, Clone, Default)]
+pub struct MockEthereum {
+    responses: Vec<serde_json::Value>,
+}
+
+impl MockEthereum {
+    /// Queue the response to the next request sent to the node.
+    pub fn respond<T: Serialize>(mut self, response: T) -> Self {
+        self.responses.push(
+            serde_json::to_value(response)
+                .expect("Test failed: Mock response should be serializable"),
+        );
+        self
+    }
+
+    /// Queue the response to the next `eth_call` request sent to
+    /// the node, with a contract method returning a single `U256`.
+    pub fn respond_call_u256(self, value: impl Into<U256>) -> Self {
+        self.respond(Bytes::from(abi::encode(&[Token::Uint(value.into())])))
+    }
+
+    /// Build an Ethereum client connected to this node, along with
+    /// a handle to assert on the requests the node receives.
+    pub fn connect(self) -> (Arc<Provider<MockProvider>>, MockProvider) {
+        let mock = MockProvider::new();
+        // NB: the mock provider answers requests with the most
+        // recently pushed response first
+        for response in self.responses.into_iter().rev() {
+            mock.push::<serde_json::Value, _>(response)
+                .expect("Test failed: Mock response should be queued");
+        }
+        let provider =
+            Provider::new(mock.clone()).interval(MOCK_POLL_INTERVAL);
+        (Arc::new(provider), mock)
+    }
+}
@@ -19,6 +19,7 @@ use namada_vote_ext::validator_set_update::{
     ValidatorSetArgs, VotingPowersMap,
 };
 
+use super::bridge_pool::{set_relay_tx_params, validate_relay_confirmations};
 use super::{block_on_eth_sync, eth_sync_or, eth_sync_or_exit, BlockOnEthSync};
 use crate::control_flow::install_shutdown_signal;
 use crate::control_flow::time::{self, Duration, Instant};
@@ -393,12 +394,13 @@ pub async fn relay_validator_set_update<'a, E>(
     eth_client: Arc<E>,
     client: &(impl Client + Sync),
     io: &impl Io,
-    args: args::ValidatorSetUpdateRelay,
+    mut args: args::ValidatorSetUpdateRelay,
 ) -> Result<(), SdkError>
 where
     E: Middleware,
     E::Error: std::fmt::Debug + std::fmt::Display,
 {
+    args.confirmations = validate_relay_confirmations(io, args.confirmations);
     let mut signal_receiver = args.safe_mode.then(install_shutdown_signal);
 
     if args.sync {
@@ -737,15 +739,12 @@ where
         gov_hash,
         signatures,
     );
-    if let Some(gas) = args.gas {
-        relay_op.tx.set_gas(gas);
-    }
-    if let Some(gas_price) = args.gas_price {
-        relay_op.tx.set_gas_price(gas_price);
-    }
-    if let Some(eth_addr) = args.eth_addr {
-        relay_op.tx.set_from(eth_addr.into());
-    }
+    set_relay_tx_params(
+        &mut relay_op.tx,
+        args.gas,
+        args.gas_price,
+        args.eth_addr,
+    );
 
     let pending_tx = relay_op.send().await.map_err(|e| {
         Error::critical(EthereumBridgeError::ContractCall(e.to_string()))
@@ -58,6 +58,26 @@ pub trait Io {
         }
     }
 
+    /// Read a single line from input, including its trailing newline.
+    /// An empty string is returned once the input has been exhausted.
+    async fn read_line(&self) -> std::io::Result<String> {
+        #[cfg(not(target_family = "wasm"))]
+        {
+            use std::io::BufRead;
+
+            // NB: stdin is read through its process-wide buffer, such
+            // that no input past the line being read is lost between
+            // calls
+            let mut s = String::new();
+            std::io::stdin().lock().read_line(&mut s)?;
+            Ok(s)
+        }
+        #[cfg(target_family = "wasm")]
+        {
+            unreachable!("Wasm should not perform general IO")
+        }
+    }
+
     /// Display the given prompt and return the string input
     async fn prompt(
         &self,
@@ -125,6 +145,10 @@ impl Io for NullIo {
         panic!("Unsupported operation")
     }
 
+    async fn read_line(&self) -> std::io::Result<String> {
+        panic!("Unsupported operation")
+    }
+
     async fn prompt(
         &self,
         _question: impl AsRef<str> + MaybeSend + MaybeSync,
@@ -155,6 +179,32 @@ where
     s
 }
 
+/// Display a yes/no question, and read the answer of the user from
+/// the input of `io`, asking again until either `y` or `n` is given.
+///
+/// The output is flushed before each read, such that the question is
+/// displayed even if it does not end with a newline. If the input is
+/// closed before the user answers, the question is deemed declined.
+pub async fn prompt_yes_no<IO: Io>(
+    io: &IO,
+    question: &str,
+) -> std::io::Result<bool> {
+    io.print(format!("{question} (y/n): "));
+    loop {
+        io.flush();
+        let answer = io.read_line().await?;
+        if answer.is_empty() {
+            // the input was closed before the user answered
+            return Ok(false);
+        }
+        match answer.trim() {
+            "y" => return Ok(true),
+            "n" => return Ok(false),
+            _ => io.print("Expected 'y' or 'n'. Please try again: "),
+        }
+    }
+}
+
 /// A generic function for reading input from users
 #[cfg(not(target_family = "wasm"))]
 pub async fn read_aux<R>(mut reader: R) -> tokio::io::Result<String>
@@ -166,6 +216,35 @@ where
     Ok(s)
 }
 
+/// A generic function for reading a single line of input from users.
+///
+/// The input is consumed one byte at a time, such that nothing past
+/// the trailing newline is taken out of `reader`.
+#[cfg(not(target_family = "wasm"))]
+pub async fn read_line_aux<R>(mut reader: R) -> tokio::io::Result<String>
+where
+    R: tokio::io::AsyncReadExt + Unpin,
+{
+    let mut line = vec![];
+    loop {
+        match reader.read_u8().await {
+            Ok(byte) => {
+                line.push(byte);
+                if byte == b'\n' {
+                    break;
+                }
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => {
+                break;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    String::from_utf8(line).map_err(|err| {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+    })
+}
+
 /// Convenience macro for formatting arguments to
 /// [`Io::print`]
 #[macro_export]
@@ -39,6 +39,7 @@ use args::{InputAmount, SdkTypes};
 use namada_core::ibc::core::host::types::identifiers::{ChannelId, PortId};
 use namada_core::types::address::Address;
 use namada_core::types::dec::Dec;
+use namada_core::types::eth_bridge_pool::TransferToEthereumKind;
 use namada_core::types::ethereum_events::EthAddress;
 use namada_core::types::key::*;
 use namada_core::types::masp::{TransferSource, TransferTarget};
@@ -485,6 +486,7 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
             recipient,
             asset,
             amount,
+            erc20_decimals: None,
             fee_amount: InputAmount::Unvalidated(
                 token::DenominatedAmount::new(
                     token::Amount::default(),
@@ -493,8 +495,9 @@ pub trait Namada: Sized + MaybeSync + MaybeSend {
             ),
             fee_payer: None,
             fee_token: self.native_token(),
-            nut: false,
+            kind: TransferToEthereumKind::Erc20,
             code_path: PathBuf::from(TX_BRIDGE_POOL_WASM),
+            describe: false,
             tx: self.tx_builder(),
         }
     }
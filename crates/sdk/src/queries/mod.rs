@@ -13,8 +13,11 @@ pub use types::{
 use vp::{Vp, VP};
 
 pub use self::shell::eth_bridge::{
-    Erc20FlowControl, GenBridgePoolProofReq, GenBridgePoolProofRsp,
-    TransferToErcArgs, TransferToEthereumStatus,
+    BridgePoolTransferHeights, Erc20FlowControl, EthBridge,
+    GenBridgePoolProofReq, GenBridgePoolProofRsp,
+    GenVersionedBridgePoolProofRsp, TransferToErcArgs,
+    TransferToEthereumProgressPage, TransferToEthereumStatus,
+    BRIDGE_POOL_PROOF_VERSION,
 };
 use crate::MaybeSend;
 
@@ -95,7 +98,7 @@ pub fn require_no_data(request: &RequestQuery) -> namada_storage::Result<()> {
 
 /// Queries testing helpers
 #[cfg(any(test, feature = "testing"))]
-mod testing {
+pub(crate) mod testing {
 
     use namada_core::types::storage::BlockHeight;
     use namada_state::testing::TestWlStorage;
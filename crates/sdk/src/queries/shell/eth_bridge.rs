@@ -2,6 +2,7 @@
 
 use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
+use std::ops::ControlFlow;
 use std::str::FromStr;
 
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -21,16 +22,21 @@ use namada_core::types::storage::{BlockHeight, DbKeySeg, Epoch, Key};
 use namada_core::types::token::Amount;
 use namada_core::types::voting_power::FractionalVotingPower;
 use namada_ethereum_bridge::protocol::transactions::votes::{
-    EpochedVotingPower, EpochedVotingPowerExt,
+    EpochedVotingPower, EpochedVotingPowerExt, Votes,
+};
+use namada_ethereum_bridge::storage::bridge_pool::{
+    get_key_from_hash, get_signed_root_key,
 };
-use namada_ethereum_bridge::storage::bridge_pool::get_key_from_hash;
 use namada_ethereum_bridge::storage::eth_bridge_queries::EthBridgeQueries;
 use namada_ethereum_bridge::storage::parameters::UpgradeableContract;
-use namada_ethereum_bridge::storage::proof::{sort_sigs, EthereumProof};
+use namada_ethereum_bridge::storage::proof::{
+    sort_sigs, BridgePoolRootProof, EthereumProof,
+};
 use namada_ethereum_bridge::storage::vote_tallies::{eth_msgs_prefix, Keys};
 use namada_ethereum_bridge::storage::{
     bridge_contract_key, native_erc20_key, vote_tallies,
 };
+use namada_parameters::read_epoch_duration_parameter;
 use namada_proof_of_stake::pos_queries::PosQueries;
 use namada_state::MembershipProof::BridgePool;
 use namada_state::{DBIter, StorageHasher, StoreRef, StoreType, DB};
@@ -78,6 +84,48 @@ pub struct TransferToEthereumStatus {
     pub unrecognized: HashSet<KeccakHash>,
 }
 
+/// The block heights at which the transfers in the Bridge pool
+/// were added to it.
+#[derive(
+    Default,
+    Debug,
+    Clone,
+    Eq,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+pub struct BridgePoolTransferHeights {
+    /// The block height at which the query was performed.
+    pub queried_height: BlockHeight,
+    /// The number of blocks a transfer may remain in the Bridge
+    /// pool, before it expires and gets refunded.
+    pub expiry_offset: u64,
+    /// The block height at which each pending transfer was
+    /// added to the Bridge pool.
+    pub heights: HashMap<KeccakHash, BlockHeight>,
+}
+
+/// A page of the transfers to Ethereum whose events have not
+/// been seen yet, along with the voting power backing them.
+#[derive(
+    Default, Debug, Clone, Eq, PartialEq, BorshSerialize, BorshDeserialize,
+)]
+pub struct TransferToEthereumProgressPage {
+    /// Transfers in this page, and the voting power backing
+    /// their events.
+    pub progress: Vec<(PendingTransfer, FractionalVotingPower)>,
+    /// The index of the next page of transfers, if any are left.
+    pub next_page: Option<u64>,
+    /// The block height at which the page was read.
+    ///
+    /// Pages read at different heights may skip or repeat
+    /// transfers, so they must not be combined.
+    pub height: BlockHeight,
+}
+
 /// Contains information about the flow control of some ERC20
 /// wrapped asset.
 #[derive(
@@ -119,6 +167,13 @@ pub type TransferToErcArgs = (
     ethereum_structs::RelayProof,
 );
 
+/// Version of the encoding of the Bridge pool proofs returned
+/// by `generate_versioned_bridge_pool_proof`.
+///
+/// This version must be bumped whenever the ABI encoding of the
+/// arguments to `transfer_to_erc` changes.
+pub const BRIDGE_POOL_PROOF_VERSION: u8 = 1;
+
 /// Response data returned by `generate_bridge_pool_proof`.
 #[derive(Debug, Clone, Eq, PartialEq, BorshSerialize, BorshDeserialize)]
 pub struct GenBridgePoolProofRsp {
@@ -142,6 +197,16 @@ impl GenBridgePoolProofRsp {
     }
 }
 
+/// Response data returned by `generate_versioned_bridge_pool_proof`
+/// and `generate_bridge_pool_proof_at_root`.
+#[derive(Debug, Clone, Eq, PartialEq, BorshSerialize, BorshDeserialize)]
+pub struct GenVersionedBridgePoolProofRsp {
+    /// Version of the encoding of the proof.
+    pub version: u8,
+    /// The generated proof.
+    pub proof: GenBridgePoolProofRsp,
+}
+
 router! {ETH_BRIDGE,
     // Get the current contents of the Ethereum bridge pool
     ( "pool" / "contents" )
@@ -152,17 +217,55 @@ router! {ETH_BRIDGE,
     ( "pool" / "signed_contents" )
         -> Vec<PendingTransfer> = read_signed_ethereum_bridge_pool,
 
+    // Get the block heights at which the transfers in the
+    // Ethereum bridge pool were added to it
+    ( "pool" / "transfer_heights" )
+        -> BridgePoolTransferHeights = read_bridge_pool_transfer_heights,
+
     // Generate a merkle proof for the inclusion of requested
     // transfers in the Ethereum bridge pool
     ( "pool" / "proof" )
         -> GenBridgePoolProofRsp = (with_options generate_bridge_pool_proof),
 
+    // Generate a merkle proof for the inclusion of requested
+    // transfers in the Ethereum bridge pool, tagged with the
+    // version of its encoding
+    ( "pool" / "versioned_proof" )
+        -> GenVersionedBridgePoolProofRsp
+        = (with_options generate_versioned_bridge_pool_proof),
+
+    // Generate a merkle proof for the inclusion of requested
+    // transfers in the Ethereum bridge pool, against the root
+    // signed as of the given block height, tagged with the
+    // version of its encoding
+    ( "pool" / "proof_at_root" / [root_height: BlockHeight] )
+        -> GenVersionedBridgePoolProofRsp
+        = (with_options generate_bridge_pool_proof_at_root),
+
     // Iterates over all ethereum events and returns the amount of
     // voting power backing each `TransferToEthereum` event.
     ( "pool" / "transfer_to_eth_progress" )
         -> HashMap<PendingTransfer, FractionalVotingPower>
         = transfer_to_ethereum_progress,
 
+    // Iterates over all ethereum events and returns a page of
+    // `page_size` transfers, along with the amount of voting power
+    // backing each `TransferToEthereum` event.
+    ( "pool" / "transfer_to_eth_progress_page" / [page: u64] / [page_size: u64] )
+        -> TransferToEthereumProgressPage = transfer_to_ethereum_progress_page,
+
+    // Iterates over all ethereum events and returns the block height
+    // at which each `TransferToEthereum` event was first voted on.
+    ( "pool" / "transfer_to_eth_seen_heights" )
+        -> HashMap<KeccakHash, BlockHeight>
+        = transfer_to_ethereum_seen_heights,
+
+    // Iterates over the ethereum events until finding the
+    // `TransferToEthereum` event of the transfer with the given
+    // hash, and returns the amount of voting power backing it.
+    ( "pool" / "transfer_to_eth_backing" / [hash: KeccakHash] )
+        -> Option<FractionalVotingPower> = transfer_to_ethereum_backing,
+
     // Given a list of keccak hashes, check whether they have been
     // relayed, expired or if they are still pending.
     ( "pool" / "transfer_status" )
@@ -416,6 +519,41 @@ where
     ))
 }
 
+/// Read the block heights at which the transfers in the
+/// Ethereum bridge pool were added to it.
+fn read_bridge_pool_transfer_heights<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> namada_storage::Result<BridgePoolTransferHeights>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let queried_height = ctx.wl_storage.storage.get_last_block_height();
+    // NB: transfers time out after `min_num_of_blocks` of an epoch
+    let expiry_offset =
+        read_epoch_duration_parameter(ctx.wl_storage)?.min_num_of_blocks;
+
+    let merkle_tree = ctx
+        .wl_storage
+        .storage
+        .get_merkle_tree(queried_height, Some(StoreType::BridgePool))
+        .expect("We should always be able to read the database");
+    let stores = merkle_tree.stores();
+    let store = match stores.store(&StoreType::BridgePool) {
+        StoreRef::BridgePool(store) => store,
+        _ => unreachable!(),
+    };
+
+    Ok(BridgePoolTransferHeights {
+        queried_height,
+        expiry_offset,
+        heights: store
+            .iter()
+            .map(|(hash, height)| (hash.clone(), *height))
+            .collect(),
+    })
+}
+
 /// Read the contents of the Ethereum bridge
 /// pool covered by the latest signed root.
 fn read_signed_ethereum_bridge_pool<D, H, V, T>(
@@ -481,6 +619,59 @@ fn generate_bridge_pool_proof<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     request: &RequestQuery,
 ) -> namada_storage::Result<EncodedResponseQuery>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    generate_bridge_pool_proof_aux(ctx, request, None, false)
+}
+
+/// Generate a merkle proof for the inclusion of the requested
+/// transfers in the Ethereum bridge pool, tagged with the
+/// version of its encoding.
+fn generate_versioned_bridge_pool_proof<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    request: &RequestQuery,
+) -> namada_storage::Result<EncodedResponseQuery>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    generate_bridge_pool_proof_aux(ctx, request, None, true)
+}
+
+/// Generate a merkle proof for the inclusion of the requested
+/// transfers in the Ethereum bridge pool, against the root
+/// signed as of `root_height`, tagged with the version of its
+/// encoding.
+///
+/// Bridge pool roots are signed anew under the same nonce until
+/// they are relayed, thus the targeted root must carry the latest
+/// Bridge pool nonce.
+fn generate_bridge_pool_proof_at_root<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    request: &RequestQuery,
+    root_height: BlockHeight,
+) -> namada_storage::Result<EncodedResponseQuery>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    generate_bridge_pool_proof_aux(ctx, request, Some(root_height), true)
+}
+
+/// Generate a merkle proof for the inclusion of the requested
+/// transfers in the Ethereum bridge pool, against the root signed
+/// as of `root_height`, or the latest signed root if unset.
+///
+/// If `versioned` is set, the proof is returned as a
+/// [`GenVersionedBridgePoolProofRsp`].
+fn generate_bridge_pool_proof_aux<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    request: &RequestQuery,
+    root_height: Option<BlockHeight>,
+    versioned: bool,
+) -> namada_storage::Result<EncodedResponseQuery>
 where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
@@ -491,19 +682,39 @@ where
         with_appendix,
     }) = BorshDeserialize::try_from_slice(&request.data)
     {
-        // get the latest signed merkle root of the Ethereum bridge pool
-        let (signed_root, height) = ctx
-            .wl_storage
-            .ethbridge_queries()
-            .get_signed_bridge_pool_root()
-            .ok_or(namada_storage::Error::SimpleMessage(
-                "No signed root for the Ethereum bridge pool exists in \
-                 storage.",
-            ))
-            .into_storage_result()?;
+        let (signed_root, height) = match root_height {
+            // get the merkle root of the Ethereum bridge pool signed
+            // as of the target height
+            Some(root_height) => {
+                let (bytes, _) = ctx
+                    .wl_storage
+                    .storage
+                    .read_with_height(&get_signed_root_key(), root_height)
+                    .into_storage_result()?;
+                let bytes = bytes
+                    .ok_or(namada_storage::Error::SimpleMessage(
+                        "No signed root for the Ethereum bridge pool exists \
+                         at the target height.",
+                    ))
+                    .into_storage_result()?;
+                <(BridgePoolRootProof, BlockHeight)>::try_from_slice(&bytes)
+                    .into_storage_result()?
+            }
+            // get the latest signed merkle root of the Ethereum bridge pool
+            None => ctx
+                .wl_storage
+                .ethbridge_queries()
+                .get_signed_bridge_pool_root()
+                .ok_or(namada_storage::Error::SimpleMessage(
+                    "No signed root for the Ethereum bridge pool exists in \
+                     storage.",
+                ))
+                .into_storage_result()?,
+        };
 
         // make sure a relay attempt won't happen before the new signed
-        // root has had time to be generated
+        // root has had time to be generated, nor against a root whose
+        // nonce has been relayed already
         let latest_bp_nonce =
             ctx.wl_storage.ethbridge_queries().get_bridge_pool_nonce();
         if latest_bp_nonce != signed_root.data.1 {
@@ -516,6 +727,18 @@ where
                 .into(),
             )));
         }
+        // the validator set that signed a targeted root may
+        // have since been superseded
+        let validator_set_epoch = root_height
+            .map(|_| {
+                ctx.wl_storage.pos_queries().get_epoch(height).ok_or(
+                    namada_storage::Error::SimpleMessage(
+                        "The epoch of the targeted Bridge pool root does not \
+                         exist",
+                    ),
+                )
+            })
+            .transpose()?;
 
         // get the merkle tree corresponding to the above root.
         let tree = ctx
@@ -529,9 +752,21 @@ where
             .iter()
             .filter_map(|hash| {
                 let key = get_key_from_hash(hash);
-                match ctx.wl_storage.read_bytes(&key) {
-                    Ok(Some(bytes)) => Some((key, bytes)),
-                    _ => {
+                // NB: the transfers of a targeted root may have
+                // expired since, so they are read as of the height
+                // of the root
+                let bytes = match root_height {
+                    Some(_) => ctx
+                        .wl_storage
+                        .storage
+                        .read_with_height(&key, height)
+                        .ok()
+                        .and_then(|(bytes, _)| bytes),
+                    None => ctx.wl_storage.read_bytes(&key).ok().flatten(),
+                };
+                match bytes {
+                    Some(bytes) => Some((key, bytes)),
+                    None => {
                         missing_hashes.push(hash);
                         None
                     }
@@ -570,7 +805,7 @@ where
                 let (validator_args, voting_powers) = ctx
                     .wl_storage
                     .ethbridge_queries()
-                    .get_bridge_validator_set(None);
+                    .get_bridge_validator_set(validator_set_epoch);
                 let relay_proof = ethereum_structs::RelayProof {
                     transfers,
                     pool_root: signed_root.data.0.0,
@@ -591,7 +826,15 @@ where
                     )),
                     appendices: with_appendix.then_some(appendices),
                 };
-                let data = rsp.serialize_to_vec();
+                let data = if versioned {
+                    GenVersionedBridgePoolProofRsp {
+                        version: BRIDGE_POOL_PROOF_VERSION,
+                        proof: rsp,
+                    }
+                    .serialize_to_vec()
+                } else {
+                    rsp.serialize_to_vec()
+                };
                 Ok(EncodedResponseQuery {
                     data,
                     ..Default::default()
@@ -618,6 +861,151 @@ where
     H: 'static + StorageHasher + Sync,
 {
     let mut pending_events = HashMap::new();
+    for_each_unseen_transfers_event(&ctx, |hashes, voting_power_key| {
+        let voting_power = read_event_voting_power(&ctx, voting_power_key)?;
+        for hash in hashes {
+            let transfer = read_pending_transfer(&ctx, &hash)?;
+            pending_events.insert(transfer, voting_power);
+        }
+        Ok(ControlFlow::Continue(()))
+    })?;
+    Ok(pending_events)
+}
+
+/// Iterates over all ethereum events and returns a page of
+/// `page_size` transfers, along with the amount of voting
+/// power backing each `TransferToEthereum` event.
+///
+/// Only the transfers within the requested page are read from
+/// storage, and the iteration stops once the page is filled.
+fn transfer_to_ethereum_progress_page<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    page: u64,
+    page_size: u64,
+) -> namada_storage::Result<TransferToEthereumProgressPage>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    if page_size == 0 {
+        return Err(namada_storage::Error::SimpleMessage(
+            "The page size of a transfer to Ethereum progress query must be \
+             greater than zero",
+        ));
+    }
+    let start = page
+        .checked_mul(page_size)
+        .and_then(|start| usize::try_from(start).ok())
+        .ok_or(namada_storage::Error::SimpleMessage(
+            "The requested page of transfers to Ethereum is out of bounds",
+        ))?;
+    let page_size = usize::try_from(page_size).unwrap_or(usize::MAX);
+    let end = start.saturating_add(page_size);
+
+    let mut progress = vec![];
+    let mut visited = 0usize;
+    for_each_unseen_transfers_event(&ctx, |hashes, voting_power_key| {
+        let first = visited;
+        visited += hashes.len();
+        if visited <= start {
+            return Ok(ControlFlow::Continue(()));
+        }
+        let voting_power = read_event_voting_power(&ctx, voting_power_key)?;
+        for (index, hash) in (first..).zip(hashes) {
+            if (start..end).contains(&index) {
+                let transfer = read_pending_transfer(&ctx, &hash)?;
+                progress.push((transfer, voting_power));
+            }
+        }
+        // one transfer past the page tells us there is a next one
+        Ok(if visited > end {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        })
+    })?;
+    let next_page = (visited > end).then_some(page + 1);
+
+    Ok(TransferToEthereumProgressPage {
+        progress,
+        next_page,
+        height: ctx.wl_storage.storage.get_last_block_height(),
+    })
+}
+
+/// Iterates over all ethereum events and returns the block height
+/// at which each `TransferToEthereum` event was first seen by a
+/// validator, keyed by the hashes of its transfers.
+///
+/// Transfers whose event has not been voted on yet are omitted.
+fn transfer_to_ethereum_seen_heights<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+) -> namada_storage::Result<HashMap<KeccakHash, BlockHeight>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let mut seen_heights = HashMap::new();
+    for_each_unseen_transfers_event(&ctx, |hashes, voting_power_key| {
+        let mut seen_by_key = voting_power_key.clone();
+        // the voting power key is not empty, so this write is fine
+        *seen_by_key.segments.last_mut().unwrap() =
+            DbKeySeg::StringSeg(Keys::segments().seen_by.into());
+        let first_vote = ctx
+            .wl_storage
+            .read::<Votes>(&seen_by_key)
+            .into_storage_result()?
+            .and_then(|votes| votes.values().min().copied());
+        if let Some(height) = first_vote {
+            seen_heights.extend(hashes.into_iter().map(|hash| (hash, height)));
+        }
+        Ok(ControlFlow::Continue(()))
+    })?;
+    Ok(seen_heights)
+}
+
+/// Iterates over the ethereum events until finding the
+/// `TransferToEthereum` event of the transfer with the given `hash`,
+/// and returns the amount of voting power backing it.
+///
+/// Returns `None` if the event of the transfer has been seen, or
+/// if no validator has voted for it yet.
+fn transfer_to_ethereum_backing<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    hash: KeccakHash,
+) -> namada_storage::Result<Option<FractionalVotingPower>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let mut backing = None;
+    for_each_unseen_transfers_event(&ctx, |hashes, voting_power_key| {
+        if !hashes.contains(&hash) {
+            return Ok(ControlFlow::Continue(()));
+        }
+        backing = Some(read_event_voting_power(&ctx, voting_power_key)?);
+        Ok(ControlFlow::Break(()))
+    })?;
+    Ok(backing)
+}
+
+/// Iterates over all ethereum events, and feeds the hashes of the
+/// transfers in each `TransferToEthereum` event that has not been
+/// seen yet to `f`, along with the key of the voting power backing
+/// the event.
+///
+/// The events are visited in the order in which they are laid out
+/// in storage, such that their transfers can be paginated. The
+/// iteration stops early if `f` breaks out of it.
+fn for_each_unseen_transfers_event<D, H, V, T, F>(
+    ctx: &RequestCtx<'_, D, H, V, T>,
+    mut f: F,
+) -> namada_storage::Result<()>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+    F: FnMut(Vec<KeccakHash>, &Key) -> namada_storage::Result<ControlFlow<()>>,
+{
     for (mut key, value) in ctx
         .wl_storage
         .iter_prefix(&eth_msgs_prefix())?
@@ -654,30 +1042,53 @@ where
         if let Ok(EthereumEvent::TransfersToEthereum { transfers, .. }) =
             EthereumEvent::try_from_slice(&value)
         {
-            // read the voting power behind the event
             *key.segments.last_mut().unwrap() =
                 DbKeySeg::StringSeg(Keys::segments().voting_power.into());
-            let voting_power = ctx
-                .wl_storage
-                .read::<EpochedVotingPower>(&key)
-                .into_storage_result()?
-                .expect(
-                    "Iterating over storage should not yield keys without \
-                     values.",
-                )
-                .fractional_stake(ctx.wl_storage);
-            for transfer in transfers {
-                let key = get_key_from_hash(&transfer.keccak256());
-                let transfer = ctx
-                    .wl_storage
-                    .read::<PendingTransfer>(&key)
-                    .into_storage_result()?
-                    .expect("The transfer must be present in storage");
-                pending_events.insert(transfer, voting_power);
+            let hashes = transfers
+                .into_iter()
+                .map(|transfer| transfer.keccak256())
+                .collect();
+            if f(hashes, &key)?.is_break() {
+                break;
             }
         }
     }
-    Ok(pending_events)
+    Ok(())
+}
+
+/// Read the voting power behind an Ethereum event, stored
+/// under `key`.
+fn read_event_voting_power<D, H, V, T>(
+    ctx: &RequestCtx<'_, D, H, V, T>,
+    key: &Key,
+) -> namada_storage::Result<FractionalVotingPower>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    Ok(ctx
+        .wl_storage
+        .read::<EpochedVotingPower>(key)
+        .into_storage_result()?
+        .expect("Iterating over storage should not yield keys without values.")
+        .fractional_stake(ctx.wl_storage))
+}
+
+/// Read the [`PendingTransfer`] with the given hash from storage.
+fn read_pending_transfer<D, H, V, T>(
+    ctx: &RequestCtx<'_, D, H, V, T>,
+    hash: &KeccakHash,
+) -> namada_storage::Result<PendingTransfer>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let key = get_key_from_hash(hash);
+    Ok(ctx
+        .wl_storage
+        .read::<PendingTransfer>(&key)
+        .into_storage_result()?
+        .expect("The transfer must be present in storage"))
 }
 
 /// Read a validator set update proof from storage.
@@ -843,14 +1254,16 @@ mod test_ethbridge_router {
     use namada_core::types::eth_bridge_pool::{
         GasFee, PendingTransfer, TransferToEthereum, TransferToEthereumKind,
     };
-    use namada_core::types::ethereum_events::EthAddress;
+    use namada_core::types::ethereum_events::{EthAddress, Uint};
+    use namada_core::types::parameters::EpochDuration;
     use namada_core::types::storage::BlockHeight;
+    use namada_core::types::time::DurationSecs;
     use namada_core::types::voting_power::{
         EthBridgeVotingPower, FractionalVotingPower,
     };
     use namada_ethereum_bridge::protocol::transactions::validator_set_update::aggregate_votes;
     use namada_ethereum_bridge::storage::bridge_pool::{
-        get_pending_key, get_signed_root_key, BridgePoolTree,
+        get_nonce_key, get_pending_key, get_signed_root_key, BridgePoolTree,
     };
     use namada_ethereum_bridge::storage::proof::BridgePoolRootProof;
     use namada_ethereum_bridge::storage::whitelist;
@@ -1096,6 +1509,68 @@ mod test_ethbridge_router {
         assert_eq!(pool, Vec::from([transfer]));
     }
 
+    /// Test that we can read the heights at which transfers
+    /// were added to the Bridge pool.
+    #[tokio::test]
+    async fn test_read_bridge_pool_transfer_heights() {
+        let mut client = TestClient::new(RPC);
+        client
+            .wl_storage
+            .write(
+                &namada_parameters::storage::get_epoch_duration_storage_key(),
+                EpochDuration {
+                    min_num_of_blocks: 10,
+                    min_duration: DurationSecs(100),
+                },
+            )
+            .expect("Test failed");
+
+        let transfer = PendingTransfer {
+            transfer: TransferToEthereum {
+                kind: TransferToEthereumKind::Erc20,
+                asset: EthAddress([0; 20]),
+                recipient: EthAddress([0; 20]),
+                sender: bertha_address(),
+                amount: 0.into(),
+            },
+            gas_fee: GasFee {
+                token: nam(),
+                amount: 0.into(),
+                payer: bertha_address(),
+            },
+        };
+
+        // write a transfer into the bridge pool
+        client.wl_storage.storage.block.height = 1.into();
+        client
+            .wl_storage
+            .write(&get_pending_key(&transfer), &transfer)
+            .expect("Test failed");
+
+        // commit the changes and increase block height
+        client.wl_storage.commit_block().expect("Test failed");
+        client.wl_storage.storage.block.height += 1;
+
+        // check the response
+        let heights = RPC
+            .shell()
+            .eth_bridge()
+            .read_bridge_pool_transfer_heights(&client)
+            .await
+            .unwrap();
+        assert_eq!(
+            heights,
+            BridgePoolTransferHeights {
+                queried_height: 1.into(),
+                expiry_offset: 10,
+                heights: HashMap::from([(
+                    transfer.keccak256(),
+                    BlockHeight(1)
+                )]),
+            }
+        );
+    }
+
     /// Test that reading the bridge pool always gets
     /// the latest pool
     #[tokio::test]
@@ -1208,6 +1683,7 @@ mod test_ethbridge_router {
                 (signed_root.clone(), written_height),
             )
             .expect("Test failed");
+        let signed_height = client.wl_storage.storage.block.height;
 
         // commit the changes and increase block height
         client.wl_storage.commit_block().expect("Test failed");
@@ -1261,6 +1737,64 @@ mod test_ethbridge_router {
             relay_proof,
         ));
         assert_eq!(encoded, resp.data.abi_encoded_args);
+
+        // the same proof is generated against the root targeted
+        // by the height at which it was signed
+        let request = GenBridgePoolProofReq {
+            transfers: vec![transfer.keccak256()].into(),
+            relayer: Cow::Owned(bertha_address()),
+            with_appendix: false,
+        }
+        .serialize_to_vec();
+        let resp = RPC
+            .shell()
+            .eth_bridge()
+            .generate_bridge_pool_proof_at_root(
+                &client,
+                Some(request.clone()),
+                None,
+                false,
+                &signed_height,
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.data.version, BRIDGE_POOL_PROOF_VERSION);
+        assert_eq!(encoded, resp.data.proof.abi_encoded_args);
+
+        // the versioned proof wraps the same proof
+        let resp = RPC
+            .shell()
+            .eth_bridge()
+            .generate_versioned_bridge_pool_proof(
+                &client,
+                Some(request.clone()),
+                None,
+                false,
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.data.version, BRIDGE_POOL_PROOF_VERSION);
+        assert_eq!(encoded, resp.data.proof.abi_encoded_args);
+
+        // roots whose nonce has been relayed cannot be targeted
+        client
+            .wl_storage
+            .write(&get_nonce_key(), Uint::from(1u64))
+            .expect("Test failed");
+        client.wl_storage.commit_block().expect("Test failed");
+        client.wl_storage.storage.block.height += 1;
+        let resp = RPC
+            .shell()
+            .eth_bridge()
+            .generate_bridge_pool_proof_at_root(
+                &client,
+                Some(request),
+                None,
+                false,
+                &signed_height,
+            )
+            .await;
+        assert!(resp.is_err());
     }
 
     /// Test if the merkle tree including a transfer has not had its
@@ -1483,7 +2017,7 @@ mod test_ethbridge_router {
         transfer2.transfer.amount = 1.into();
         client
             .wl_storage
-            .write(&get_pending_key(&transfer2), transfer2)
+            .write(&get_pending_key(&transfer2), &transfer2)
             .expect("Test failed");
 
         // commit the changes and increase block height
@@ -1500,8 +2034,182 @@ mod test_ethbridge_router {
             .await
             .unwrap();
         let expected: HashMap<PendingTransfer, FractionalVotingPower> =
-            [(transfer, voting_power)].into_iter().collect();
+            [(transfer.clone(), voting_power)].into_iter().collect();
         assert_eq!(expected, resp);
+
+        // the backing of a single transfer can be queried as well
+        let backing = RPC
+            .shell()
+            .eth_bridge()
+            .transfer_to_ethereum_backing(&client, &transfer.keccak256())
+            .await
+            .unwrap();
+        assert_eq!(backing, Some(voting_power));
+        let backing = RPC
+            .shell()
+            .eth_bridge()
+            .transfer_to_ethereum_backing(&client, &transfer2.keccak256())
+            .await
+            .unwrap();
+        assert_eq!(backing, None);
+
+        // the event has not been voted on yet
+        let seen_heights = RPC
+            .shell()
+            .eth_bridge()
+            .transfer_to_ethereum_seen_heights(&client)
+            .await
+            .unwrap();
+        assert!(seen_heights.is_empty());
+
+        // the earliest vote on the event is reported
+        client
+            .wl_storage
+            .write(
+                &eth_msg_key.seen_by(),
+                Votes::from([
+                    (bertha_address(), BlockHeight(3)),
+                    (established_address_1(), BlockHeight(2)),
+                ]),
+            )
+            .expect("Test failed");
+        client
+            .wl_storage
+            .storage
+            .commit_block(MockDBWriteBatch)
+            .expect("Test failed");
+        client.wl_storage.storage.block.height += 1;
+        let seen_heights = RPC
+            .shell()
+            .eth_bridge()
+            .transfer_to_ethereum_seen_heights(&client)
+            .await
+            .unwrap();
+        assert_eq!(
+            seen_heights,
+            HashMap::from([(transfer.keccak256(), BlockHeight(2))])
+        );
+    }
+
+    /// Test that the backing voting power of pending
+    /// TransferToEthereum events can be queried in pages.
+    #[tokio::test]
+    async fn test_transfer_to_eth_progress_page() {
+        let mut client = TestClient::new(RPC);
+        let transfers: Vec<_> = (0..3u64)
+            .map(|amount| PendingTransfer {
+                transfer: TransferToEthereum {
+                    kind: TransferToEthereumKind::Erc20,
+                    asset: EthAddress([0; 20]),
+                    recipient: EthAddress([0; 20]),
+                    sender: bertha_address(),
+                    amount: amount.into(),
+                },
+                gas_fee: GasFee {
+                    token: nam(),
+                    amount: 0.into(),
+                    payer: bertha_address(),
+                },
+            })
+            .collect();
+        // write validator to storage
+        let (_, dummy_validator_stake) = test_utils::default_validator();
+        test_utils::init_default_storage(&mut client.wl_storage);
+
+        // write the transfers into the bridge pool
+        for transfer in &transfers {
+            client
+                .wl_storage
+                .write(&get_pending_key(transfer), transfer)
+                .expect("Test failed");
+        }
+
+        let eth_event = EthereumEvent::TransfersToEthereum {
+            nonce: Default::default(),
+            transfers: transfers.iter().map(From::from).collect(),
+            relayer: bertha_address(),
+        };
+        let eth_msg_key = vote_tallies::Keys::from(&eth_event);
+        let voting_power = FractionalVotingPower::HALF;
+        client
+            .wl_storage
+            .write(&eth_msg_key.body(), eth_event)
+            .expect("Test failed");
+        client
+            .wl_storage
+            .write(
+                &eth_msg_key.voting_power(),
+                EpochedVotingPower::from([(
+                    0.into(),
+                    voting_power * dummy_validator_stake,
+                )]),
+            )
+            .expect("Test failed");
+        client
+            .wl_storage
+            .write(&eth_msg_key.seen(), false)
+            .expect("Test failed");
+        // commit the changes and increase block height
+        client
+            .wl_storage
+            .storage
+            .commit_block(MockDBWriteBatch)
+            .expect("Test failed");
+        client.wl_storage.storage.block.height += 1;
+
+        let first_page = RPC
+            .shell()
+            .eth_bridge()
+            .transfer_to_ethereum_progress_page(&client, &0, &2)
+            .await
+            .unwrap();
+        assert_eq!(first_page.next_page, Some(1));
+        let second_page = RPC
+            .shell()
+            .eth_bridge()
+            .transfer_to_ethereum_progress_page(&client, &1, &2)
+            .await
+            .unwrap();
+        assert_eq!(second_page.next_page, None);
+        assert_eq!(first_page.height, second_page.height);
+        let paged: HashMap<_, _> = first_page
+            .progress
+            .into_iter()
+            .chain(second_page.progress)
+            .collect();
+        let expected: HashMap<PendingTransfer, FractionalVotingPower> =
+            transfers
+                .into_iter()
+                .map(|transfer| (transfer, voting_power))
+                .collect();
+        assert_eq!(paged, expected);
+
+        // the pages are aggregated by the sdk helper
+        let aggregated =
+            crate::eth_bridge::bridge_pool::query_transfer_to_ethereum_progress(
+                &client, 2,
+            )
+            .await
+            .unwrap();
+        assert_eq!(aggregated, expected);
+
+        // the pages match the progress read at once
+        let snapshot =
+            crate::eth_bridge::bridge_pool::query_transfer_to_ethereum_snapshot(
+                &client,
+            )
+            .await
+            .unwrap();
+        assert_eq!(snapshot, expected);
+
+        // empty pages are rejected
+        assert!(
+            RPC.shell()
+                .eth_bridge()
+                .transfer_to_ethereum_progress_page(&client, &0, &0)
+                .await
+                .is_err()
+        );
     }
 
     /// Test if the a transfer has been removed from the
@@ -1667,6 +2375,43 @@ mod test_ethbridge_router {
         );
     }
 
+    /// Test that the Ethereum bridge is reported active
+    /// according to its status in storage.
+    #[tokio::test]
+    async fn test_bridge_active() {
+        use namada_ethereum_bridge::storage::active_key;
+        use namada_ethereum_bridge::storage::eth_bridge_queries::{
+            EthBridgeEnabled, EthBridgeStatus,
+        };
+
+        use crate::eth_bridge::bridge_pool::bridge_active;
+
+        let mut client = TestClient::new(RPC);
+
+        // the bridge is inactive if its status was never written
+        assert!(!bridge_active(&client).await.unwrap());
+
+        test_utils::init_default_storage(&mut client.wl_storage);
+        assert!(bridge_active(&client).await.unwrap());
+
+        // the bridge is inactive until the epoch it is enabled at
+        let next_epoch = client.wl_storage.storage.last_epoch.next();
+        client
+            .wl_storage
+            .write(
+                &active_key(),
+                EthBridgeStatus::Enabled(EthBridgeEnabled::AtEpoch(next_epoch)),
+            )
+            .expect("Test failed");
+        assert!(!bridge_active(&client).await.unwrap());
+
+        client
+            .wl_storage
+            .write(&active_key(), EthBridgeStatus::Disabled)
+            .expect("Test failed");
+        assert!(!bridge_active(&client).await.unwrap());
+    }
+
     /// Test that querying the status of the Bridge pool
     /// returns the expected keccak hashes.
     #[tokio::test]